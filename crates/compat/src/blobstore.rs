@@ -54,6 +54,23 @@ pub struct ContainerObject {
     pub object_id: String,
 }
 
+/// Parameter to CopyObject
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct CopyObjectRequest {
+    /// container of the object to copy
+    #[serde(rename = "srcContainer")]
+    pub src_container: String,
+    /// object to copy
+    #[serde(rename = "srcObject")]
+    pub src_object: String,
+    /// container to copy the object to
+    #[serde(rename = "destContainer")]
+    pub dest_container: String,
+    /// name of the copy
+    #[serde(rename = "destObject")]
+    pub dest_object: String,
+}
+
 /// Parameter to GetObject
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
 pub struct GetObjectRequest {
@@ -213,6 +230,23 @@ pub struct ObjectMetadata {
     pub content_encoding: Option<String>,
 }
 
+/// Parameter to MoveObject
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct MoveObjectRequest {
+    /// container of the object to move
+    #[serde(rename = "srcContainer")]
+    pub src_container: String,
+    /// object to move
+    #[serde(rename = "srcObject")]
+    pub src_object: String,
+    /// container to move the object to
+    #[serde(rename = "destContainer")]
+    pub dest_container: String,
+    /// new name of the object
+    #[serde(rename = "destObject")]
+    pub dest_object: String,
+}
+
 /// Parameter to PutChunk operation
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
 pub struct PutChunkRequest {
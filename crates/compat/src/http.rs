@@ -109,6 +109,62 @@ impl TryFrom<Request> for http::Request<Vec<u8>> {
     }
 }
 
+/// Request sent to a `wasmcloud:httpclient` provider on behalf of the actor
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ClientRequest {
+    /// HTTP method
+    #[serde(default)]
+    pub method: String,
+    /// Full URL of the request
+    #[serde(default)]
+    pub url: String,
+    /// Map of request headers (string key, string values)
+    pub headers: HashMap<String, Vec<String>>,
+    /// Request body as a byte array. May be empty.
+    #[serde(with = "serde_bytes")]
+    #[serde(default)]
+    pub body: Vec<u8>,
+}
+
+impl ClientRequest {
+    pub async fn from_http(
+        request: http::Request<impl AsyncRead + Unpin>,
+    ) -> anyhow::Result<ClientRequest> {
+        let (
+            http::request::Parts {
+                method,
+                uri,
+                headers,
+                ..
+            },
+            mut body,
+        ) = request.into_parts();
+        let headers = headers.iter().try_fold(
+            HashMap::<_, Vec<_>>::default(),
+            |mut headers, (name, value)| {
+                let value = value
+                    .to_str()
+                    .with_context(|| format!("failed to parse `{name}` header value as string"))?;
+                headers
+                    .entry(name.as_str().into())
+                    .or_default()
+                    .push(value.into());
+                anyhow::Ok(headers)
+            },
+        )?;
+        let mut buf = vec![];
+        body.read_to_end(&mut buf)
+            .await
+            .context("failed to read request body")?;
+        Ok(ClientRequest {
+            method: method.as_str().into(),
+            url: uri.to_string(),
+            headers,
+            body: buf,
+        })
+    }
+}
+
 /// Response contains the actor's response to return to the http client
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Response {
@@ -1,16 +1,65 @@
 use serde::{Deserialize, Serialize};
 
+/// Key-value request optionally scoped to a bucket.
+///
+/// Requests to the default bucket are encoded as the bare request, which keeps them compatible
+/// with providers unaware of buckets. Requests to a named bucket are encoded as a
+/// `[bucket, request]` tuple, which such providers fail to decode.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum BucketRequest<T> {
+    /// Request scoped to a named bucket
+    Bucket(String, T),
+    /// Request to the default bucket
+    Default(T),
+}
+
+impl<T> BucketRequest<T> {
+    /// Constructs a request scoped to `bucket`, the empty bucket name denotes the default bucket
+    pub fn new(bucket: impl Into<String>, request: T) -> Self {
+        let bucket = bucket.into();
+        if bucket.is_empty() {
+            Self::Default(request)
+        } else {
+            Self::Bucket(bucket, request)
+        }
+    }
+
+    /// Returns the bucket name, `None` for the default bucket, and the request
+    pub fn into_parts(self) -> (Option<String>, T) {
+        match self {
+            Self::Bucket(bucket, request) => (Some(bucket), request),
+            Self::Default(request) => (None, request),
+        }
+    }
+}
+
 /// Response to get request
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
 pub struct GetResponse {
     /// the value, if it existed
+    #[serde(with = "serde_bytes")]
     #[serde(default)]
-    pub value: String,
+    pub value: Vec<u8>,
     /// whether or not the value existed
     #[serde(default)]
     pub exists: bool,
 }
 
+/// Parameter to CompareAndSwap operation
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct CompareAndSwapRequest {
+    /// name of value to swap
+    #[serde(default)]
+    pub key: String,
+    /// value expected to be currently stored
+    #[serde(default)]
+    pub old: u64,
+    /// value to store if the current value matches `old`
+    #[serde(default)]
+    pub new: u64,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
 pub struct IncrementRequest {
     /// name of value to increment
@@ -86,9 +135,43 @@ pub struct SetRequest {
     #[serde(default)]
     pub key: String,
     /// the new value
+    #[serde(with = "serde_bytes")]
     #[serde(default)]
-    pub value: String,
+    pub value: Vec<u8>,
     /// expiration time in seconds 0 for no expiration
     #[serde(default)]
     pub expires: u32,
 }
+
+#[test]
+fn values_binary_safe() {
+    let value = vec![0, 159, 146, 150, 0xff];
+    let buf = rmp_serde::to_vec_named(&SetRequest {
+        key: "foo".into(),
+        value: value.clone(),
+        expires: 0,
+    })
+    .expect("failed to encode `SetRequest`");
+    let SetRequest { value: decoded, .. } =
+        rmp_serde::from_slice(&buf).expect("failed to decode `SetRequest`");
+    assert_eq!(decoded, value);
+}
+
+#[test]
+fn values_decode_from_strings() {
+    // providers encoding values as strings remain supported
+    #[derive(Serialize)]
+    struct StringGetResponse {
+        value: String,
+        exists: bool,
+    }
+    let buf = rmp_serde::to_vec_named(&StringGetResponse {
+        value: "bar".into(),
+        exists: true,
+    })
+    .expect("failed to encode response");
+    let GetResponse { value, exists } =
+        rmp_serde::from_slice(&buf).expect("failed to decode `GetResponse`");
+    assert!(exists);
+    assert_eq!(value, b"bar");
+}
@@ -48,6 +48,26 @@ pub struct RequestMessage {
     pub timeout_ms: u32,
 }
 
+/// Message sent as part of a scatter-gather request, collecting multiple replies
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RequestMultiMessage {
+    /// The subject, or topic, of the message
+    #[serde(default)]
+    pub subject: String,
+    /// The message payload
+    #[serde(with = "serde_bytes")]
+    #[serde(default)]
+    pub body: Vec<u8>,
+    /// A timeout, in milliseconds, within which replies are collected
+    #[serde(rename = "timeoutMs")]
+    #[serde(default)]
+    pub timeout_ms: u32,
+    /// Maximum amount of replies to collect
+    #[serde(rename = "maxResults")]
+    #[serde(default)]
+    pub max_results: u32,
+}
+
 /// Message received as part of a subscription
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
 pub struct SubMessage {
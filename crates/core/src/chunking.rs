@@ -23,7 +23,7 @@ use async_nats::jetstream::{
     object_store::{Config, ObjectStore},
     Context as JetstreamContext,
 };
-use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::io::{self, AsyncRead, AsyncWrite};
 use tracing::{debug, error, instrument};
 
 /// Amount of time to add to rpc timeout if chunkifying
@@ -67,6 +67,24 @@ impl ChunkEndpoint {
     #[instrument(level = "trace", skip(self))]
     pub async fn get_unchunkified(&self, inv_id: &str) -> anyhow::Result<Vec<u8>> {
         let mut result = Vec::new();
+        self.copy_unchunkified(inv_id, &mut result).await?;
+        Ok(result)
+    }
+
+    /// load response after de-chunking
+    pub async fn get_unchunkified_response(&self, inv_id: &str) -> anyhow::Result<Vec<u8>> {
+        // responses are stored in the object store with '-r' suffix on the object name
+        self.get_unchunkified(&format!("{inv_id}-r")).await
+    }
+
+    /// stream the de-chunked message into `dst` as chunks are read from the store, returns the
+    /// number of bytes copied. The message must have been chunkified in full
+    #[instrument(level = "trace", skip(self, dst))]
+    pub async fn copy_unchunkified(
+        &self,
+        inv_id: &str,
+        dst: &mut (impl AsyncWrite + Unpin),
+    ) -> anyhow::Result<u64> {
         let store = self
             .create_or_reuse_store()
             .await
@@ -76,7 +94,7 @@ impl ChunkEndpoint {
             .get(inv_id)
             .await
             .context("failed to receive chunked stream")?;
-        obj.read_to_end(&mut result)
+        let n = io::copy(&mut obj, dst)
             .await
             .context("failed to read chunked stream")?;
         if let Err(e) = store.delete(inv_id).await {
@@ -84,13 +102,17 @@ impl ChunkEndpoint {
             // if all the bytes have been received
             error!(invocation_id = %inv_id, error = %e, "failed to delete chunks");
         }
-        Ok(result)
+        Ok(n)
     }
 
-    /// load response after de-chunking
-    pub async fn get_unchunkified_response(&self, inv_id: &str) -> anyhow::Result<Vec<u8>> {
-        // responses are stored in the object store with '-r' suffix on the object name
-        self.get_unchunkified(&format!("{inv_id}-r")).await
+    /// stream the de-chunked response into `dst` as chunks are read from the store, returns the
+    /// number of bytes copied. The response must have been chunkified in full
+    pub async fn copy_unchunkified_response(
+        &self,
+        inv_id: &str,
+        dst: &mut (impl AsyncWrite + Unpin),
+    ) -> anyhow::Result<u64> {
+        self.copy_unchunkified(&format!("{inv_id}-r"), dst).await
     }
 
     /// chunkify a message
@@ -2,17 +2,23 @@ pub mod chunking;
 pub mod logging;
 
 use core::fmt;
+use core::pin::Pin;
+use core::task::{self, ready, Poll};
+use core::time::Duration;
 
 use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use anyhow::{anyhow, bail, ensure, Context};
 use nkeys::{KeyPair, KeyPairType};
 use serde::{ser::SerializeMap, Deserialize, Serialize, Serializer};
 use sha2::{Digest, Sha256};
+use tokio::io::{AsyncRead, ReadBuf};
 use ulid::Ulid;
 use uuid::Uuid;
 use wascap::{jwt, prelude::Claims};
 
+use crate::chunking::ChunkEndpoint;
 use crate::logging::Level;
 
 /// List of linked actors for a provider
@@ -105,6 +111,67 @@ pub fn invocation_hash(
     hex::encode_upper(hash.finalize())
 }
 
+fn encode_invocation_claims(
+    cluster_key: &KeyPair,
+    id: &str,
+    target_url: &str,
+    origin: &WasmCloudEntity,
+    hash: &str,
+) -> anyhow::Result<String> {
+    jwt::Claims::<jwt::Invocation>::new(
+        cluster_key.public_key(),
+        id.to_string(),
+        target_url,
+        &origin.url(),
+        hash,
+    )
+    .encode(cluster_key)
+    .context("failed to encode claims")
+}
+
+/// [`AsyncRead`] wrapper computing the [`invocation_hash`] and length of an invocation body,
+/// which is read through it
+struct HashingReader<T> {
+    inner: T,
+    hash: Sha256,
+    len: u64,
+}
+
+impl<T> HashingReader<T> {
+    fn new(
+        target_url: impl AsRef<str>,
+        origin_url: impl AsRef<str>,
+        op: impl AsRef<str>,
+        inner: T,
+    ) -> Self {
+        let mut hash = Sha256::default();
+        hash.update(origin_url.as_ref());
+        hash.update(target_url.as_ref());
+        hash.update(op.as_ref());
+        Self {
+            inner,
+            hash,
+            len: 0,
+        }
+    }
+}
+
+impl<T: AsyncRead + Unpin> AsyncRead for HashingReader<T> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut task::Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let filled = buf.filled().len();
+        ready!(Pin::new(&mut self.inner).poll_read(cx, buf))?;
+        let this = &mut *self;
+        let read = &buf.filled()[filled..];
+        this.hash.update(read);
+        this.len += read.len() as u64;
+        Poll::Ready(Ok(()))
+    }
+}
+
 /// RPC message to capability provider
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Invocation {
@@ -123,6 +190,16 @@ pub struct Invocation {
     pub host_id: String,
     /// total message size
     pub content_length: u64,
+    /// Optional time, in milliseconds since the Unix epoch, after which the caller no longer
+    /// waits for a response. Recipients may abandon the invocation once the deadline has passed.
+    ///
+    /// The deadline is an absolute wall-clock time, so it assumes that the clocks of the caller
+    /// and the recipient are synchronized, e.g. using NTP. Clock skew between the two shortens or
+    /// extends the time the recipient spends on the invocation by the same amount.
+    /// The deadline is not covered by the signed [`Invocation::encoded_claims`], so it must only
+    /// be used as a hint to stop work early and never to make authorization decisions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deadline: Option<u64>,
     /// Open Telemetry tracing support
     #[serde(rename = "traceContext")]
     #[serde(
@@ -163,16 +240,9 @@ impl Invocation {
         // TODO: Support per-interface links
         let id = Uuid::from_u128(Ulid::new().into()).to_string();
         let target_url = format!("{}/{operation}", target.url());
-        let claims = jwt::Claims::<jwt::Invocation>::new(
-            cluster_key.public_key(),
-            id.to_string(),
-            &target_url,
-            &origin.url(),
-            &invocation_hash(&target_url, origin.url(), operation, &msg),
-        );
-        let encoded_claims = claims
-            .encode(cluster_key)
-            .context("failed to encode claims")?;
+        let hash = invocation_hash(&target_url, origin.url(), operation, &msg);
+        let encoded_claims =
+            encode_invocation_claims(cluster_key, &id, &target_url, &origin, &hash)?;
 
         let operation = operation.to_string();
         Ok(Invocation {
@@ -184,10 +254,85 @@ impl Invocation {
             id,
             encoded_claims,
             host_id: host_key.public_key(),
+            deadline: None,
+            trace_context,
+        })
+    }
+
+    /// Creates a new invocation, the body of which is streamed from `msg` to the object store
+    /// of `chunk_endpoint` as it is being read, instead of being carried in the invocation itself.
+    /// This allows bodies to be sent without ever holding them in memory in full.
+    ///
+    /// Note that this only returns once `msg` is exhausted and the upload is complete, so the
+    /// returned invocation can only be published after the whole body has been written.
+    /// Receivers do not observe the body while it is being sent, i.e. this bounds the memory
+    /// used by the sender, but does not stream the body to the receiver.
+    ///
+    /// See [`Invocation::new`] for a description of the arguments.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn new_chunked(
+        cluster_key: &KeyPair,
+        host_key: &KeyPair,
+        origin: WasmCloudEntity,
+        target: WasmCloudEntity,
+        operation: impl Into<String>,
+        msg: impl AsyncRead + Unpin,
+        chunk_endpoint: &ChunkEndpoint,
+        trace_context: TraceContext,
+    ) -> anyhow::Result<Invocation> {
+        let operation = operation.into();
+        let (_, operation) = operation
+            .rsplit_once('/')
+            .context("failed to parse operation")?;
+        // TODO: Support per-interface links
+        let id = Uuid::from_u128(Ulid::new().into()).to_string();
+        let target_url = format!("{}/{operation}", target.url());
+        let mut msg = HashingReader::new(&target_url, origin.url(), operation, msg);
+        chunk_endpoint
+            .chunkify(&id, &mut msg)
+            .await
+            .context("failed to chunk invocation")?;
+        let content_length = msg.len;
+        let hash = hex::encode_upper(msg.hash.finalize());
+        let encoded_claims =
+            encode_invocation_claims(cluster_key, &id, &target_url, &origin, &hash)?;
+
+        let operation = operation.to_string();
+        Ok(Invocation {
+            content_length,
+            origin,
+            target,
+            operation,
+            msg: vec![],
+            id,
+            encoded_claims,
+            host_id: host_key.public_key(),
+            deadline: None,
             trace_context,
         })
     }
 
+    /// Sets the [`Invocation::deadline`] to `timeout` from now, according to the local clock.
+    /// See [`Invocation::deadline`] for the clock synchronization this relies on
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        let deadline = SystemTime::now() + timeout;
+        self.deadline = deadline
+            .duration_since(UNIX_EPOCH)
+            .ok()
+            .and_then(|deadline| deadline.as_millis().try_into().ok());
+    }
+
+    /// Time remaining until the [`Invocation::deadline`], if one is set, according to the local
+    /// clock. [`Duration::ZERO`] is returned if the deadline has already passed.
+    pub fn time_remaining(&self) -> Option<Duration> {
+        let deadline = UNIX_EPOCH + Duration::from_millis(self.deadline?);
+        Some(
+            deadline
+                .duration_since(SystemTime::now())
+                .unwrap_or(Duration::ZERO),
+        )
+    }
+
     /// A fully-qualified URL indicating the origin of the invocation
     pub fn origin_url(&self) -> String {
         self.origin.url()
@@ -383,3 +528,35 @@ where
     let values = HashMap::<String, T>::deserialize(deserializer)?;
     Ok(values.into_iter().collect())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tokio::io::{AsyncReadExt, BufReader};
+
+    #[tokio::test]
+    async fn hashing_reader() -> anyhow::Result<()> {
+        const TARGET: &str = "wasmbus://wasmcloud/blobstore/default/Blobstore.PutObject";
+        const ORIGIN: &str = "wasmbus://MB2ZQB6ROOMAYBO4ZCTFYWN7YIVBWA3MTKZYAQKJMTIHE2ELLRW2E3ZW";
+        const OPERATION: &str = "Blobstore.PutObject";
+
+        let msg: Vec<u8> = (0..=u8::MAX).cycle().take(10_000).collect();
+        // read in small pieces, to ensure the hash is updated across multiple reads
+        let mut r = HashingReader::new(
+            TARGET,
+            ORIGIN,
+            OPERATION,
+            BufReader::with_capacity(7, msg.as_slice()),
+        );
+        let mut buf = vec![];
+        r.read_to_end(&mut buf).await?;
+        assert_eq!(buf, msg);
+        assert_eq!(r.len, msg.len() as u64);
+        assert_eq!(
+            hex::encode_upper(r.hash.finalize()),
+            invocation_hash(TARGET, ORIGIN, OPERATION, &msg)
+        );
+        Ok(())
+    }
+}
@@ -110,6 +110,16 @@ impl ResourceRef<'_> {
     }
 }
 
+/// Whether the artifact referenced by `image_ref` is available locally, i.e. whether it can be
+/// started without pulling it from a registry. Bindle references are never considered available.
+async fn image_cached(image_ref: impl AsRef<str>) -> bool {
+    match ResourceRef::try_from(image_ref.as_ref()) {
+        Ok(ResourceRef::File(path)) => fs::metadata(path).await.is_ok(),
+        Ok(ResourceRef::Oci(image_ref)) => oci::is_cached(image_ref).await,
+        Ok(ResourceRef::Bindle(_)) | Err(_) => false,
+    }
+}
+
 /// Fetch an actor from a reference.
 #[instrument(skip(actor_ref))]
 pub async fn fetch_actor(
@@ -23,6 +23,7 @@ const OCI_MEDIA_TYPE: &str = "application/vnd.oci.image.layer.v1.tar";
 
 /// Configuration options for OCI operations.
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default)]
 pub struct Config {
     /// Whether or not to allow downloading OCI artifacts with the tag `latest`
     pub allow_latest: bool,
@@ -70,6 +71,14 @@ async fn get_digest_filepath(img: &str) -> std::io::Result<PathBuf> {
     Ok(path)
 }
 
+/// Whether the image referenced by `img` is present in the local OCI cache
+pub(crate) async fn is_cached(img: &str) -> bool {
+    let Ok(path) = get_cached_filepath(&img.to_lowercase()).await else {
+        return false;
+    };
+    fs::metadata(path).await.is_ok()
+}
+
 async fn create_filepath(img: &str) -> std::io::Result<PathBuf> {
     let path = temp_dir();
     let path = path.join("wasmcloud_ocicache");
@@ -11,8 +11,8 @@ use futures::{
     StreamExt,
 };
 use serde::{Deserialize, Serialize, Serializer};
-use tokio::spawn;
-use tokio::sync::RwLock;
+use tokio::sync::{mpsc, RwLock};
+use tokio::{select, spawn};
 use tracing::{debug, error, instrument, trace, warn};
 use ulid::Ulid;
 use uuid::Uuid;
@@ -65,7 +65,7 @@ pub struct RequestTarget {
 }
 
 /// Relevant information about the host that is receiving the invocation, or starting the actor or provider
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
 pub struct HostInfo {
     /// The public key of the host
     #[serde(rename = "publicKey")]
@@ -203,15 +203,21 @@ impl From<jwt::Claims<jwt::CapabilityProvider>> for RequestTarget {
 #[derive(Debug)]
 pub struct Manager {
     nats: async_nats::Client,
-    host_info: HostInfo,
-    policy_topic: Option<String>,
-    policy_timeout: Duration,
+    host_info: RwLock<HostInfo>,
+    policy_topic: RwLock<Option<String>>,
+    policy_timeout: RwLock<Duration>,
     decision_cache: Arc<RwLock<HashMap<RequestKey, Response>>>,
     request_to_key: Arc<RwLock<HashMap<String, RequestKey>>>,
+    /// The policy changes topic currently subscribed to
+    policy_changes_topic: RwLock<Option<String>>,
+    /// Sender of policy changes subscriptions replacing the current one
+    policy_changes_subscriptions: mpsc::UnboundedSender<Option<async_nats::Subscriber>>,
     /// An abort handle for the policy changes subscription
     pub policy_changes: AbortHandle,
 }
 
+const DEFAULT_POLICY_TIMEOUT: Duration = Duration::from_secs(1);
+
 impl Manager {
     /// Construct a new policy manager. Can fail if policy_changes_topic is set but we fail to subscribe to it
     #[instrument(skip(nats))]
@@ -222,41 +228,134 @@ impl Manager {
         policy_timeout: Option<Duration>,
         policy_changes_topic: Option<String>,
     ) -> anyhow::Result<Arc<Self>> {
-        const DEFAULT_POLICY_TIMEOUT: Duration = Duration::from_secs(1);
+        let policy_changes = if let Some(policy_changes_topic) = &policy_changes_topic {
+            Some(
+                nats.subscribe(policy_changes_topic.clone())
+                    .await
+                    .context("failed to subscribe to policy changes")?,
+            )
+        } else {
+            None
+        };
 
         let (policy_changes_abort, policy_changes_abort_reg) = AbortHandle::new_pair();
-
+        let (policy_changes_tx, policy_changes_rx) = mpsc::unbounded_channel();
         let manager = Manager {
-            nats: nats.clone(),
-            host_info,
-            policy_topic,
-            policy_timeout: policy_timeout.unwrap_or(DEFAULT_POLICY_TIMEOUT),
+            nats,
+            host_info: RwLock::new(host_info),
+            policy_topic: RwLock::new(policy_topic),
+            policy_timeout: RwLock::new(policy_timeout.unwrap_or(DEFAULT_POLICY_TIMEOUT)),
             decision_cache: Arc::default(),
             request_to_key: Arc::default(),
+            policy_changes_topic: RwLock::new(policy_changes_topic),
+            policy_changes_subscriptions: policy_changes_tx,
             policy_changes: policy_changes_abort,
         };
         let manager = Arc::new(manager);
 
-        if let Some(policy_changes_topic) = policy_changes_topic {
-            let policy_changes = nats
-                .subscribe(policy_changes_topic)
-                .await
-                .context("failed to subscribe to policy changes")?;
+        let _policy_changes = spawn(Abortable::new(
+            Arc::clone(&manager).receive_policy_changes(policy_changes, policy_changes_rx),
+            policy_changes_abort_reg,
+        ));
+        Ok(manager)
+    }
 
-            let _policy_changes = spawn({
-                let manager = Arc::clone(&manager);
-                Abortable::new(policy_changes, policy_changes_abort_reg).for_each(move |msg| {
-                    let manager = Arc::clone(&manager);
-                    async move {
-                        if let Err(e) = manager.override_decision(msg).await {
-                            error!("failed to process policy decision override: {}", e);
-                        }
+    /// Processes policy decision overrides received on `policy_changes`, until it is replaced
+    /// by a subscription received on `subscriptions`
+    async fn receive_policy_changes(
+        self: Arc<Self>,
+        mut policy_changes: Option<async_nats::Subscriber>,
+        mut subscriptions: mpsc::UnboundedReceiver<Option<async_nats::Subscriber>>,
+    ) {
+        loop {
+            let next = async {
+                match policy_changes.as_mut() {
+                    Some(policy_changes) => policy_changes.next().await,
+                    None => futures::future::pending().await,
+                }
+            };
+            select! {
+                subscription = subscriptions.recv() => {
+                    let Some(subscription) = subscription else {
+                        return;
+                    };
+                    policy_changes = subscription;
+                }
+                msg = next => {
+                    let Some(msg) = msg else {
+                        policy_changes = None;
+                        continue;
+                    };
+                    if let Err(e) = self.override_decision(msg).await {
+                        error!("failed to process policy decision override: {}", e);
                     }
-                })
-            });
+                }
+            }
         }
+    }
 
-        Ok(manager)
+    /// Replaces the policy service configuration, discarding cached policy decisions if it
+    /// changed. Can fail if policy_changes_topic is set but we fail to subscribe to it
+    #[instrument(skip(self))]
+    pub async fn reconfigure(
+        self: &Arc<Self>,
+        policy_topic: Option<String>,
+        policy_timeout: Option<Duration>,
+        policy_changes_topic: Option<String>,
+    ) -> anyhow::Result<()> {
+        let policy_timeout = policy_timeout.unwrap_or(DEFAULT_POLICY_TIMEOUT);
+        let mut current_policy_changes_topic = self.policy_changes_topic.write().await;
+        let mut current_policy_topic = self.policy_topic.write().await;
+        let mut current_policy_timeout = self.policy_timeout.write().await;
+        if *current_policy_topic == policy_topic
+            && *current_policy_timeout == policy_timeout
+            && *current_policy_changes_topic == policy_changes_topic
+        {
+            return Ok(());
+        }
+        if *current_policy_changes_topic != policy_changes_topic {
+            let policy_changes = if let Some(policy_changes_topic) = &policy_changes_topic {
+                Some(
+                    self.nats
+                        .subscribe(policy_changes_topic.clone())
+                        .await
+                        .context("failed to subscribe to policy changes")?,
+                )
+            } else {
+                None
+            };
+            // The receiver is only dropped once policy changes are aborted
+            let _ = self.policy_changes_subscriptions.send(policy_changes);
+            *current_policy_changes_topic = policy_changes_topic;
+        }
+        *current_policy_topic = policy_topic;
+        *current_policy_timeout = policy_timeout;
+        // `evaluate_action` acquires these while holding the decision cache lock
+        drop((
+            current_policy_changes_topic,
+            current_policy_topic,
+            current_policy_timeout,
+        ));
+        self.clear_decisions().await;
+        Ok(())
+    }
+
+    /// Replaces the host information sent with policy requests, discarding cached policy
+    /// decisions if it changed
+    #[instrument(skip(self))]
+    pub async fn update_host_info(&self, host_info: HostInfo) {
+        let mut current = self.host_info.write().await;
+        if *current != host_info {
+            *current = host_info;
+            // `evaluate_action` acquires the host info while holding the decision cache lock
+            drop(current);
+            self.clear_decisions().await;
+        }
+    }
+
+    async fn clear_decisions(&self) {
+        self.decision_cache.write().await.clear();
+        self.request_to_key.write().await.clear();
     }
 
     /// Constructs a
@@ -281,19 +380,20 @@ impl Manager {
             }
             hash_map::Entry::Vacant(entry) => {
                 let request_id = Uuid::from_u128(Ulid::new().into()).to_string();
-                let decision = if let Some(policy_topic) = self.policy_topic.clone() {
+                let policy_topic = self.policy_topic.read().await.clone();
+                let decision = if let Some(policy_topic) = policy_topic {
                     trace!(?cache_key, "requesting policy decision");
                     let payload = serde_json::to_vec(&Request {
                         request_id: request_id.clone(),
                         source,
                         target,
-                        host: self.host_info.clone(),
+                        host: self.host_info.read().await.clone(),
                         action,
                     })
                     .context("failed to serialize policy request")?;
                     let request = async_nats::Request::new()
                         .payload(payload.into())
-                        .timeout(Some(self.policy_timeout));
+                        .timeout(Some(*self.policy_timeout.read().await));
                     let res = self
                         .nats
                         .send_request(policy_topic, request)
@@ -1,11 +1,20 @@
 use crate::OciConfig;
 
+use core::num::NonZeroUsize;
+use core::str::FromStr;
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 
+use anyhow::{bail, Context};
 use nkeys::KeyPair;
+use serde::{de, Deserialize, Deserializer};
 use url::Url;
 use wasmcloud_core::{logging::Level as LogLevel, OtelConfig};
+use wasmcloud_runtime::ActorLimits;
 
 /// wasmCloud Host configuration
 #[allow(clippy::struct_excessive_bools)]
@@ -23,7 +32,7 @@ pub struct Host {
     pub ctl_topic_prefix: String,
     /// NATS URL to connect to for actor RPC
     pub rpc_nats_url: Url,
-    /// Timeout period for all RPC calls
+    /// Timeout period for all RPC calls and HTTP requests sent directly from the host
     pub rpc_timeout: Duration,
     /// Authentication JWT for RPC connection, must be specified with rpc_seed
     pub rpc_jwt: Option<String>,
@@ -65,19 +74,483 @@ pub struct Host {
     pub otel_config: OtelConfig,
     /// configuration for wasmCloud policy service
     pub policy_service_config: PolicyService,
+    /// Wall-clock time a single actor invocation is allowed to run for, unlimited if `None`
+    pub max_execution_time: Option<Duration>,
+    /// Amount of fuel a single actor invocation is allowed to consume, unlimited if `None`
+    pub max_fuel: Option<u64>,
+    /// Resource limits of actor instances, which can be overridden per actor using annotations
+    pub actor_limits: ActorLimits,
+    /// Directory to cache precompiled actors in, actors are compiled on every start if `None`
+    pub artifact_cache_dir: Option<PathBuf>,
+    /// Maximum total size, in bytes, of precompiled actors in the artifact cache
+    pub artifact_cache_max_size: u64,
+    /// Maximum time to wait for in-flight invocations of actor instances being stopped or replaced
+    /// to complete. On shutdown, this is used unless the stop command specifies a timeout
+    pub drain_timeout: Duration,
+    /// Amount of actor instances replaced at a time during rolling actor updates
+    pub actor_update_batch_size: NonZeroUsize,
+    /// Supervision of capability provider processes
+    pub provider_supervision: ProviderSupervision,
+    /// Health checking of capability provider processes
+    pub provider_health_check: ProviderHealthCheck,
+    /// Whether to publish output of capability provider processes on
+    /// `wasmbus.log.{lattice_prefix}.{provider_id}.{link_name}` in addition to logging it
+    pub publish_provider_output: bool,
+    /// Authorities (`host` or `host:port`) actor outgoing HTTP requests are sent to directly by the
+    /// host. Requests to any other authority are routed through a linked `wasmcloud:httpclient` provider
+    pub allowed_http_authorities: Vec<String>,
+    /// Labels of the host in addition to the `hostcore.*` labels and the labels set using
+    /// `HOST_*` environment variables, which they take precedence over
+    pub labels: HashMap<String, String>,
+    /// Interval between host heartbeats
+    pub heartbeat_interval: Duration,
+    /// Capacity limits of the host
+    pub capacity: Capacity,
+}
+
+/// Policy determining whether a capability provider process is restarted once it exits
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ProviderRestartPolicy {
+    /// Never restart the provider
+    Never,
+    /// Restart the provider if it exits with a failure
+    #[default]
+    OnFailure,
+    /// Restart the provider whenever it exits
+    Always,
+}
+
+impl ProviderRestartPolicy {
+    /// Whether a provider, which exited with or without a failure, should be restarted
+    #[must_use]
+    pub fn should_restart(self, failed: bool) -> bool {
+        match self {
+            Self::Never => false,
+            Self::OnFailure => failed,
+            Self::Always => true,
+        }
+    }
+}
+
+impl FromStr for ProviderRestartPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "never" => Ok(Self::Never),
+            "on-failure" => Ok(Self::OnFailure),
+            "always" => Ok(Self::Always),
+            _ => bail!("unknown provider restart policy `{s}`, expected one of `never`, `on-failure` or `always`"),
+        }
+    }
+}
+
+/// Supervision configuration of capability provider processes
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProviderSupervision {
+    /// Policy determining whether a provider is restarted once it exits
+    pub restart_policy: ProviderRestartPolicy,
+    /// Maximum amount of consecutive restarts before the provider is considered stopped.
+    /// The count is reset once a provider stays up for at least `max_backoff`
+    pub max_restarts: u32,
+    /// Delay before the first restart, doubled on every consecutive restart
+    #[serde(deserialize_with = "deserialize_required_duration")]
+    pub initial_backoff: Duration,
+    /// Maximum delay between consecutive restarts
+    #[serde(deserialize_with = "deserialize_required_duration")]
+    pub max_backoff: Duration,
+}
+
+impl ProviderSupervision {
+    /// Delay before restarting a provider, which has been restarted `restarts` times in a row
+    #[must_use]
+    pub fn backoff(&self, restarts: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2_u32.saturating_pow(restarts))
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for ProviderSupervision {
+    fn default() -> Self {
+        Self {
+            restart_policy: ProviderRestartPolicy::default(),
+            max_restarts: 5,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Action taken once a capability provider fails consecutive health checks
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ProviderHealthAction {
+    /// Only publish a `provider_unhealthy` event
+    Event,
+    /// Kill and restart the provider, subject to the restart budget of [`ProviderSupervision`]
+    #[default]
+    Restart,
+    /// Kill the provider and consider it stopped
+    Stop,
+}
+
+impl FromStr for ProviderHealthAction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "event" => Ok(Self::Event),
+            "restart" => Ok(Self::Restart),
+            "stop" => Ok(Self::Stop),
+            _ => bail!(
+                "unknown provider health action `{s}`, expected one of `event`, `restart` or `stop`"
+            ),
+        }
+    }
+}
+
+/// Health check configuration of capability provider processes
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProviderHealthCheck {
+    /// Interval between consecutive health checks
+    #[serde(deserialize_with = "deserialize_required_duration")]
+    pub interval: Duration,
+    /// Time to wait for a health check response before considering the check failed
+    #[serde(deserialize_with = "deserialize_required_duration")]
+    pub timeout: Duration,
+    /// Amount of consecutive failed health checks, after which `action` is taken.
+    /// No action is ever taken if 0
+    pub failure_threshold: u32,
+    /// Action taken once `failure_threshold` is reached
+    pub action: ProviderHealthAction,
+}
+
+impl Default for ProviderHealthCheck {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(30),
+            timeout: Duration::from_secs(10),
+            failure_threshold: 3,
+            action: ProviderHealthAction::default(),
+        }
+    }
+}
+
+/// Capacity limits of the host. The host declines actor and provider auctions once a limit
+/// is reached and rejects commands, which would exceed a limit
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Capacity {
+    /// Maximum amount of distinct actors
+    pub max_actors: Option<usize>,
+    /// Maximum total amount of actor instances
+    pub max_actor_instances: Option<usize>,
+    /// Maximum amount of capability provider processes
+    pub max_providers: Option<usize>,
+    /// Minimum amount of memory, in bytes, which must be available on the system
+    pub min_memory_headroom: Option<u64>,
 }
 
 /// Configuration for wasmCloud policy service
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
 pub struct PolicyService {
     /// The topic to request policy decisions on
     pub policy_topic: Option<String>,
     /// An optional topic to receive updated policy decisions on
     pub policy_changes_topic: Option<String>,
     /// The timeout for policy requests
+    #[serde(deserialize_with = "deserialize_duration")]
     pub policy_timeout_ms: Option<Duration>,
 }
 
+fn deserialize_duration<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Some(duration) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+    humantime::parse_duration(&duration)
+        .map(Some)
+        .map_err(|e| de::Error::custom(format!("failed to parse `{duration}` as a duration: {e}")))
+}
+
+fn deserialize_required_duration<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_duration(deserializer)?.ok_or_else(|| de::Error::custom("missing duration"))
+}
+
+/// Actor resource limits as specified in a host configuration file, see [`ActorLimits`]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ActorLimitsFile {
+    max_memory_bytes: Option<usize>,
+    max_table_elements: Option<u32>,
+    max_instances: Option<usize>,
+}
+
+fn deserialize_actor_limits<'de, D>(deserializer: D) -> Result<Option<ActorLimits>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let limits = Option::<ActorLimitsFile>::deserialize(deserializer)?;
+    Ok(limits.map(
+        |ActorLimitsFile {
+             max_memory_bytes,
+             max_table_elements,
+             max_instances,
+         }| ActorLimits {
+            max_memory_bytes,
+            max_table_elements,
+            max_instances,
+        },
+    ))
+}
+
+/// Host configuration file, which maps onto [`Host`]. Settings are named after the [`Host`]
+/// fields they set and durations are specified in human-readable form, for example `"1500ms"`
+/// or `"2s"`. Settings omitted from the file are left unchanged, with the exception of `labels`
+/// on reload. Tables, such as `capacity`, replace the whole setting they map onto.
+///
+/// `labels`, `log_level`, `oci_opts` and `policy_service_config` can be applied to a running
+/// host using [`crate::wasmbus::Host::reload_config`], all other settings only take effect on
+/// host startup.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct File {
+    /// See [`Host::labels`]. Omitting the labels from the file removes labels previously set
+    /// using the file on reload. Labels put or deleted using the control interface take
+    /// precedence over labels set in the file
+    pub labels: Option<HashMap<String, String>>,
+    /// See [`Host::log_level`]
+    pub log_level: Option<LogLevel>,
+    /// See [`Host::oci_opts`]
+    pub oci_opts: Option<OciConfig>,
+    /// See [`Host::policy_service_config`]
+    pub policy_service_config: Option<PolicyService>,
+    /// See [`Host::lattice_prefix`]
+    pub lattice_prefix: Option<String>,
+    /// See [`Host::js_domain`]
+    pub js_domain: Option<String>,
+    /// See [`Host::rpc_timeout`]
+    #[serde(default, deserialize_with = "deserialize_duration")]
+    pub rpc_timeout: Option<Duration>,
+    /// See [`Host::provider_shutdown_delay`]
+    #[serde(default, deserialize_with = "deserialize_duration")]
+    pub provider_shutdown_delay: Option<Duration>,
+    /// See [`Host::allow_file_load`]
+    pub allow_file_load: Option<bool>,
+    /// See [`Host::enable_structured_logging`]
+    pub enable_structured_logging: Option<bool>,
+    /// See [`Host::config_service_enabled`]
+    pub config_service_enabled: Option<bool>,
+    /// See [`Host::max_execution_time`]
+    #[serde(default, deserialize_with = "deserialize_duration")]
+    pub max_execution_time: Option<Duration>,
+    /// See [`Host::max_fuel`]
+    pub max_fuel: Option<u64>,
+    /// See [`Host::actor_limits`]
+    #[serde(default, deserialize_with = "deserialize_actor_limits")]
+    pub actor_limits: Option<ActorLimits>,
+    /// See [`Host::artifact_cache_dir`]
+    pub artifact_cache_dir: Option<PathBuf>,
+    /// See [`Host::artifact_cache_max_size`]
+    pub artifact_cache_max_size: Option<u64>,
+    /// See [`Host::drain_timeout`]
+    #[serde(default, deserialize_with = "deserialize_duration")]
+    pub drain_timeout: Option<Duration>,
+    /// See [`Host::actor_update_batch_size`]
+    pub actor_update_batch_size: Option<NonZeroUsize>,
+    /// See [`Host::provider_supervision`]
+    pub provider_supervision: Option<ProviderSupervision>,
+    /// See [`Host::provider_health_check`]
+    pub provider_health_check: Option<ProviderHealthCheck>,
+    /// See [`Host::publish_provider_output`]
+    pub publish_provider_output: Option<bool>,
+    /// See [`Host::allowed_http_authorities`]
+    pub allowed_http_authorities: Option<Vec<String>>,
+    /// See [`Host::heartbeat_interval`]
+    #[serde(default, deserialize_with = "deserialize_duration")]
+    pub heartbeat_interval: Option<Duration>,
+    /// See [`Host::capacity`]
+    pub capacity: Option<Capacity>,
+}
+
+impl File {
+    /// Reads a TOML host configuration file from `path`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a valid configuration file
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let config = fs::read_to_string(path)
+            .with_context(|| format!("failed to read `{}`", path.display()))?;
+        toml::from_str(&config).with_context(|| format!("failed to parse `{}`", path.display()))
+    }
+
+    /// Applies all settings present in the file to `config`
+    pub fn apply(&self, config: &mut Host) {
+        if let Some(labels) = &self.labels {
+            config.labels = labels.clone();
+        }
+        if let Some(log_level) = &self.log_level {
+            config.log_level = log_level.clone();
+        }
+        if let Some(oci_opts) = &self.oci_opts {
+            config.oci_opts = oci_opts.clone();
+        }
+        if let Some(policy_service_config) = &self.policy_service_config {
+            config.policy_service_config = policy_service_config.clone();
+        }
+        if let Some(lattice_prefix) = &self.lattice_prefix {
+            config.lattice_prefix = lattice_prefix.clone();
+        }
+        if let Some(js_domain) = &self.js_domain {
+            config.js_domain = Some(js_domain.clone());
+        }
+        if let Some(rpc_timeout) = self.rpc_timeout {
+            config.rpc_timeout = rpc_timeout;
+        }
+        if let Some(provider_shutdown_delay) = self.provider_shutdown_delay {
+            config.provider_shutdown_delay = Some(provider_shutdown_delay);
+        }
+        if let Some(allow_file_load) = self.allow_file_load {
+            config.allow_file_load = allow_file_load;
+        }
+        if let Some(enable_structured_logging) = self.enable_structured_logging {
+            config.enable_structured_logging = enable_structured_logging;
+        }
+        if let Some(config_service_enabled) = self.config_service_enabled {
+            config.config_service_enabled = config_service_enabled;
+        }
+        if let Some(max_execution_time) = self.max_execution_time {
+            config.max_execution_time = Some(max_execution_time);
+        }
+        if let Some(max_fuel) = self.max_fuel {
+            config.max_fuel = Some(max_fuel);
+        }
+        if let Some(actor_limits) = self.actor_limits {
+            config.actor_limits = actor_limits;
+        }
+        if let Some(artifact_cache_dir) = &self.artifact_cache_dir {
+            config.artifact_cache_dir = Some(artifact_cache_dir.clone());
+        }
+        if let Some(artifact_cache_max_size) = self.artifact_cache_max_size {
+            config.artifact_cache_max_size = artifact_cache_max_size;
+        }
+        if let Some(drain_timeout) = self.drain_timeout {
+            config.drain_timeout = drain_timeout;
+        }
+        if let Some(actor_update_batch_size) = self.actor_update_batch_size {
+            config.actor_update_batch_size = actor_update_batch_size;
+        }
+        if let Some(provider_supervision) = &self.provider_supervision {
+            config.provider_supervision = provider_supervision.clone();
+        }
+        if let Some(provider_health_check) = &self.provider_health_check {
+            config.provider_health_check = provider_health_check.clone();
+        }
+        if let Some(publish_provider_output) = self.publish_provider_output {
+            config.publish_provider_output = publish_provider_output;
+        }
+        if let Some(allowed_http_authorities) = &self.allowed_http_authorities {
+            config.allowed_http_authorities = allowed_http_authorities.clone();
+        }
+        if let Some(heartbeat_interval) = self.heartbeat_interval {
+            config.heartbeat_interval = heartbeat_interval;
+        }
+        if let Some(capacity) = &self.capacity {
+            config.capacity = capacity.clone();
+        }
+    }
+
+    /// Returns the names of settings present in the file, which differ from `config`, but can
+    /// only be changed by restarting the host
+    #[must_use]
+    pub fn restart_required(&self, config: &Host) -> Vec<&'static str> {
+        let mut updated = config.clone();
+        self.apply(&mut updated);
+        [
+            (
+                "lattice_prefix",
+                updated.lattice_prefix != config.lattice_prefix,
+            ),
+            ("js_domain", updated.js_domain != config.js_domain),
+            ("rpc_timeout", updated.rpc_timeout != config.rpc_timeout),
+            (
+                "provider_shutdown_delay",
+                updated.provider_shutdown_delay != config.provider_shutdown_delay,
+            ),
+            (
+                "allow_file_load",
+                updated.allow_file_load != config.allow_file_load,
+            ),
+            (
+                "enable_structured_logging",
+                updated.enable_structured_logging != config.enable_structured_logging,
+            ),
+            (
+                "config_service_enabled",
+                updated.config_service_enabled != config.config_service_enabled,
+            ),
+            (
+                "max_execution_time",
+                updated.max_execution_time != config.max_execution_time,
+            ),
+            ("max_fuel", updated.max_fuel != config.max_fuel),
+            ("actor_limits", updated.actor_limits != config.actor_limits),
+            (
+                "artifact_cache_dir",
+                updated.artifact_cache_dir != config.artifact_cache_dir,
+            ),
+            (
+                "artifact_cache_max_size",
+                updated.artifact_cache_max_size != config.artifact_cache_max_size,
+            ),
+            (
+                "drain_timeout",
+                updated.drain_timeout != config.drain_timeout,
+            ),
+            (
+                "actor_update_batch_size",
+                updated.actor_update_batch_size != config.actor_update_batch_size,
+            ),
+            (
+                "provider_supervision",
+                updated.provider_supervision != config.provider_supervision,
+            ),
+            (
+                "provider_health_check",
+                updated.provider_health_check != config.provider_health_check,
+            ),
+            (
+                "publish_provider_output",
+                updated.publish_provider_output != config.publish_provider_output,
+            ),
+            (
+                "allowed_http_authorities",
+                updated.allowed_http_authorities != config.allowed_http_authorities,
+            ),
+            (
+                "heartbeat_interval",
+                updated.heartbeat_interval != config.heartbeat_interval,
+            ),
+            ("capacity", updated.capacity != config.capacity),
+        ]
+        .into_iter()
+        .filter_map(|(name, changed)| changed.then_some(name))
+        .collect()
+    }
+}
+
 impl Default for Host {
     fn default() -> Self {
         Self {
@@ -111,6 +584,20 @@ impl Default for Host {
             config_service_enabled: false,
             otel_config: OtelConfig::default(),
             policy_service_config: PolicyService::default(),
+            max_execution_time: None,
+            max_fuel: None,
+            actor_limits: ActorLimits::default(),
+            artifact_cache_dir: None,
+            artifact_cache_max_size: 1 << 30,
+            drain_timeout: Duration::from_secs(10),
+            actor_update_batch_size: NonZeroUsize::MIN,
+            provider_supervision: ProviderSupervision::default(),
+            provider_health_check: ProviderHealthCheck::default(),
+            publish_provider_output: false,
+            allowed_http_authorities: Vec::default(),
+            labels: HashMap::default(),
+            heartbeat_interval: Duration::from_secs(30),
+            capacity: Capacity::default(),
         }
     }
 }
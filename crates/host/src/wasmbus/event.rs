@@ -1,6 +1,8 @@
 use core::num::NonZeroUsize;
 
 use std::collections::{BTreeMap, HashMap};
+use std::io;
+use std::process::ExitStatus;
 
 use anyhow::Context;
 use cloudevents::{EventBuilder, EventBuilderV10};
@@ -10,6 +12,7 @@ use tracing::instrument;
 use ulid::Ulid;
 use uuid::Uuid;
 use wascap::jwt;
+use wasmcloud_core::logging::Level;
 
 fn format_actor_claims(claims: &jwt::Claims<jwt::Actor>) -> serde_json::Value {
     let issuer = &claims.issuer;
@@ -71,6 +74,22 @@ pub fn actor_stopped(
     })
 }
 
+pub fn actor_invocation_timed_out(
+    claims: &jwt::Claims<jwt::Actor>,
+    instance_id: Uuid,
+    invocation_id: impl AsRef<str>,
+    operation: impl AsRef<str>,
+    origin: impl AsRef<str>,
+) -> serde_json::Value {
+    json!({
+        "public_key": claims.subject,
+        "instance_id": instance_id,
+        "invocation_id": invocation_id.as_ref(),
+        "operation": operation.as_ref(),
+        "origin": origin.as_ref(),
+    })
+}
+
 pub fn actors_started(
     claims: &jwt::Claims<jwt::Actor>,
     annotations: &BTreeMap<String, String>,
@@ -110,6 +129,8 @@ pub fn actors_stopped(
     host_id: impl AsRef<str>,
     count: NonZeroUsize,
     remaining: usize,
+    drained: usize,
+    abandoned: usize,
 ) -> serde_json::Value {
     json!({
         "host_id": host_id.as_ref(),
@@ -117,6 +138,33 @@ pub fn actors_stopped(
         "count": count,
         "remaining": remaining,
         "annotations": annotations,
+        "drained": drained,
+        "abandoned": abandoned,
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn actor_update_progress(
+    claims: &jwt::Claims<jwt::Actor>,
+    annotations: &BTreeMap<String, String>,
+    host_id: impl AsRef<str>,
+    image_ref: impl AsRef<str>,
+    new_image_ref: impl AsRef<str>,
+    updated: usize,
+    total: usize,
+    status: impl AsRef<str>,
+    error: Option<&anyhow::Error>,
+) -> serde_json::Value {
+    json!({
+        "host_id": host_id.as_ref(),
+        "public_key": claims.subject,
+        "image_ref": image_ref.as_ref(),
+        "new_image_ref": new_image_ref.as_ref(),
+        "annotations": annotations,
+        "updated": updated,
+        "total": total,
+        "status": status.as_ref(),
+        "error": error.map(|error| format!("{error:#}")),
     })
 }
 
@@ -216,6 +264,55 @@ pub fn provider_stopped(
     })
 }
 
+pub fn provider_crashed(
+    claims: &jwt::Claims<jwt::CapabilityProvider>,
+    annotations: &BTreeMap<String, String>,
+    instance_id: Uuid,
+    host_id: impl AsRef<str>,
+    link_name: impl AsRef<str>,
+    exit_status: &io::Result<ExitStatus>,
+    restarts: u32,
+) -> serde_json::Value {
+    let metadata = claims.metadata.as_ref();
+    let (exit_code, exit_status) = match exit_status {
+        Ok(status) => (status.code(), status.to_string()),
+        Err(err) => (None, format!("failed to wait for provider process: {err}")),
+    };
+    json!({
+        "host_id": host_id.as_ref(),
+        "public_key": claims.subject,
+        "link_name": link_name.as_ref(),
+        "contract_id": metadata.map(|jwt::CapabilityProvider { capid, .. }| capid),
+        "instance_id": instance_id,
+        "annotations": annotations,
+        "exit_code": exit_code,
+        "exit_status": exit_status,
+        "restarts": restarts,
+    })
+}
+
+pub fn provider_unhealthy(
+    claims: &jwt::Claims<jwt::CapabilityProvider>,
+    annotations: &BTreeMap<String, String>,
+    instance_id: Uuid,
+    host_id: impl AsRef<str>,
+    link_name: impl AsRef<str>,
+    failures: u32,
+    action: impl AsRef<str>,
+) -> serde_json::Value {
+    let metadata = claims.metadata.as_ref();
+    json!({
+        "host_id": host_id.as_ref(),
+        "public_key": claims.subject,
+        "link_name": link_name.as_ref(),
+        "contract_id": metadata.map(|jwt::CapabilityProvider { capid, .. }| capid),
+        "instance_id": instance_id,
+        "annotations": annotations,
+        "failures": failures,
+        "action": action.as_ref(),
+    })
+}
+
 pub fn provider_health_check(
     public_key: impl AsRef<str>,
     link_name: impl AsRef<str>,
@@ -228,6 +325,42 @@ pub fn provider_health_check(
     })
 }
 
+pub fn host_capacity_exceeded(
+    host_id: impl AsRef<str>,
+    limit: impl AsRef<str>,
+    max: usize,
+    requested: usize,
+) -> serde_json::Value {
+    json!({
+        "host_id": host_id.as_ref(),
+        "limit": limit.as_ref(),
+        "max": max,
+        "requested": requested,
+    })
+}
+
+pub fn labels_changed(
+    host_id: impl AsRef<str>,
+    labels: &HashMap<String, String>,
+) -> serde_json::Value {
+    json!({
+        "host_id": host_id.as_ref(),
+        "labels": labels,
+    })
+}
+
+pub fn host_config_updated(
+    labels: &HashMap<String, String>,
+    log_level: &Level,
+    restart_required: &[&str],
+) -> serde_json::Value {
+    json!({
+        "labels": labels,
+        "log_level": log_level,
+        "restart_required": restart_required,
+    })
+}
+
 #[instrument(skip(event_builder, ctl_nats, name))]
 pub(crate) async fn publish(
     event_builder: &EventBuilderV10,
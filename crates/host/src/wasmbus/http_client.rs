@@ -0,0 +1,159 @@
+use core::time::Duration;
+
+use std::collections::HashSet;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use anyhow::Context;
+use async_trait::async_trait;
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::time::timeout;
+use tracing::instrument;
+use url::Url;
+use wasmcloud_runtime::capability::http::types::RequestOptions;
+use wasmcloud_runtime::capability::OutgoingHttp;
+
+/// Maximum amount of redirects followed for a single request
+const MAX_REDIRECTS: usize = 10;
+
+/// Whether `authority` or, if it contains a port, its host is contained in `allowed_authorities`
+fn authority_allowed(allowed_authorities: &HashSet<String>, authority: &str) -> bool {
+    let authority = authority.to_ascii_lowercase();
+    allowed_authorities.contains(&authority)
+        || allowed_authorities.contains(
+            authority
+                .rsplit_once(':')
+                .map_or(authority.as_str(), |(host, _)| host),
+        )
+}
+
+/// Returns the authority of `url`, if any
+fn url_authority(url: &Url) -> Option<String> {
+    let host = url.host_str()?;
+    Some(match url.port() {
+        Some(port) => format!("{host}:{port}"),
+        None => host.to_string(),
+    })
+}
+
+/// [`OutgoingHttp`] implementation sending requests directly from the host to a fixed set of
+/// allowed authorities
+#[derive(Clone, Debug)]
+pub(crate) struct HostHttpClient {
+    client: reqwest::Client,
+    allowed_authorities: Arc<HashSet<String>>,
+}
+
+impl HostHttpClient {
+    /// Constructs a new [`HostHttpClient`] allowing requests to `allowed_authorities` only,
+    /// returns `None` if no authorities are allowed.
+    /// Connecting and, unless a request sets shorter timeouts, whole requests time out after
+    /// `timeout`
+    pub(crate) fn new(
+        allowed_authorities: impl IntoIterator<Item = String>,
+        timeout: Duration,
+    ) -> anyhow::Result<Option<Self>> {
+        let allowed_authorities: HashSet<_> = allowed_authorities
+            .into_iter()
+            .map(|authority| authority.to_ascii_lowercase())
+            .collect();
+        if allowed_authorities.is_empty() {
+            return Ok(None);
+        }
+        let allowed_authorities = Arc::new(allowed_authorities);
+        // Redirects must only be followed to allowed authorities, since the allow-list could
+        // otherwise be bypassed by an allowed authority redirecting to an arbitrary URL
+        let redirect_authorities = Arc::clone(&allowed_authorities);
+        let redirect = reqwest::redirect::Policy::custom(move |attempt| {
+            let allowed = url_authority(attempt.url())
+                .is_some_and(|authority| authority_allowed(&redirect_authorities, &authority));
+            if !allowed {
+                let err = format!("redirect to `{}` is not allowed", attempt.url());
+                attempt.error(err)
+            } else if attempt.previous().len() > MAX_REDIRECTS {
+                attempt.error("too many redirects")
+            } else {
+                attempt.follow()
+            }
+        });
+        let client = reqwest::Client::builder()
+            .redirect(redirect)
+            .connect_timeout(timeout)
+            .timeout(timeout)
+            .build()
+            .context("failed to build HTTP client")?;
+        Ok(Some(Self {
+            client,
+            allowed_authorities,
+        }))
+    }
+
+    /// Whether requests to `uri` may be sent directly from the host
+    pub(crate) fn allows(&self, uri: &http::Uri) -> bool {
+        uri.authority().is_some_and(|authority| {
+            authority_allowed(&self.allowed_authorities, authority.as_str())
+        })
+    }
+}
+
+#[async_trait]
+impl OutgoingHttp for HostHttpClient {
+    #[instrument(skip(self, request), fields(uri = %request.uri()))]
+    async fn handle(
+        &self,
+        request: http::Request<Box<dyn AsyncRead + Sync + Send + Unpin>>,
+        options: Option<RequestOptions>,
+    ) -> anyhow::Result<http::Response<Box<dyn AsyncRead + Sync + Send + Unpin>>> {
+        anyhow::ensure!(
+            self.allows(request.uri()),
+            "authority of `{}` is not allowed",
+            request.uri()
+        );
+        let (
+            http::request::Parts {
+                method,
+                uri,
+                headers,
+                ..
+            },
+            mut body,
+        ) = request.into_parts();
+        let mut buf = vec![];
+        body.read_to_end(&mut buf)
+            .await
+            .context("failed to read request body")?;
+        let req = self
+            .client
+            .request(method, uri.to_string())
+            .headers(headers)
+            .body(buf)
+            .send();
+        // `reqwest` does not support per-request connect timeouts, so the connect and first byte
+        // timeouts are combined into a single timeout for receiving the response head. Reading
+        // the response body is only bounded by the timeout of the client
+        let res = match options {
+            Some(RequestOptions {
+                connect_timeout_ms,
+                first_byte_timeout_ms,
+                ..
+            }) if connect_timeout_ms.is_some() || first_byte_timeout_ms.is_some() => {
+                let timeout_ms = u64::from(connect_timeout_ms.unwrap_or_default())
+                    + u64::from(first_byte_timeout_ms.unwrap_or_default());
+                timeout(Duration::from_millis(timeout_ms), req)
+                    .await
+                    .context("timed out waiting for response")?
+            }
+            _ => req.await,
+        }
+        .context("failed to send request")?;
+        let status = res.status();
+        let headers = res.headers().clone();
+        let body = res.bytes().await.context("failed to read response body")?;
+        let mut res = http::Response::builder().status(status);
+        if let Some(res_headers) = res.headers_mut() {
+            *res_headers = headers;
+        }
+        res.body(Box::new(Cursor::new(body)) as Box<dyn AsyncRead + Sync + Send + Unpin>)
+            .context("failed to build response")
+    }
+}
@@ -3,29 +3,37 @@ pub mod config;
 
 pub use config::Host as HostConfig;
 
+use config::{PolicyService, ProviderHealthAction, ProviderHealthCheck, ProviderSupervision};
+
 mod event;
+mod http_client;
+
+use http_client::HostHttpClient;
 
 use crate::{
-    fetch_actor, socket_pair, OciConfig, PolicyAction, PolicyHostInfo, PolicyManager,
+    fetch_actor, image_cached, socket_pair, OciConfig, PolicyAction, PolicyHostInfo, PolicyManager,
     PolicyRequestSource, PolicyRequestTarget, PolicyResponse, RegistryAuth, RegistryConfig,
     RegistryType,
 };
 
+use core::fmt;
 use core::future::Future;
 use core::num::NonZeroUsize;
 use core::ops::{Deref, RangeInclusive};
 use core::pin::Pin;
-use core::task::{Context, Poll};
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::task::{ready, Context, Poll};
 use core::time::Duration;
 
 use std::collections::hash_map::{self, Entry};
 use std::collections::{BTreeMap, HashMap};
 use std::env;
 use std::env::consts::{ARCH, FAMILY, OS};
-use std::io::Cursor;
-use std::process::Stdio;
+use std::io::{self, Cursor};
+use std::path::{Path, PathBuf};
+use std::process::{ExitStatus, Stdio};
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 
 use anyhow::{anyhow, bail, ensure, Context as _};
 use async_trait::async_trait;
@@ -33,16 +41,20 @@ use base64::engine::general_purpose::STANDARD;
 use base64::Engine;
 use bytes::{BufMut, Bytes, BytesMut};
 use cloudevents::{EventBuilder, EventBuilderV10};
+use futures::future::join_all;
 use futures::stream::{AbortHandle, Abortable};
 use futures::{join, stream, try_join, FutureExt, Stream, StreamExt, TryFutureExt, TryStreamExt};
 use nkeys::{KeyPair, KeyPairType};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use sha2::{Digest, Sha256};
-use tokio::io::{empty, stderr, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
-use tokio::sync::{watch, RwLock};
+use tokio::io::{
+    empty, stderr, AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader,
+    ReadBuf,
+};
+use tokio::sync::{watch, Mutex, RwLock};
 use tokio::task::JoinHandle;
-use tokio::time::{interval_at, Instant};
+use tokio::time::{interval_at, sleep, timeout_at, Instant};
 use tokio::{process, select, spawn};
 use tokio_stream::wrappers::IntervalStream;
 use tracing::{debug, error, info, instrument, trace, warn};
@@ -50,32 +62,40 @@ use ulid::Ulid;
 use uuid::Uuid;
 use wascap::{jwt, prelude::ClaimsBuilder};
 use wasmcloud_control_interface::{
-    ActorAuctionAck, ActorAuctionRequest, ActorDescription, HostInventory, LinkDefinition,
-    LinkDefinitionList, ProviderAuctionRequest, ProviderDescription, RegistryCredential,
-    RegistryCredentialMap, RemoveLinkDefinitionRequest, ScaleActorCommand, StartActorCommand,
-    StartProviderCommand, StopActorCommand, StopHostCommand, StopProviderCommand,
-    UpdateActorCommand,
+    ActorAuctionRequest, ActorDescription, HostInventory, LinkDefinition, LinkDefinitionList,
+    ProviderAuctionRequest, ProviderDescription, RegistryCredential, RegistryCredentialMap,
+    RemoveLinkDefinitionRequest, ScaleActorCommand, StartActorCommand, StartProviderCommand,
+    StopActorCommand, StopHostCommand, StopProviderCommand, UpdateActorCommand,
 };
 use wasmcloud_core::chunking::{ChunkEndpoint, CHUNK_RPC_EXTRA_TIME, CHUNK_THRESHOLD_BYTES};
 use wasmcloud_core::{
     HealthCheckResponse, HostData, Invocation, InvocationResponse, OtelConfig, WasmCloudEntity,
 };
+use wasmcloud_runtime::capability::http::types::RequestOptions;
 use wasmcloud_runtime::capability::logging::logging;
 use wasmcloud_runtime::capability::{
-    blobstore, messaging, ActorIdentifier, Blobstore, Bus, IncomingHttp, KeyValueAtomic,
-    KeyValueReadWrite, Logging, Messaging, TargetEntity, TargetInterface,
+    blobstore, messaging, ActorIdentifier, Blobstore, Bus, KeyValueAtomic, KeyValueReadWrite,
+    Logging, Messaging, OutgoingHttp, TargetEntity, TargetInterface,
 };
-use wasmcloud_runtime::Runtime;
+use wasmcloud_runtime::{is_timeout, ActorLimits, InstancePool, Runtime};
 use wasmcloud_tracing::context::TraceContextInjector;
 
 const SUCCESS: &str = r#"{"accepted":true,"error":""}"#;
 
+/// Annotation overriding [`ActorLimits::max_memory_bytes`] configured for the host
+const MAX_MEMORY_BYTES_ANNOTATION: &str = "wasmcloud.dev/max-memory-bytes";
+/// Annotation overriding [`ActorLimits::max_table_elements`] configured for the host
+const MAX_TABLE_ELEMENTS_ANNOTATION: &str = "wasmcloud.dev/max-table-elements";
+/// Annotation overriding [`ActorLimits::max_instances`] configured for the host
+const MAX_WASM_INSTANCES_ANNOTATION: &str = "wasmcloud.dev/max-wasm-instances";
+
 #[derive(Debug)]
 struct Queue {
     auction: async_nats::Subscriber,
     commands: async_nats::Subscriber,
     pings: async_nats::Subscriber,
     inventory: async_nats::Subscriber,
+    labels: async_nats::Subscriber,
     links: async_nats::Subscriber,
     queries: async_nats::Subscriber,
     registries: async_nats::Subscriber,
@@ -111,6 +131,11 @@ impl Stream for Queue {
             Poll::Ready(None) => {}
             Poll::Pending => pending = true,
         }
+        match Pin::new(&mut self.labels).poll_next(cx) {
+            Poll::Ready(Some(msg)) => return Poll::Ready(Some(msg)),
+            Poll::Ready(None) => {}
+            Poll::Pending => pending = true,
+        }
         match Pin::new(&mut self.auction).poll_next(cx) {
             Poll::Ready(Some(msg)) => return Poll::Ready(Some(msg)),
             Poll::Ready(None) => {}
@@ -180,7 +205,7 @@ impl Queue {
         host_key: &KeyPair,
     ) -> anyhow::Result<Self> {
         let host_id = host_key.public_key();
-        let (registries, pings, links, queries, auction, commands, inventory) = try_join!(
+        let (registries, pings, links, queries, auction, commands, inventory, labels) = try_join!(
             nats.subscribe(format!("{topic_prefix}.{lattice_prefix}.registries.put",)),
             nats.subscribe(format!("{topic_prefix}.{lattice_prefix}.ping.hosts",)),
             nats.subscribe(format!("{topic_prefix}.{lattice_prefix}.linkdefs.*",)),
@@ -188,6 +213,9 @@ impl Queue {
             nats.subscribe(format!("{topic_prefix}.{lattice_prefix}.auction.>",)),
             nats.subscribe(format!("{topic_prefix}.{lattice_prefix}.cmd.{host_id}.*",)),
             nats.subscribe(format!("{topic_prefix}.{lattice_prefix}.get.{host_id}.inv",)),
+            nats.subscribe(format!(
+                "{topic_prefix}.{lattice_prefix}.labels.{host_id}.*",
+            )),
         )
         .context("failed to subscribe to queues")?;
         Ok(Self {
@@ -195,6 +223,7 @@ impl Queue {
             commands,
             pings,
             inventory,
+            labels,
             links,
             queries,
             registries,
@@ -204,12 +233,23 @@ impl Queue {
 
 #[derive(Debug)]
 struct ActorInstance {
-    actor: wasmcloud_runtime::Actor,
+    /// Pool of actor instances shared by all [`ActorInstance`]s started for the same actor
+    pool: InstancePool,
     nats: async_nats::Client,
     id: Ulid,
     calls: AbortHandle,
+    /// Set to stop accepting new invocations
+    draining: watch::Sender<bool>,
+    /// Amount of invocations currently being handled
+    in_flight: watch::Sender<usize>,
+    /// Amount of invocations handled since the last heartbeat
+    invocations: AtomicU64,
+    /// Amount of invocations, which failed, since the last heartbeat
+    errors: AtomicU64,
     handler: Handler,
     chunk_endpoint: ChunkEndpoint,
+    ctl_nats: async_nats::Client,
+    event_builder: EventBuilderV10,
     /// Cluster issuers that this actor should accept invocations from
     valid_issuers: Vec<String>,
     policy_manager: Arc<PolicyManager>,
@@ -221,7 +261,52 @@ impl Deref for ActorInstance {
     type Target = wasmcloud_runtime::Actor;
 
     fn deref(&self) -> &Self::Target {
-        &self.actor
+        self.pool.actor()
+    }
+}
+
+/// Link value overriding the host RPC timeout, in milliseconds, for calls made by the actor
+/// over the link
+const LINK_RPC_TIMEOUT_MS: &str = "rpc_timeout_ms";
+
+/// Provider target of an actor link
+#[derive(Clone, Debug)]
+struct LinkTarget {
+    entity: WasmCloudEntity,
+    /// Timeout of calls made over the link, overriding the host RPC timeout
+    rpc_timeout: Option<Duration>,
+}
+
+impl LinkTarget {
+    fn new(
+        LinkDefinition {
+            link_name,
+            contract_id,
+            provider_id,
+            values,
+            ..
+        }: &LinkDefinition,
+    ) -> Self {
+        let rpc_timeout = values.get(LINK_RPC_TIMEOUT_MS).and_then(|timeout| {
+            timeout
+                .parse()
+                .map(Duration::from_millis)
+                .map_err(|err| {
+                    warn!(
+                        ?err,
+                        timeout, "failed to parse `{LINK_RPC_TIMEOUT_MS}` link value, ignoring"
+                    );
+                })
+                .ok()
+        });
+        Self {
+            entity: WasmCloudEntity {
+                link_name: link_name.clone(),
+                contract_id: contract_id.clone(),
+                public_key: provider_id.clone(),
+            },
+            rpc_timeout,
+        }
     }
 }
 
@@ -233,42 +318,76 @@ struct Handler {
     host_key: Arc<KeyPair>,
     claims: jwt::Claims<jwt::Actor>,
     origin: WasmCloudEntity,
-    // package -> target -> entity
-    links: Arc<RwLock<HashMap<String, HashMap<String, WasmCloudEntity>>>>,
+    /// Timeout of calls made by the actor, unless overridden by the link
+    rpc_timeout: Duration,
+    // package -> target -> link target
+    links: Arc<RwLock<HashMap<String, HashMap<String, LinkTarget>>>>,
     targets: Arc<RwLock<HashMap<TargetInterface, TargetEntity>>>,
     aliases: Arc<RwLock<HashMap<String, WasmCloudEntity>>>,
     chunk_endpoint: ChunkEndpoint,
+    /// Client used for outgoing HTTP requests to authorities, which the host is allowed to call directly
+    http_client: Option<HostHttpClient>,
 }
 
+/// Resolves the `target` entity of a call, returns the entity and the RPC timeout override
+/// of the link used, if any
 #[instrument]
 async fn resolve_target(
     target: Option<&TargetEntity>,
-    links: Option<&HashMap<String, WasmCloudEntity>>,
+    links: Option<&HashMap<String, LinkTarget>>,
     aliases: &HashMap<String, WasmCloudEntity>,
-) -> anyhow::Result<WasmCloudEntity> {
+) -> anyhow::Result<(WasmCloudEntity, Option<Duration>)> {
     const DEFAULT_LINK_NAME: &str = "default";
 
     trace!("resolve target");
 
-    let target = match target {
-        None => links
-            .and_then(|targets| targets.get(DEFAULT_LINK_NAME))
-            .context("link not found")?
-            .clone(),
-        Some(TargetEntity::Link(link_name)) => links
-            .and_then(|targets| targets.get(link_name.as_deref().unwrap_or(DEFAULT_LINK_NAME)))
-            .context("link not found")?
-            .clone(),
-        Some(TargetEntity::Actor(ActorIdentifier::Key(key))) => WasmCloudEntity {
-            public_key: key.public_key(),
-            ..Default::default()
-        },
-        Some(TargetEntity::Actor(ActorIdentifier::Alias(alias))) => aliases
-            .get(alias)
-            .context("unknown actor call alias")?
-            .clone(),
+    let link_name = match target {
+        None => DEFAULT_LINK_NAME,
+        Some(TargetEntity::Link(link_name)) => link_name.as_deref().unwrap_or(DEFAULT_LINK_NAME),
+        Some(TargetEntity::Actor(ActorIdentifier::Key(key))) => {
+            let target = WasmCloudEntity {
+                public_key: key.public_key(),
+                ..Default::default()
+            };
+            return Ok((target, None));
+        }
+        Some(TargetEntity::Actor(ActorIdentifier::Alias(alias))) => {
+            let target = aliases
+                .get(alias)
+                .context("unknown actor call alias")?
+                .clone();
+            return Ok((target, None));
+        }
     };
-    Ok(target)
+    let LinkTarget {
+        entity,
+        rpc_timeout,
+    } = links
+        .and_then(|targets| targets.get(link_name))
+        .context("link not found")?;
+    Ok((entity.clone(), *rpc_timeout))
+}
+
+/// Additional time granted to providers for responding to operations, which are bounded by a
+/// timeout themselves, like messaging requests
+const OPERATION_TIMEOUT_MARGIN: Duration = Duration::from_secs(1);
+
+/// Returns the timeout of a call to an operation, which is bounded by `operation_timeout` itself.
+/// The call is allowed to take at least as long as the operation plus a margin for the provider
+/// to respond
+fn operation_rpc_timeout(timeout: Duration, operation_timeout: Option<Duration>) -> Duration {
+    operation_timeout.map_or(timeout, |operation_timeout| {
+        timeout.max(operation_timeout.saturating_add(OPERATION_TIMEOUT_MARGIN))
+    })
+}
+
+/// Returns the total timeout of a call, accounting for the extra time needed to chunk the request
+fn rpc_timeout(timeout: Duration, needs_chunking: bool) -> Duration {
+    if needs_chunking {
+        timeout + CHUNK_RPC_EXTRA_TIME
+    } else {
+        timeout
+    }
 }
 
 impl Handler {
@@ -278,6 +397,7 @@ impl Handler {
         target: Option<&TargetEntity>,
         operation: impl Into<String>,
         request: Vec<u8>,
+        operation_timeout: Option<Duration>,
     ) -> anyhow::Result<Result<Vec<u8>, String>> {
         let links = self.links.read().await;
         let aliases = self.aliases.read().await;
@@ -285,7 +405,8 @@ impl Handler {
         let (package, _) = operation
             .rsplit_once('/')
             .context("failed to parse operation")?;
-        let inv_target = resolve_target(target, links.get(package), &aliases).await?;
+        let (inv_target, link_rpc_timeout) =
+            resolve_target(target, links.get(package), &aliases).await?;
         let needs_chunking = request.len() > CHUNK_THRESHOLD_BYTES;
         let injector = TraceContextInjector::default_with_span();
         let headers = injector_to_headers(&injector);
@@ -313,6 +434,13 @@ impl Handler {
             invocation.msg = vec![];
         }
 
+        let timeout = operation_rpc_timeout(
+            link_rpc_timeout.unwrap_or(self.rpc_timeout),
+            operation_timeout,
+        );
+        let timeout = rpc_timeout(timeout, needs_chunking);
+        invocation.set_timeout(timeout);
+
         let payload =
             rmp_serde::to_vec_named(&invocation).context("failed to encode invocation")?;
         let topic = match target {
@@ -326,10 +454,9 @@ impl Handler {
             ),
         };
 
-        let timeout = needs_chunking.then_some(CHUNK_RPC_EXTRA_TIME); // TODO: add rpc_nats timeout
         let request = async_nats::Request::new()
             .payload(payload.into())
-            .timeout(timeout)
+            .timeout(Some(timeout))
             .headers(headers); // TODO: remove headers once all providers are built off the new SDK, which parses the trace context in the invocation
         let res = self
             .nats
@@ -371,13 +498,205 @@ impl Handler {
         target: Option<&TargetEntity>,
         operation: impl Into<String>,
         request: &impl Serialize,
+    ) -> anyhow::Result<Vec<u8>> {
+        self.call_operation_with_timeout(target, operation, request, None)
+            .await
+    }
+
+    /// Calls `operation`, which is bounded by `operation_timeout` itself, see
+    /// [`operation_rpc_timeout`]
+    #[instrument(skip(self, operation, request))]
+    async fn call_operation_with_timeout(
+        &self,
+        target: Option<&TargetEntity>,
+        operation: impl Into<String>,
+        request: &impl Serialize,
+        operation_timeout: Option<Duration>,
     ) -> anyhow::Result<Vec<u8>> {
         let request = rmp_serde::to_vec_named(request).context("failed to encode request")?;
-        self.call_operation_with_payload(target, operation, request)
+        self.call_operation_with_payload(target, operation, request, operation_timeout)
             .await
             .context("failed to call target entity")?
             .map_err(|err| anyhow!(err).context("call failed"))
     }
+
+    /// Requests the inclusive `range` of an object from the blobstore provider. Providers may
+    /// return fewer bytes than requested if the object ends within the range.
+    #[instrument(skip(self))]
+    async fn get_object_range(
+        &self,
+        container: &str,
+        name: &str,
+        range: RangeInclusive<u64>,
+    ) -> anyhow::Result<Vec<u8>> {
+        let targets = self.targets.read().await;
+        let res = self
+            .call_operation(
+                targets.get(&TargetInterface::WasiBlobstoreBlobstore),
+                "wasmcloud:blobstore/Blobstore.GetObject",
+                &wasmcloud_compat::blobstore::GetObjectRequest {
+                    object_id: name.into(),
+                    container_id: container.into(),
+                    range_start: Some(*range.start()),
+                    range_end: Some(*range.end()),
+                },
+            )
+            .await?;
+        let wasmcloud_compat::blobstore::GetObjectResponse {
+            success,
+            error,
+            initial_chunk,
+            ..
+        } = decode_provider_response(res)?;
+        match (success, initial_chunk, error) {
+            (_, _, Some(err)) => Err(anyhow!(err).context("failed to get object response")),
+            (false, _, None) => bail!("failed to get object response"),
+            (true, None, None) => Ok(vec![]),
+            (
+                true,
+                Some(wasmcloud_compat::blobstore::Chunk {
+                    object_id,
+                    container_id,
+                    bytes,
+                    ..
+                }),
+                None,
+            ) => {
+                ensure!(object_id == name);
+                ensure!(container_id == container);
+                Ok(bytes)
+            }
+        }
+    }
+
+    /// Writes the first `chunk` of an object to the blobstore provider. Returns the stream ID to
+    /// use for subsequent chunks, if the provider supports chunked uploads and the chunk is not
+    /// the last one.
+    #[instrument(skip(self, chunk))]
+    async fn put_object(
+        &self,
+        chunk: wasmcloud_compat::blobstore::Chunk,
+    ) -> anyhow::Result<Option<String>> {
+        let targets = self.targets.read().await;
+        let res = self
+            .call_operation(
+                targets.get(&TargetInterface::WasiBlobstoreBlobstore),
+                "wasmcloud:blobstore/Blobstore.PutObject",
+                &wasmcloud_compat::blobstore::PutObjectRequest {
+                    chunk,
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let wasmcloud_compat::blobstore::PutObjectResponse { stream_id } =
+            decode_provider_response(res)?;
+        Ok(stream_id)
+    }
+
+    /// Writes a subsequent `chunk` of an object uploaded using `stream_id` to the blobstore provider
+    #[instrument(skip(self, chunk))]
+    async fn put_chunk(
+        &self,
+        chunk: wasmcloud_compat::blobstore::Chunk,
+        stream_id: String,
+    ) -> anyhow::Result<()> {
+        let targets = self.targets.read().await;
+        let res = self
+            .call_operation(
+                targets.get(&TargetInterface::WasiBlobstoreBlobstore),
+                "wasmcloud:blobstore/Blobstore.PutChunk",
+                &wasmcloud_compat::blobstore::PutChunkRequest {
+                    chunk,
+                    stream_id: Some(stream_id),
+                    cancel_and_remove: false,
+                },
+            )
+            .await?;
+        decode_empty_provider_response(res)
+    }
+
+    /// Copies an object by streaming it from and back to the blobstore provider in chunks of at
+    /// most [`BLOBSTORE_READ_WINDOW_BYTES`], used when the provider does not support server-side
+    /// copies
+    #[instrument(skip(self))]
+    async fn copy_object_data(
+        &self,
+        src_container: &str,
+        src_name: String,
+        dest_container: &str,
+        dest_name: String,
+    ) -> anyhow::Result<()> {
+        let (mut data, size) = self
+            .get_data(src_container, src_name, 0..=u64::MAX)
+            .await
+            .context("failed to read source object")?;
+        let mut offset = 0;
+        let mut stream_id: Option<String> = None;
+        loop {
+            let mut bytes = Vec::new();
+            (&mut data)
+                .take(BLOBSTORE_READ_WINDOW_BYTES)
+                .read_to_end(&mut bytes)
+                .await
+                .context("failed to read source object")?;
+            let n: u64 = bytes
+                .len()
+                .try_into()
+                .context("chunk size does not fit in `u64`")?;
+            let is_last = offset + n >= size;
+            ensure!(n > 0 || is_last, "source object is shorter than reported");
+            let chunk = wasmcloud_compat::blobstore::Chunk {
+                object_id: dest_name.clone(),
+                container_id: dest_container.into(),
+                bytes,
+                offset,
+                is_last,
+            };
+            if let Some(stream_id) = &stream_id {
+                self.put_chunk(chunk, stream_id.clone())
+                    .await
+                    .context("failed to write destination object chunk")?;
+            } else {
+                ensure!(offset == 0, "provider does not support chunked uploads");
+                stream_id = self
+                    .put_object(chunk)
+                    .await
+                    .context("failed to write destination object")?;
+            }
+            if is_last {
+                return Ok(());
+            }
+            offset += n;
+        }
+    }
+}
+
+/// Returns the link definitions in `links`, which target the provider with `provider_id` and
+/// `link_name`, as passed to the provider on start
+fn provider_link_definitions(
+    links: &HashMap<String, LinkDefinition>,
+    provider_id: &str,
+    link_name: &str,
+) -> Vec<wasmcloud_core::LinkDefinition> {
+    // TODO: update type of links to use wasmcloud_core::LinkDefinition
+    links
+        .values()
+        .filter(|ld| ld.provider_id == provider_id && ld.link_name == link_name)
+        .map(|ld| wasmcloud_core::LinkDefinition {
+            actor_id: ld.actor_id.clone(),
+            provider_id: ld.provider_id.clone(),
+            link_name: ld.link_name.clone(),
+            contract_id: ld.contract_id.clone(),
+            values: ld.values.clone().into_iter().collect(),
+        })
+        .collect()
+}
+
+/// Returns `true` if `err` returned by a provider indicates, that the provider does not handle
+/// the invoked operation
+fn is_method_not_handled(err: &str) -> bool {
+    // NOTE: This is the `Display` representation of `wasmbus_rpc::error::RpcError::MethodNotHandled`
+    err.starts_with("method not handled")
 }
 
 /// Decode provider response accounting for the custom wasmbus-rpc encoding format
@@ -403,6 +722,74 @@ fn decode_empty_provider_response(buf: impl AsRef<[u8]>) -> anyhow::Result<()> {
     }
 }
 
+/// Maximum number of bytes requested from a blobstore provider in a single `GetObject` call, kept
+/// below [`CHUNK_THRESHOLD_BYTES`], so that responses are never chunked
+const BLOBSTORE_READ_WINDOW_BYTES: u64 = 512 * 1024;
+
+/// Object data read from a blobstore provider, which fetches the next window of the object only
+/// once the previous one has been consumed
+struct ObjectReader {
+    handler: Handler,
+    container: String,
+    name: String,
+    /// Offset of the next byte to fetch
+    offset: u64,
+    /// Offset of the last byte to fetch (inclusive)
+    end: u64,
+    buf: Cursor<Vec<u8>>,
+    fetch: Option<JoinHandle<anyhow::Result<Vec<u8>>>>,
+}
+
+impl AsyncRead for ObjectReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        loop {
+            let filled = buf.filled().len();
+            ready!(Pin::new(&mut self.buf).poll_read(cx, buf))?;
+            if buf.filled().len() > filled || buf.remaining() == 0 || self.offset > self.end {
+                return Poll::Ready(Ok(()));
+            }
+            let Self {
+                handler,
+                container,
+                name,
+                offset,
+                end,
+                fetch,
+                ..
+            } = &mut *self;
+            let task = fetch.get_or_insert_with(|| {
+                let handler = handler.clone();
+                let container = container.clone();
+                let name = name.clone();
+                let range =
+                    *offset..=(*end).min(offset.saturating_add(BLOBSTORE_READ_WINDOW_BYTES - 1));
+                spawn(async move { handler.get_object_range(&container, &name, range).await })
+            });
+            let res = ready!(Pin::new(task).poll(cx));
+            self.fetch = None;
+            let data = match res {
+                Ok(Ok(data)) => data,
+                Ok(Err(err)) => {
+                    return Poll::Ready(Err(io::Error::new(
+                        io::ErrorKind::Other,
+                        format!("{err:#}"),
+                    )))
+                }
+                Err(err) => return Poll::Ready(Err(io::Error::new(io::ErrorKind::Other, err))),
+            };
+            if data.is_empty() {
+                return Poll::Ready(Err(io::ErrorKind::UnexpectedEof.into()));
+            }
+            self.offset = self.offset.saturating_add(data.len() as u64);
+            self.buf = Cursor::new(data);
+        }
+    }
+}
+
 #[async_trait]
 impl Blobstore for Handler {
     #[instrument]
@@ -475,48 +862,44 @@ impl Blobstore for Handler {
         name: String,
         range: RangeInclusive<u64>,
     ) -> anyhow::Result<(Box<dyn AsyncRead + Sync + Send + Unpin>, u64)> {
-        let targets = self.targets.read().await;
-        let res = self
-            .call_operation(
-                targets.get(&TargetInterface::WasiBlobstoreBlobstore),
-                "wasmcloud:blobstore/Blobstore.GetObject",
-                &wasmcloud_compat::blobstore::GetObjectRequest {
-                    object_id: name.clone(),
-                    container_id: container.into(),
-                    range_start: Some(*range.start()),
-                    range_end: Some(*range.end()),
-                },
-            )
+        let (start, end) = range.into_inner();
+        if start > end {
+            return Ok((Box::new(empty()), 0));
+        }
+        let window_end = end.min(start.saturating_add(BLOBSTORE_READ_WINDOW_BYTES - 1));
+        let buf = self
+            .get_object_range(container, &name, start..=window_end)
             .await?;
-        let wasmcloud_compat::blobstore::GetObjectResponse {
-            success,
-            error,
-            initial_chunk,
-            ..
-        } = decode_provider_response(res)?;
-        match (success, initial_chunk, error) {
-            (_, _, Some(err)) => Err(anyhow!(err).context("failed to get object response")),
-            (false, _, None) => bail!("failed to get object response"),
-            (true, None, None) => Ok((Box::new(empty()), 0)),
-            (
-                true,
-                Some(wasmcloud_compat::blobstore::Chunk {
-                    object_id,
-                    container_id,
-                    bytes,
-                    ..
-                }),
-                None,
-            ) => {
-                ensure!(object_id == name);
-                ensure!(container_id == container);
-                let size = bytes
-                    .len()
-                    .try_into()
-                    .context("value size does not fit in `u64`")?;
-                Ok((Box::new(Cursor::new(bytes)), size))
-            }
+        let n: u64 = buf
+            .len()
+            .try_into()
+            .context("value size does not fit in `u64`")?;
+        ensure!(
+            n <= window_end - start + 1,
+            "provider returned more data than requested"
+        );
+        if window_end == end || n < window_end - start + 1 {
+            // The whole range was returned or the object ends within the first window
+            return Ok((Box::new(Cursor::new(buf)), n));
         }
+        let blobstore::container::ObjectMetadata { size, .. } = self
+            .object_info(container, name.clone())
+            .await
+            .context("failed to get object size")?;
+        let size = size.min(end.saturating_add(1)).saturating_sub(start);
+        ensure!(size >= n, "object size is smaller than data returned");
+        Ok((
+            Box::new(ObjectReader {
+                handler: self.clone(),
+                container: container.into(),
+                name,
+                offset: start + n,
+                end: start + size - 1,
+                buf: Cursor::new(buf),
+                fetch: None,
+            }),
+            size,
+        ))
     }
 
     #[instrument]
@@ -541,30 +924,20 @@ impl Blobstore for Handler {
         name: String,
         mut value: Box<dyn AsyncRead + Sync + Send + Unpin>,
     ) -> anyhow::Result<()> {
-        let targets = self.targets.read().await;
         let mut bytes = Vec::new();
         value
             .read_to_end(&mut bytes)
             .await
             .context("failed to read bytes")?;
-        let res = self
-            .call_operation(
-                targets.get(&TargetInterface::WasiBlobstoreBlobstore),
-                "wasmcloud:blobstore/Blobstore.PutObject",
-                &wasmcloud_compat::blobstore::PutObjectRequest {
-                    chunk: wasmcloud_compat::blobstore::Chunk {
-                        object_id: name,
-                        container_id: container.into(),
-                        bytes,
-                        offset: 0,
-                        is_last: true,
-                    },
-                    ..Default::default()
-                },
-            )
+        let stream_id = self
+            .put_object(wasmcloud_compat::blobstore::Chunk {
+                object_id: name,
+                container_id: container.into(),
+                bytes,
+                offset: 0,
+                is_last: true,
+            })
             .await?;
-        let wasmcloud_compat::blobstore::PutObjectResponse { stream_id } =
-            decode_provider_response(res)?;
         ensure!(
             stream_id.is_none(),
             "provider returned an unexpected stream ID"
@@ -639,7 +1012,10 @@ impl Blobstore for Handler {
             .call_operation(
                 targets.get(&TargetInterface::WasiBlobstoreBlobstore),
                 "wasmcloud:blobstore/Blobstore.GetObjectInfo",
-                &name,
+                &wasmcloud_compat::blobstore::ContainerObject {
+                    container_id: container.into(),
+                    object_id: name,
+                },
             )
             .await?;
         let wasmcloud_compat::blobstore::ObjectMetadata {
@@ -655,6 +1031,89 @@ impl Blobstore for Handler {
             created_at: 0,
         })
     }
+
+    #[instrument]
+    async fn copy_object(
+        &self,
+        src_container: &str,
+        src_name: String,
+        dest_container: &str,
+        dest_name: String,
+    ) -> anyhow::Result<()> {
+        let request = rmp_serde::to_vec_named(&wasmcloud_compat::blobstore::CopyObjectRequest {
+            src_container: src_container.into(),
+            src_object: src_name.clone(),
+            dest_container: dest_container.into(),
+            dest_object: dest_name.clone(),
+        })
+        .context("failed to encode request")?;
+        let res = {
+            let targets = self.targets.read().await;
+            self.call_operation_with_payload(
+                targets.get(&TargetInterface::WasiBlobstoreBlobstore),
+                "wasmcloud:blobstore/Blobstore.CopyObject",
+                request,
+                None,
+            )
+            .await
+            .context("failed to call target entity")?
+        };
+        match res {
+            Ok(res) => decode_empty_provider_response(res),
+            Err(err) if is_method_not_handled(&err) => {
+                debug!(
+                    err,
+                    "provider does not support copying objects, falling back to read and write"
+                );
+                self.copy_object_data(src_container, src_name, dest_container, dest_name)
+                    .await
+            }
+            Err(err) => Err(anyhow!(err).context("failed to copy object")),
+        }
+    }
+
+    #[instrument]
+    async fn move_object(
+        &self,
+        src_container: &str,
+        src_name: String,
+        dest_container: &str,
+        dest_name: String,
+    ) -> anyhow::Result<()> {
+        let request = rmp_serde::to_vec_named(&wasmcloud_compat::blobstore::MoveObjectRequest {
+            src_container: src_container.into(),
+            src_object: src_name.clone(),
+            dest_container: dest_container.into(),
+            dest_object: dest_name.clone(),
+        })
+        .context("failed to encode request")?;
+        let res = {
+            let targets = self.targets.read().await;
+            self.call_operation_with_payload(
+                targets.get(&TargetInterface::WasiBlobstoreBlobstore),
+                "wasmcloud:blobstore/Blobstore.MoveObject",
+                request,
+                None,
+            )
+            .await
+            .context("failed to call target entity")?
+        };
+        match res {
+            Ok(res) => decode_empty_provider_response(res),
+            Err(err) if is_method_not_handled(&err) => {
+                debug!(
+                    err,
+                    "provider does not support moving objects, falling back to read, write and delete"
+                );
+                self.copy_object_data(src_container, src_name.clone(), dest_container, dest_name)
+                    .await?;
+                self.delete_objects(src_container, vec![src_name])
+                    .await
+                    .context("failed to delete source object")
+            }
+            Err(err) => Err(anyhow!(err).context("failed to move object")),
+        }
+    }
 }
 
 #[async_trait]
@@ -717,50 +1176,75 @@ impl Bus for Handler {
         let cluster_key = self.cluster_key.clone();
         let host_key = self.host_key.clone();
         let claims_metadata = self.claims.metadata.clone();
+        let default_rpc_timeout = self.rpc_timeout;
         Ok((
             async move {
-                // TODO: Stream data
-                let mut request = vec![];
-                req_r
-                    .read_to_end(&mut request)
-                    .await
-                    .context("failed to read request")
-                    .map_err(|e| e.to_string())?;
                 let links = links.read().await;
                 let aliases = aliases.read().await;
                 let (package, _) = operation
                     .rsplit_once('/')
                     .context("failed to parse operation")
                     .map_err(|e| e.to_string())?;
-                let inv_target = resolve_target(target.as_ref(), links.get(package), &aliases)
+                let (inv_target, link_rpc_timeout) =
+                    resolve_target(target.as_ref(), links.get(package), &aliases)
+                        .await
+                        .map_err(|e| e.to_string())?;
+                drop((links, aliases));
+
+                // Validate that the actor has the capability to call the target
+                ensure_actor_capability(claims_metadata.as_ref(), &inv_target.contract_id)
+                    .map_err(|e| e.to_string())?;
+
+                // Read at most one byte over the chunking threshold to determine whether the
+                // request can be sent inline. If not, the request is streamed to the object store
+                // as the actor writes it, and the invocation is published once the actor has
+                // finished writing the request and the upload is complete.
+                // This bounds the memory used for large requests, but it is not a streaming RPC:
+                // the target only observes the request once it was written in full.
+                // TODO: Stream data while the call is in flight. This requires a chunk protocol
+                // receivers can consume as chunks land, since objects only become visible in the
+                // object store once complete, and invocation claims covering the request hash,
+                // which is only known once the request was written in full
+                let mut request = vec![];
+                (&mut req_r)
+                    .take(CHUNK_THRESHOLD_BYTES as u64 + 1)
+                    .read_to_end(&mut request)
                     .await
+                    .context("failed to read request")
                     .map_err(|e| e.to_string())?;
                 let needs_chunking = request.len() > CHUNK_THRESHOLD_BYTES;
                 let injector = TraceContextInjector::default_with_span();
                 let headers = injector_to_headers(&injector);
-                let mut invocation = Invocation::new(
-                    &cluster_key,
-                    &host_key,
-                    origin,
-                    inv_target,
-                    operation,
-                    request,
-                    injector.into(),
-                )
+                let mut invocation = if needs_chunking {
+                    Invocation::new_chunked(
+                        &cluster_key,
+                        &host_key,
+                        origin,
+                        inv_target,
+                        operation,
+                        Cursor::new(request).chain(req_r),
+                        &chunk_endpoint,
+                        injector.into(),
+                    )
+                    .await
+                } else {
+                    Invocation::new(
+                        &cluster_key,
+                        &host_key,
+                        origin,
+                        inv_target,
+                        operation,
+                        request,
+                        injector.into(),
+                    )
+                }
                 .map_err(|e| e.to_string())?;
 
-                // Validate that the actor has the capability to call the target
-                ensure_actor_capability(claims_metadata.as_ref(), &invocation.target.contract_id)
-                    .map_err(|e| e.to_string())?;
-
-                if needs_chunking {
-                    chunk_endpoint
-                        .chunkify(&invocation.id, Cursor::new(invocation.msg))
-                        .await
-                        .context("failed to chunk invocation")
-                        .map_err(|e| e.to_string())?;
-                    invocation.msg = vec![];
-                }
+                let timeout = rpc_timeout(
+                    link_rpc_timeout.unwrap_or(default_rpc_timeout),
+                    needs_chunking,
+                );
+                invocation.set_timeout(timeout);
 
                 let payload = rmp_serde::to_vec_named(&invocation)
                     .context("failed to encode invocation")
@@ -776,10 +1260,9 @@ impl Bus for Handler {
                     ),
                 };
 
-                let timeout = needs_chunking.then_some(CHUNK_RPC_EXTRA_TIME); // TODO: add rpc_nats timeout
                 let request = async_nats::Request::new()
                     .payload(payload.into())
-                    .timeout(timeout)
+                    .timeout(Some(timeout))
                     .headers(headers); // TODO: remove headers once all providers are built off the new SDK, which parses the trace context in the invocation
                 let res = nats
                     .send_request(topic, request)
@@ -789,7 +1272,7 @@ impl Bus for Handler {
 
                 let InvocationResponse {
                     invocation_id,
-                    mut msg,
+                    msg,
                     content_length,
                     error,
                     ..
@@ -799,30 +1282,34 @@ impl Bus for Handler {
                 if invocation_id != invocation.id {
                     return Err("invocation ID mismatch".into());
                 }
+                if let Some(error) = error {
+                    return Err(error);
+                }
 
                 let resp_length = usize::try_from(content_length)
                     .context("content length does not fit in usize")
                     .map_err(|e| e.to_string())?;
                 if resp_length > CHUNK_THRESHOLD_BYTES {
-                    msg = chunk_endpoint
-                        .get_unchunkified_response(&invocation_id)
+                    // The target uploads the response in full before replying. Copy it to the
+                    // actor as it is read from the object store instead of buffering it
+                    let n = chunk_endpoint
+                        .copy_unchunkified_response(&invocation_id, &mut res_w)
                         .await
                         .context("failed to dechunk response")
                         .map_err(|e| e.to_string())?;
+                    if n != content_length {
+                        return Err("message size mismatch".into());
+                    }
                 } else if resp_length != msg.len() {
                     return Err("message size mismatch".into());
-                }
-
-                if let Some(error) = error {
-                    Err(error)
                 } else {
                     res_w
                         .write_all(&msg)
                         .await
                         .context("failed to write reply")
                         .map_err(|e| e.to_string())?;
-                    Ok(())
                 }
+                Ok(())
             }
             .boxed(),
             Box::new(req_w),
@@ -837,7 +1324,7 @@ impl Bus for Handler {
         operation: String,
         request: Vec<u8>,
     ) -> anyhow::Result<Vec<u8>> {
-        self.call_operation_with_payload(target.as_ref(), operation, request)
+        self.call_operation_with_payload(target.as_ref(), operation, request, None)
             .await
             .context("failed to call linked provider")?
             .map_err(|e| anyhow!(e).context("provider call failed"))
@@ -849,16 +1336,16 @@ impl KeyValueAtomic for Handler {
     #[instrument(skip(self))]
     async fn increment(&self, bucket: &str, key: String, delta: u64) -> anyhow::Result<u64> {
         const METHOD: &str = "wasmcloud:keyvalue/KeyValue.Increment";
-        if !bucket.is_empty() {
-            bail!("buckets not currently supported")
-        }
         let targets = self.targets.read().await;
         let value = delta.try_into().context("delta does not fit in `i32`")?;
         let res = self
             .call_operation(
                 targets.get(&TargetInterface::WasiKeyvalueAtomic),
                 METHOD,
-                &wasmcloud_compat::keyvalue::IncrementRequest { key, value },
+                &wasmcloud_compat::keyvalue::BucketRequest::new(
+                    bucket,
+                    wasmcloud_compat::keyvalue::IncrementRequest { key, value },
+                ),
             )
             .await?;
         let new: i32 = decode_provider_response(res)?;
@@ -866,7 +1353,6 @@ impl KeyValueAtomic for Handler {
         Ok(new)
     }
 
-    #[allow(unused)] // TODO: Implement https://github.com/wasmCloud/wasmCloud/issues/457
     #[instrument(skip(self))]
     async fn compare_and_swap(
         &self,
@@ -875,7 +1361,19 @@ impl KeyValueAtomic for Handler {
         old: u64,
         new: u64,
     ) -> anyhow::Result<bool> {
-        bail!("not supported")
+        const METHOD: &str = "wasmcloud:keyvalue/KeyValue.CompareAndSwap";
+        let targets = self.targets.read().await;
+        let res = self
+            .call_operation(
+                targets.get(&TargetInterface::WasiKeyvalueAtomic),
+                METHOD,
+                &wasmcloud_compat::keyvalue::BucketRequest::new(
+                    bucket,
+                    wasmcloud_compat::keyvalue::CompareAndSwapRequest { key, old, new },
+                ),
+            )
+            .await?;
+        decode_provider_response(res)
     }
 }
 
@@ -888,15 +1386,12 @@ impl KeyValueReadWrite for Handler {
         key: String,
     ) -> anyhow::Result<(Box<dyn AsyncRead + Sync + Send + Unpin>, u64)> {
         const METHOD: &str = "wasmcloud:keyvalue/KeyValue.Get";
-        if !bucket.is_empty() {
-            bail!("buckets not currently supported")
-        }
         let targets = self.targets.read().await;
         let res = self
             .call_operation(
                 targets.get(&TargetInterface::WasiKeyvalueReadwrite),
                 METHOD,
-                &key,
+                &wasmcloud_compat::keyvalue::BucketRequest::new(bucket, key),
             )
             .await?;
         let wasmcloud_compat::keyvalue::GetResponse { value, exists } =
@@ -919,23 +1414,23 @@ impl KeyValueReadWrite for Handler {
         mut value: Box<dyn AsyncRead + Sync + Send + Unpin>,
     ) -> anyhow::Result<()> {
         const METHOD: &str = "wasmcloud:keyvalue/KeyValue.Set";
-        if !bucket.is_empty() {
-            bail!("buckets not currently supported")
-        }
-        let mut buf = String::new();
+        let mut buf = vec![];
         value
-            .read_to_string(&mut buf)
+            .read_to_end(&mut buf)
             .await
             .context("failed to read value")?;
         let targets = self.targets.read().await;
         self.call_operation(
             targets.get(&TargetInterface::WasiKeyvalueReadwrite),
             METHOD,
-            &wasmcloud_compat::keyvalue::SetRequest {
-                key,
-                value: buf,
-                expires: 0,
-            },
+            &wasmcloud_compat::keyvalue::BucketRequest::new(
+                bucket,
+                wasmcloud_compat::keyvalue::SetRequest {
+                    key,
+                    value: buf,
+                    expires: 0,
+                },
+            ),
         )
         .await
         .and_then(decode_empty_provider_response)
@@ -944,15 +1439,12 @@ impl KeyValueReadWrite for Handler {
     #[instrument(skip(self))]
     async fn delete(&self, bucket: &str, key: String) -> anyhow::Result<()> {
         const METHOD: &str = "wasmcloud:keyvalue/KeyValue.Del";
-        if !bucket.is_empty() {
-            bail!("buckets not currently supported")
-        }
         let targets = self.targets.read().await;
         let res = self
             .call_operation(
                 targets.get(&TargetInterface::WasiKeyvalueReadwrite),
                 METHOD,
-                &key,
+                &wasmcloud_compat::keyvalue::BucketRequest::new(bucket, key),
             )
             .await?;
         let deleted: bool = decode_provider_response(res)?;
@@ -963,14 +1455,11 @@ impl KeyValueReadWrite for Handler {
     #[instrument(skip(self))]
     async fn exists(&self, bucket: &str, key: String) -> anyhow::Result<bool> {
         const METHOD: &str = "wasmcloud:keyvalue/KeyValue.Contains";
-        if !bucket.is_empty() {
-            bail!("buckets not currently supported")
-        }
         let targets = self.targets.read().await;
         self.call_operation(
             targets.get(&TargetInterface::WasiKeyvalueReadwrite),
             METHOD,
-            &key,
+            &wasmcloud_compat::keyvalue::BucketRequest::new(bucket, key),
         )
         .await
         .and_then(decode_provider_response)
@@ -1064,7 +1553,7 @@ impl Messaging for Handler {
             .context("timeout milliseconds do not fit in `u32`")?;
         let targets = self.targets.read().await;
         let res = self
-            .call_operation(
+            .call_operation_with_timeout(
                 targets.get(&TargetInterface::WasmcloudMessagingConsumer),
                 METHOD,
                 &wasmcloud_compat::messaging::RequestMessage {
@@ -1072,6 +1561,7 @@ impl Messaging for Handler {
                     body: body.unwrap_or_default(),
                     timeout_ms,
                 },
+                Some(timeout),
             )
             .await?;
         let wasmcloud_compat::messaging::ReplyMessage {
@@ -1094,13 +1584,47 @@ impl Messaging for Handler {
         timeout: Duration,
         max_results: u32,
     ) -> anyhow::Result<Vec<messaging::types::BrokerMessage>> {
-        match max_results {
-            0..=1 => {
-                let res = self.request(subject, body, timeout).await?;
-                Ok(vec![res])
-            }
-            2.. => bail!("at most 1 result can be requested at the time"),
+        const METHOD: &str = "wasmcloud:messaging/Messaging.RequestMulti";
+
+        // Single-result requests do not require providers to support scatter-gather requests
+        if max_results <= 1 {
+            let res = self.request(subject, body, timeout).await?;
+            return Ok(vec![res]);
         }
+        let timeout_ms = timeout
+            .as_millis()
+            .try_into()
+            .context("timeout milliseconds do not fit in `u32`")?;
+        let targets = self.targets.read().await;
+        // The provider waits for the full `timeout` unless `max_results` replies arrive earlier
+        let res = self
+            .call_operation_with_timeout(
+                targets.get(&TargetInterface::WasmcloudMessagingConsumer),
+                METHOD,
+                &wasmcloud_compat::messaging::RequestMultiMessage {
+                    subject,
+                    body: body.unwrap_or_default(),
+                    timeout_ms,
+                    max_results,
+                },
+                Some(timeout),
+            )
+            .await?;
+        let res: Vec<wasmcloud_compat::messaging::ReplyMessage> = decode_provider_response(res)?;
+        Ok(res
+            .into_iter()
+            .map(
+                |wasmcloud_compat::messaging::ReplyMessage {
+                     subject,
+                     reply_to,
+                     body,
+                 }| messaging::types::BrokerMessage {
+                    subject,
+                    reply_to,
+                    body: Some(body),
+                },
+            )
+            .collect())
     }
 
     #[instrument(skip_all)]
@@ -1128,20 +1652,125 @@ impl Messaging for Handler {
     }
 }
 
+#[async_trait]
+impl OutgoingHttp for Handler {
+    #[instrument(skip(self, request), fields(uri = %request.uri()))]
+    async fn handle(
+        &self,
+        request: http::Request<Box<dyn AsyncRead + Sync + Send + Unpin>>,
+        options: Option<RequestOptions>,
+    ) -> anyhow::Result<http::Response<Box<dyn AsyncRead + Sync + Send + Unpin>>> {
+        const METHOD: &str = "wasmcloud:httpclient/HttpClient.Request";
+
+        if let Some(http_client) = &self.http_client {
+            if http_client.allows(request.uri()) {
+                return http_client.handle(request, options).await;
+            }
+        }
+        // TODO: Pass request options once supported by `wasmcloud:httpclient`
+        let request = wasmcloud_compat::HttpClientRequest::from_http(request)
+            .await
+            .context("failed to convert request")?;
+        let res = self.call_operation(None, METHOD, &request).await?;
+        let res: wasmcloud_compat::HttpResponse = decode_provider_response(res)?;
+        let res = http::Response::try_from(res).context("failed to convert response")?;
+        Ok(res.map(|body| -> Box<dyn AsyncRead + Sync + Send + Unpin> {
+            Box::new(Cursor::new(body))
+        }))
+    }
+}
+
 impl ActorInstance {
+    /// Stops accepting new invocations and waits for in-flight invocations to complete until
+    /// `deadline`, after which the remaining ones are aborted.
+    /// Returns the amounts of drained and abandoned invocations.
+    #[instrument(skip(self), fields(instance_id = %self.id))]
+    async fn drain(&self, deadline: Instant) -> (usize, usize) {
+        self.draining.send_replace(true);
+        let in_flight = *self.in_flight.borrow();
+        let mut done = self.in_flight.subscribe();
+        if timeout_at(deadline, done.wait_for(|&n| n == 0))
+            .await
+            .is_err()
+        {
+            warn!("actor instance did not finish in-flight invocations in time, aborting them");
+        }
+        let abandoned = *self.in_flight.borrow();
+        self.calls.abort();
+        (in_flight.saturating_sub(abandoned), abandoned)
+    }
+
+    /// Returns the response message `msg` of invocation `invocation_id` along with its content
+    /// length. Messages exceeding [`CHUNK_THRESHOLD_BYTES`] are uploaded to the chunk endpoint
+    /// and an empty message is returned instead
+    async fn chunk_response(
+        &self,
+        invocation_id: &str,
+        msg: Vec<u8>,
+    ) -> anyhow::Result<(Vec<u8>, u64)> {
+        let content_length = msg
+            .len()
+            .try_into()
+            .context("failed to convert content_length to u64")?;
+        if msg.len() <= CHUNK_THRESHOLD_BYTES {
+            return Ok((msg, content_length));
+        }
+        debug!(inv_id = invocation_id, "chunking invocation response");
+        self.chunk_endpoint
+            .chunkify_response(invocation_id, Cursor::new(msg))
+            .await
+            .context("failed to chunk invocation response")?;
+        Ok((vec![], content_length))
+    }
+
+    /// Uploads the encoded HTTP `res` of invocation `invocation_id` to the chunk endpoint while
+    /// the actor writes the body, if the response declares a content length exceeding
+    /// [`CHUNK_THRESHOLD_BYTES`]. Returns the content length of the encoded response if it was
+    /// uploaded and `res` otherwise
+    async fn stream_http_response(
+        &self,
+        invocation_id: &str,
+        res: http::Response<Box<dyn AsyncRead + Sync + Send + Unpin>>,
+    ) -> anyhow::Result<Result<u64, http::Response<Box<dyn AsyncRead + Sync + Send + Unpin>>>> {
+        let Some(body_length) = res
+            .headers()
+            .get(http::header::CONTENT_LENGTH)
+            .and_then(|content_length| content_length.to_str().ok())
+            .and_then(|content_length| content_length.parse::<u32>().ok())
+            .filter(|&body_length| body_length as usize > CHUNK_THRESHOLD_BYTES)
+        else {
+            return Ok(Err(res));
+        };
+        let (parts, body) = res.into_parts();
+        let head = encode_http_response_head(http::Response::from_parts(parts, ()), body_length)?;
+        let content_length = head.len() as u64 + u64::from(body_length);
+        debug!(inv_id = invocation_id, "streaming chunked HTTP response");
+        let mut res = Cursor::new(head).chain(body.take(body_length.into()));
+        self.chunk_endpoint
+            .chunkify_response(invocation_id, &mut res)
+            .await
+            .context("failed to chunk HTTP response")?;
+        ensure!(
+            res.get_ref().1.limit() == 0,
+            "response body is shorter than its content length"
+        );
+        Ok(Ok(content_length))
+    }
+
     #[instrument(skip(self, msg))]
     async fn handle_invocation(
         &self,
+        invocation_id: &str,
         contract_id: &str,
         operation: &str,
         msg: Vec<u8>,
-    ) -> anyhow::Result<Result<Vec<u8>, String>> {
+    ) -> anyhow::Result<Result<(Vec<u8>, u64), String>> {
         // Validate that the actor has the capability to receive the invocation
         ensure_actor_capability(self.handler.claims.metadata.as_ref(), contract_id)?;
 
         let mut instance = self
-            .actor
-            .instantiate()
+            .pool
+            .get()
             .await
             .context("failed to instantiate actor")?;
         instance
@@ -1153,30 +1782,48 @@ impl ActorInstance {
             .keyvalue_atomic(Arc::new(self.handler.clone()))
             .keyvalue_readwrite(Arc::new(self.handler.clone()))
             .logging(Arc::new(self.handler.clone()))
-            .messaging(Arc::new(self.handler.clone()));
+            .messaging(Arc::new(self.handler.clone()))
+            .outgoing_http(Arc::new(self.handler.clone()));
         #[allow(clippy::single_match_else)] // TODO: Remove once more interfaces supported
         match (contract_id, operation) {
             ("wasmcloud:httpserver", "HttpServer.HandleRequest") => {
                 let req: wasmcloud_compat::HttpRequest =
                     rmp_serde::from_slice(&msg).context("failed to decode HTTP request")?;
                 let req = http::Request::try_from(req).context("failed to convert request")?;
+                // `HttpServer.HandleRequest` carries the request and response bodies inline, so
+                // the request body is buffered and trailers are not forwarded. Responses
+                // exceeding the chunking threshold are uploaded to the chunk endpoint as the
+                // actor writes them if their length is known upfront, all other responses are
+                // buffered. The response body is read to completion before the instance is
+                // returned to the pool, which discards it if the invocation fails
                 let res = match instance
-                    .into_incoming_http()
-                    .await
-                    .context("failed to instantiate `wasi:http/incoming-handler`")?
-                    .handle(req.map(|body| -> Box<dyn AsyncRead + Send + Sync + Unpin> {
-                        Box::new(Cursor::new(body))
-                    }))
+                    .serve_incoming_http(req.map(
+                        |body| -> Box<dyn AsyncRead + Send + Sync + Unpin> {
+                            Box::new(Cursor::new(body))
+                        },
+                    ))
                     .await
                 {
+                    Ok(res) => match self.stream_http_response(invocation_id, res).await {
+                        Ok(Ok(content_length)) => return Ok(Ok((vec![], content_length))),
+                        Ok(Err(res)) => wasmcloud_compat::HttpResponse::from_http(res)
+                            .await
+                            .context("failed to read response"),
+                        Err(err) => Err(err),
+                    },
+                    Err(err) => Err(err),
+                };
+                let res = match res {
                     Ok(res) => res,
-                    Err(err) => return Ok(Err(format!("{err:#}"))),
+                    Err(err) => {
+                        if is_timeout(&err) {
+                            return Err(err.context("failed to handle HTTP request"));
+                        }
+                        return Ok(Err(format!("{err:#}")));
+                    }
                 };
-                let res = wasmcloud_compat::HttpResponse::from_http(res)
-                    .await
-                    .context("failed to convert response")?;
                 let res = rmp_serde::to_vec_named(&res).context("failed to encode response")?;
-                Ok(Ok(res))
+                self.chunk_response(invocation_id, res).await.map(Ok)
             }
             _ => {
                 let res = AsyncBytesMut::default();
@@ -1187,7 +1834,7 @@ impl ActorInstance {
                 {
                     Ok(()) => {
                         let res = res.try_into().context("failed to unwrap bytes")?;
-                        Ok(Ok(res))
+                        self.chunk_response(invocation_id, res).await.map(Ok)
                     }
                     Err(e) => Ok(Err(e)),
                 }
@@ -1199,6 +1846,9 @@ impl ActorInstance {
     async fn handle_call(&self, invocation: Invocation) -> anyhow::Result<(Vec<u8>, u64)> {
         debug!(?invocation.origin, ?invocation.target, invocation.operation, "validate actor invocation");
         invocation.validate_antiforgery(&self.valid_issuers)?;
+        let deadline = invocation
+            .time_remaining()
+            .map(|remaining| Instant::now() + remaining);
 
         let content_length: usize = invocation
             .content_length
@@ -1265,37 +1915,23 @@ impl ActorInstance {
             );
         };
 
-        let maybe_resp = self
-            .handle_invocation(
-                &invocation.origin.contract_id,
-                &invocation.operation,
-                inv_msg,
-            )
-            .await
-            .context("failed to handle invocation")?;
-
-        match maybe_resp {
-            Ok(resp_msg) => {
-                let content_length = resp_msg.len();
-                let resp_msg = if content_length > CHUNK_THRESHOLD_BYTES {
-                    debug!(inv_id = invocation.id, "chunking invocation response");
-                    self.chunk_endpoint
-                        .chunkify_response(&invocation.id, Cursor::new(resp_msg))
-                        .await
-                        .context("failed to chunk invocation response")?;
-                    vec![]
-                } else {
-                    resp_msg
-                };
-                Ok((
-                    resp_msg,
-                    content_length
-                        .try_into()
-                        .context("failed to convert content_length to u64")?,
-                ))
-            }
-            Err(e) => Err(anyhow!(e)),
+        let handle = self.handle_invocation(
+            &invocation.id,
+            &invocation.origin.contract_id,
+            &invocation.operation,
+            inv_msg,
+        );
+        // Abandon the invocation once the caller has stopped waiting for a response
+        let maybe_resp = if let Some(deadline) = deadline {
+            timeout_at(deadline, handle)
+                .await
+                .context("invocation deadline exceeded")?
+        } else {
+            handle.await
         }
+        .context("failed to handle invocation")?;
+
+        maybe_resp.map_err(|e| anyhow!(e))
     }
 
     #[instrument(skip_all)]
@@ -1323,6 +1959,10 @@ impl ActorInstance {
                 let operation = invocation.operation.clone();
 
                 let res = self.handle_call(invocation).await;
+                self.invocations.fetch_add(1, Ordering::Relaxed);
+                if res.is_err() {
+                    self.errors.fetch_add(1, Ordering::Relaxed);
+                }
                 let injector = TraceContextInjector::default_with_span();
                 let headers = injector_to_headers(&injector);
                 let trace_context = injector.into();
@@ -1334,6 +1974,41 @@ impl ActorInstance {
                         trace_context,
                         ..Default::default()
                     },
+                    Err(e) if is_timeout(&e) => {
+                        warn!(
+                            ?origin,
+                            ?target,
+                            ?operation,
+                            ?invocation_id,
+                            ?e,
+                            "actor invocation timed out"
+                        );
+                        if let Err(e) = event::publish(
+                            &self.event_builder,
+                            &self.ctl_nats,
+                            &self.handler.lattice_prefix,
+                            "actor_invocation_timed_out",
+                            event::actor_invocation_timed_out(
+                                &self.handler.claims,
+                                Uuid::from_u128(self.id.into()),
+                                &invocation_id,
+                                &operation,
+                                &origin.public_key,
+                            ),
+                        )
+                        .await
+                        {
+                            error!(?e, "failed to publish `actor_invocation_timed_out` event");
+                        }
+                        InvocationResponse {
+                            error: Some(format!(
+                                "invocation `{invocation_id}` of operation `{operation}` timed out: actor exceeded its execution limits"
+                            )),
+                            invocation_id,
+                            trace_context,
+                            ..Default::default()
+                        }
+                    }
                     Err(e) => {
                         error!(
                             ?origin,
@@ -1381,9 +2056,12 @@ type Annotations = BTreeMap<String, String>;
 #[derive(Debug)]
 struct Actor {
     actor: wasmcloud_runtime::Actor,
+    pool: InstancePool,
     instances: RwLock<HashMap<Annotations, Vec<Arc<ActorInstance>>>>,
     image_ref: String,
     handler: Handler,
+    /// Held for the duration of a rolling update of the actor
+    update: Arc<Mutex<()>>,
 }
 
 impl Deref for Actor {
@@ -1394,11 +2072,104 @@ impl Deref for Actor {
     }
 }
 
+impl Actor {
+    /// Fails if a rolling update of the actor is in progress
+    fn ensure_not_updating(&self) -> anyhow::Result<()> {
+        ensure!(
+            self.update.try_lock().is_ok(),
+            "actor is currently being updated"
+        );
+        Ok(())
+    }
+
+    /// Returns an [Actor] running `actor` from `image_ref` with all current instances of `self`.
+    /// Scaling the returned [Actor] will instantiate `actor` using `pool`.
+    async fn updated(
+        &self,
+        actor: wasmcloud_runtime::Actor,
+        pool: InstancePool,
+        image_ref: String,
+    ) -> anyhow::Result<Self> {
+        let claims = actor.claims().context("claims missing from new actor")?;
+        let handler = Handler {
+            claims: claims.clone(),
+            ..self.handler.clone()
+        };
+        let instances = self.instances.read().await.clone();
+        Ok(Self {
+            actor,
+            pool,
+            instances: RwLock::new(instances),
+            image_ref,
+            handler,
+            update: Arc::clone(&self.update),
+        })
+    }
+}
+
+/// Adds `added` to `instances` and removes all instances, for which `replaced` returns `true`.
+/// Returns the removed instances.
+fn replace_instances<T>(
+    instances: &mut Vec<T>,
+    added: Vec<T>,
+    replaced: impl Fn(&T) -> bool,
+) -> Vec<T> {
+    let (removed, kept) = instances.drain(..).partition(replaced);
+    *instances = kept;
+    instances.extend(added);
+    removed
+}
+
 #[derive(Debug)]
 struct ProviderInstance {
     child: JoinHandle<()>,
     id: Ulid,
     annotations: Annotations,
+    health: watch::Receiver<ProviderHealth>,
+}
+
+/// Encodes `res` as a `wasmcloud:httpserver` response, omitting the `body_length` bytes of the
+/// body, which are expected to immediately follow the returned bytes
+fn encode_http_response_head(res: http::Response<()>, body_length: u32) -> anyhow::Result<Vec<u8>> {
+    // The headers are converted by encoding an empty body, which is replaced by the header of a
+    // binary of `body_length` bytes. `body` is the last field of the encoded response.
+    // `Content-Length` is only added afterwards, since the conversion reserves that many bytes
+    let (mut parts, ()) = res.into_parts();
+    let content_length = parts.headers.remove(http::header::CONTENT_LENGTH);
+    let mut res =
+        wasmcloud_compat::HttpResponse::from_http(http::Response::from_parts(parts, empty()))
+            .now_or_never()
+            .context("reading empty body did not complete")?
+            .context("failed to convert response")?;
+    if let Some(content_length) = content_length {
+        let content_length = content_length
+            .to_str()
+            .context("failed to parse `content-length` header value as string")?;
+        res.header.insert(
+            http::header::CONTENT_LENGTH.as_str().into(),
+            vec![content_length.into()],
+        );
+    }
+    let mut head = rmp_serde::to_vec_named(&res).context("failed to encode response")?;
+    ensure!(
+        head.ends_with(&[0xc4, 0x00]),
+        "encoded response does not end with an empty body"
+    );
+    head.truncate(head.len() - 2);
+    head.push(0xc6);
+    head.extend_from_slice(&body_length.to_be_bytes());
+    Ok(head)
+}
+
+/// Health of a capability provider, as determined by the most recent health check
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ProviderHealth {
+    /// The provider was not health checked yet since it was (re)started
+    #[default]
+    Pending,
+    Healthy,
+    Unhealthy,
 }
 
 #[derive(Debug)]
@@ -1408,6 +2179,389 @@ struct Provider {
     image_ref: String,
 }
 
+/// Forwards output of a capability provider process to the host log and, optionally, NATS
+#[derive(Clone)]
+struct ProviderOutput {
+    provider_id: String,
+    link_name: String,
+    contract_id: String,
+    /// NATS client and subject to publish output lines on
+    nats: Option<(async_nats::Client, String)>,
+}
+
+impl ProviderOutput {
+    /// Forwards each line of `output` until it is closed
+    async fn forward(self, output: impl AsyncRead + Unpin, stream: &'static str) {
+        let mut output = BufReader::new(output);
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+            match output.read_until(b'\n', &mut buf).await {
+                Ok(0) => return,
+                Ok(_) => {}
+                Err(e) => {
+                    warn!(
+                        provider_id = self.provider_id,
+                        stream,
+                        ?e,
+                        "failed to read provider output"
+                    );
+                    return;
+                }
+            }
+            let line = String::from_utf8_lossy(&buf);
+            let line = line.trim_end_matches(['\r', '\n']);
+            info!(
+                provider_id = self.provider_id,
+                link_name = self.link_name,
+                contract_id = self.contract_id,
+                stream,
+                "{line}"
+            );
+            if let Some((nats, subject)) = &self.nats {
+                let payload = json!({
+                    "provider_id": self.provider_id,
+                    "link_name": self.link_name,
+                    "contract_id": self.contract_id,
+                    "stream": stream,
+                    "line": line,
+                });
+                if let Err(e) = nats
+                    .publish(subject.clone(), payload.to_string().into())
+                    .await
+                {
+                    warn!(
+                        provider_id = self.provider_id,
+                        ?e,
+                        "failed to publish provider output"
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Reason a supervised capability provider process stopped being watched
+enum ProviderExit {
+    /// The provider process exited
+    Exited(io::Result<ExitStatus>),
+    /// The provider failed too many consecutive health checks and `action` must be taken
+    Unhealthy(ProviderHealthAction),
+}
+
+/// Supervises a capability provider process, checking its health and restarting it according
+/// to the configured [`ProviderSupervision`] once it exits
+struct ProviderSupervisor {
+    host: Weak<Host>,
+    path: PathBuf,
+    /// Data passed to the provider on start, links are updated before every restart
+    host_data: HostData,
+    claims: jwt::Claims<jwt::CapabilityProvider>,
+    annotations: Annotations,
+    id: Ulid,
+    host_id: String,
+    provider_ref: String,
+    link_name: String,
+    contract_id: String,
+    lattice_prefix: String,
+    supervision: ProviderSupervision,
+    health_check: ProviderHealthCheck,
+    health: watch::Sender<ProviderHealth>,
+    prov_nats: async_nats::Client,
+    ctl_nats: async_nats::Client,
+    event_builder: EventBuilderV10,
+}
+
+impl ProviderSupervisor {
+    async fn publish_health_event(&self, name: &str) {
+        if let Err(e) = event::publish(
+            &self.event_builder,
+            &self.ctl_nats,
+            &self.lattice_prefix,
+            name,
+            event::provider_health_check(&self.claims.subject, &self.link_name, &self.contract_id),
+        )
+        .await
+        {
+            warn!(?e, "failed to publish provider {name} event");
+        }
+    }
+
+    /// Requests the health of the provider on `health_topic`, publishing a health check event.
+    /// `previous_healthy` is updated to the result of the check
+    async fn check_health(
+        &self,
+        health_topic: &str,
+        timeout: Duration,
+        previous_healthy: &mut bool,
+    ) -> bool {
+        let provider_id = &self.claims.subject;
+        trace!(provider_id, "performing provider health check");
+        let request = async_nats::Request::new()
+            .payload(Bytes::new())
+            .timeout(Some(timeout))
+            .headers(injector_to_headers(
+                &TraceContextInjector::default_with_span(),
+            ));
+        let payload = match self
+            .prov_nats
+            .send_request(health_topic.to_string(), request)
+            .await
+        {
+            Ok(async_nats::Message { payload, .. }) => payload,
+            Err(e) => {
+                warn!(?e, "failed to request provider health, retrying");
+                return false;
+            }
+        };
+        match (
+            rmp_serde::from_slice::<HealthCheckResponse>(&payload),
+            *previous_healthy,
+        ) {
+            (Ok(HealthCheckResponse { healthy: true, .. }), false) => {
+                trace!(provider_id, "provider health check succeeded");
+                *previous_healthy = true;
+                self.publish_health_event("health_check_passed").await;
+                true
+            }
+            (Ok(HealthCheckResponse { healthy: false, .. }), true) => {
+                trace!(provider_id, "provider health check failed");
+                *previous_healthy = false;
+                self.publish_health_event("health_check_failed").await;
+                false
+            }
+            // If the provider health status didn't change, we simply publish a health check status event
+            (Ok(HealthCheckResponse { healthy, .. }), _) => {
+                self.publish_health_event("health_check_status").await;
+                healthy
+            }
+            (Err(_), _) => {
+                warn!("failed to deserialize provider health check response");
+                false
+            }
+        }
+    }
+
+    /// Checks health of the provider until its process exits or, if configured, the provider
+    /// fails too many consecutive health checks
+    async fn watch(&self, child: &mut process::Child) -> ProviderExit {
+        let provider_id = &self.claims.subject;
+        let ProviderHealthCheck {
+            interval,
+            timeout,
+            failure_threshold,
+            action,
+        } = self.health_check;
+        let mut health_check = tokio::time::interval(interval);
+        let mut previous_healthy = false;
+        self.health.send_replace(ProviderHealth::Pending);
+        let mut failures = 0;
+        // Allow the provider 5 seconds to initialize
+        health_check.reset_after(Duration::from_secs(5));
+        let health_topic = format!(
+            "wasmbus.rpc.{}.{provider_id}.{}.health",
+            self.lattice_prefix, self.link_name
+        );
+        loop {
+            select! {
+                _ = health_check.tick() => {
+                    let healthy = self
+                        .check_health(&health_topic, timeout, &mut previous_healthy)
+                        .await;
+                    self.health.send_replace(if healthy {
+                        ProviderHealth::Healthy
+                    } else {
+                        ProviderHealth::Unhealthy
+                    });
+                    if healthy {
+                        failures = 0;
+                        continue;
+                    }
+                    failures += 1;
+                    if failures != failure_threshold {
+                        continue;
+                    }
+                    let action_name = match action {
+                        ProviderHealthAction::Event => "event",
+                        ProviderHealthAction::Restart => "restart",
+                        ProviderHealthAction::Stop => "stop",
+                    };
+                    warn!(provider_id, failures, action = action_name, "provider is unhealthy");
+                    if let Err(e) = event::publish(
+                        &self.event_builder,
+                        &self.ctl_nats,
+                        &self.lattice_prefix,
+                        "provider_unhealthy",
+                        event::provider_unhealthy(
+                            &self.claims,
+                            &self.annotations,
+                            Uuid::from_u128(self.id.into()),
+                            &self.host_id,
+                            &self.link_name,
+                            failures,
+                            action_name,
+                        ),
+                    ).await {
+                        warn!(?e, "failed to publish provider unhealthy event");
+                    }
+                    if action != ProviderHealthAction::Event {
+                        return ProviderExit::Unhealthy(action);
+                    }
+                }
+                exit_status = child.wait() => return ProviderExit::Exited(exit_status),
+            }
+        }
+    }
+
+    /// Supervises the provider `child` process, restarting it until either the restart policy
+    /// or the restart budget is exhausted, the provider is stopped or the host shuts down
+    #[instrument(skip_all, fields(provider_id = self.claims.subject, link_name = self.link_name))]
+    async fn run(mut self, mut child: process::Child) {
+        let mut restarts = 0;
+        loop {
+            let started_at = Instant::now();
+            let (exit_status, unhealthy) = match self.watch(&mut child).await {
+                ProviderExit::Exited(exit_status) => (exit_status, None),
+                ProviderExit::Unhealthy(action) => {
+                    if let Err(e) = child.kill().await {
+                        warn!(?e, "failed to kill unhealthy provider");
+                    }
+                    (child.wait().await, Some(action))
+                }
+            };
+            let mut failed = match &exit_status {
+                Ok(status) => {
+                    debug!("`{}` exited with `{status:?}`", self.path.display());
+                    !status.success()
+                }
+                Err(e) => {
+                    warn!(
+                        "failed to wait for `{}` to execute: {e}",
+                        self.path.display()
+                    );
+                    true
+                }
+            };
+            if started_at.elapsed() >= self.supervision.max_backoff {
+                restarts = 0;
+            }
+            if failed && unhealthy.is_none() {
+                let Some(host) = self.host.upgrade() else {
+                    return;
+                };
+                if let Err(e) = host
+                    .publish_event(
+                        "provider_crashed",
+                        event::provider_crashed(
+                            &self.claims,
+                            &self.annotations,
+                            Uuid::from_u128(self.id.into()),
+                            &self.host_id,
+                            &self.link_name,
+                            &exit_status,
+                            restarts,
+                        ),
+                    )
+                    .await
+                {
+                    warn!(?e, "failed to publish provider crashed event");
+                }
+            }
+            loop {
+                let Some(host) = self.host.upgrade() else {
+                    return;
+                };
+                // The provider is no longer registered if it was stopped via the control interface
+                if !host
+                    .provider_instance_running(&self.claims.subject, &self.link_name, self.id)
+                    .await
+                {
+                    return;
+                }
+                // The stop channel changes once the host is requested to stop
+                let host_stopping = host.stop_rx.has_changed().unwrap_or(true);
+                let restart = match unhealthy {
+                    Some(ProviderHealthAction::Restart) => true,
+                    Some(ProviderHealthAction::Stop) => false,
+                    Some(ProviderHealthAction::Event) | None => {
+                        self.supervision.restart_policy.should_restart(failed)
+                    }
+                };
+                if host_stopping || !restart || restarts >= self.supervision.max_restarts {
+                    let reason = match (unhealthy, failed) {
+                        (Some(_), _) => "unhealthy",
+                        (None, true) => "crashed",
+                        (None, false) => "exited",
+                    };
+                    if let Err(e) = host
+                        .remove_provider_instance(
+                            &self.claims.subject,
+                            &self.link_name,
+                            self.id,
+                            reason,
+                        )
+                        .await
+                    {
+                        warn!(?e, "failed to remove exited provider");
+                    }
+                    return;
+                }
+                drop(host);
+
+                let backoff = self.supervision.backoff(restarts);
+                restarts += 1;
+                info!(?backoff, restarts, "restarting provider");
+                sleep(backoff).await;
+
+                let Some(host) = self.host.upgrade() else {
+                    return;
+                };
+                // Links may have been put or deleted since the provider was started
+                self.host_data.link_definitions = provider_link_definitions(
+                    &*host.links.read().await,
+                    &self.claims.subject,
+                    &self.link_name,
+                );
+                self.host_data.log_level = Some(host.log_level.read().await.clone());
+                match host
+                    .spawn_provider_process(
+                        &self.path,
+                        &self.host_data,
+                        &self.claims,
+                        &self.link_name,
+                    )
+                    .await
+                {
+                    Ok(restarted) => {
+                        child = restarted;
+                        if let Err(e) = host
+                            .publish_event(
+                                "provider_started",
+                                event::provider_started(
+                                    &self.claims,
+                                    &self.annotations,
+                                    Uuid::from_u128(self.id.into()),
+                                    &self.host_id,
+                                    &self.provider_ref,
+                                    &self.link_name,
+                                ),
+                            )
+                            .await
+                        {
+                            warn!(?e, "failed to publish provider started event");
+                        }
+                        break;
+                    }
+                    Err(e) => {
+                        warn!(?e, "failed to restart provider");
+                        failed = true;
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// wasmCloud Host
 pub struct Host {
     // TODO: Clean up actors after stop
@@ -1420,7 +2574,12 @@ pub struct Host {
     heartbeat: AbortHandle,
     host_config: HostConfig,
     host_key: Arc<KeyPair>,
-    labels: HashMap<String, String>,
+    labels: RwLock<HashMap<String, String>>,
+    /// Labels put (`Some`) or deleted (`None`) using the control interface, which take precedence
+    /// over labels set in the host configuration file. Only acquired while holding `labels`
+    label_overrides: Mutex<HashMap<String, Option<String>>>,
+    /// Log level passed to capability providers
+    log_level: RwLock<wasmcloud_core::logging::Level>,
     ctl_topic_prefix: String,
     /// NATS client to use for control interface subscriptions and jetstream queries
     ctl_nats: async_nats::Client,
@@ -1434,6 +2593,7 @@ pub struct Host {
     providers: RwLock<HashMap<String, Provider>>,
     registry_config: RwLock<HashMap<String, RegistryConfig>>,
     runtime: Runtime,
+    http_client: Option<HostHttpClient>,
     start_at: Instant,
     stop_tx: watch::Sender<Option<Instant>>,
     stop_rx: watch::Receiver<Option<Instant>>,
@@ -1685,9 +2845,256 @@ async fn merge_registry_config(
         .for_each(|config| config.allow_latest = allow_latest);
 }
 
-impl Host {
-    const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(30);
+/// Returns the labels of the host, consisting of the `hostcore.*` labels, labels set using
+/// `HOST_*` environment variables and `configured` labels, in order of increasing precedence
+fn host_labels(configured: &HashMap<String, String>) -> HashMap<String, String> {
+    let mut labels = HashMap::from([
+        ("hostcore.arch".into(), ARCH.into()),
+        ("hostcore.os".into(), OS.into()),
+        ("hostcore.osfamily".into(), FAMILY.into()),
+    ]);
+    labels.extend(env::vars().filter_map(|(k, v)| {
+        let k = k.strip_prefix("HOST_")?;
+        Some((k.to_lowercase(), v))
+    }));
+    labels.extend(configured.clone());
+    labels
+}
+
+/// Returns the value, in bytes, of the `key` entry in a `/proc` file with `key: value kB` lines
+#[cfg(target_os = "linux")]
+fn read_proc_kib(path: &str, key: &str) -> Option<u64> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    let kib = contents
+        .lines()
+        .find_map(|line| line.strip_prefix(key)?.strip_prefix(':'))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    kib.checked_mul(1024)
+}
+
+/// Returns the resident set size, in bytes, of the host process
+#[cfg(target_os = "linux")]
+fn process_rss() -> Option<u64> {
+    read_proc_kib("/proc/self/status", "VmRSS")
+}
+
+/// Returns the resident set size, in bytes, of the host process
+#[cfg(not(target_os = "linux"))]
+fn process_rss() -> Option<u64> {
+    None
+}
+
+/// Returns the amount of memory, in bytes, available for starting new workloads on the system
+#[cfg(target_os = "linux")]
+fn available_memory() -> Option<u64> {
+    read_proc_kib("/proc/meminfo", "MemAvailable")
+}
+
+/// Returns the amount of memory, in bytes, available for starting new workloads on the system
+#[cfg(not(target_os = "linux"))]
+fn available_memory() -> Option<u64> {
+    None
+}
+
+/// Whether host `labels` satisfy all auction `constraints`
+fn constraints_satisfied(
+    labels: &HashMap<String, String>,
+    constraints: &HashMap<String, String>,
+) -> bool {
+    constraints
+        .iter()
+        .all(|(key, value)| labels.get(key) == Some(value))
+}
+
+/// Returns the total amount of instances of `actors`
+async fn count_actor_instances(actors: &HashMap<String, Arc<Actor>>) -> usize {
+    stream::iter(actors.values())
+        .then(|actor| async move {
+            actor
+                .instances
+                .read()
+                .await
+                .values()
+                .map(Vec::len)
+                .sum::<usize>()
+        })
+        .fold(0, |total, count| async move { total + count })
+        .await
+}
+
+/// Returns the amount of capability provider processes of `providers`
+fn count_providers(providers: &HashMap<String, Provider>) -> usize {
+    providers
+        .values()
+        .map(|Provider { instances, .. }| instances.len())
+        .sum()
+}
+
+/// Error returned when a command would exceed the configured maximum of a host capacity limit
+#[derive(Debug, PartialEq, Eq)]
+struct CapacityExceeded {
+    limit: &'static str,
+    max: usize,
+    requested: usize,
+}
+
+impl fmt::Display for CapacityExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            limit,
+            max,
+            requested,
+        } = self;
+        write!(
+            f,
+            "host capacity exceeded: {requested} {limit} requested, at most {max} allowed"
+        )
+    }
+}
+
+impl std::error::Error for CapacityExceeded {}
+
+/// Ensures that `requested` does not exceed the configured `max` of host capacity `limit`
+fn ensure_capacity(
+    limit: &'static str,
+    max: Option<usize>,
+    requested: usize,
+) -> Result<(), CapacityExceeded> {
+    match max {
+        Some(max) if requested > max => Err(CapacityExceeded {
+            limit,
+            max,
+            requested,
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Ensures that `capacity` admits running `actors` distinct actors with a total of
+/// `actor_instances` instances
+fn ensure_actor_capacity(
+    capacity: &config::Capacity,
+    actors: usize,
+    actor_instances: usize,
+) -> Result<(), CapacityExceeded> {
+    ensure_capacity("actors", capacity.max_actors, actors)?;
+    ensure_capacity(
+        "actor_instances",
+        capacity.max_actor_instances,
+        actor_instances,
+    )
+}
+
+/// Capacity of the host reported in auction responses
+#[derive(Debug, Serialize)]
+struct HostCapacity {
+    actors: usize,
+    max_actors: Option<usize>,
+    actor_instances: usize,
+    max_actor_instances: Option<usize>,
+    providers: usize,
+    max_providers: Option<usize>,
+    /// Memory, in bytes, available on the system, if it can be determined
+    memory_available: Option<u64>,
+    /// Whether the auctioned image is available on the host without pulling it
+    image_cached: bool,
+}
+
+/// Whether the memory available on the system satisfies the configured minimum headroom.
+/// If the available memory cannot be determined, the headroom is assumed to be available
+fn memory_headroom_available(limits: &config::Capacity, capacity: &HostCapacity) -> bool {
+    match (limits.min_memory_headroom, capacity.memory_available) {
+        (Some(min), Some(available)) => available >= min,
+        _ => true,
+    }
+}
+
+/// Returns the reason to decline an actor auction given the configured capacity `limits` and the
+/// current `capacity` of the host, if it should be declined. `running` denotes whether the
+/// auctioned actor is already running on the host
+fn actor_auction_declined(
+    limits: &config::Capacity,
+    capacity: &HostCapacity,
+    running: bool,
+) -> Option<&'static str> {
+    if !running && limits.max_actors.is_some_and(|max| capacity.actors >= max) {
+        return Some("maximum actors reached");
+    }
+    if limits
+        .max_actor_instances
+        .is_some_and(|max| capacity.actor_instances >= max)
+    {
+        return Some("maximum actor instances reached");
+    }
+    if !memory_headroom_available(limits, capacity) {
+        return Some("minimum memory headroom not available");
+    }
+    None
+}
+
+/// Returns the reason to decline a provider auction given the configured capacity `limits` and
+/// the current `capacity` of the host, if it should be declined
+fn provider_auction_declined(
+    limits: &config::Capacity,
+    capacity: &HostCapacity,
+) -> Option<&'static str> {
+    if limits
+        .max_providers
+        .is_some_and(|max| capacity.providers >= max)
+    {
+        return Some("maximum providers reached");
+    }
+    if !memory_headroom_available(limits, capacity) {
+        return Some("minimum memory headroom not available");
+    }
+    None
+}
+
+/// Request to set a host label, sent on `labels.{host_id}.put`
+#[derive(Debug, Deserialize)]
+struct HostLabel {
+    key: String,
+    value: String,
+}
+
+/// Request to remove a host label, sent on `labels.{host_id}.del`
+#[derive(Debug, Deserialize)]
+struct HostLabelKey {
+    key: String,
+}
+
+/// Returns the host labels given the labels `configured` for the host and the `overrides` made
+/// using the control interface, which take precedence
+fn overridden_host_labels(
+    configured: &HashMap<String, String>,
+    overrides: &HashMap<String, Option<String>>,
+) -> HashMap<String, String> {
+    let mut labels = host_labels(configured);
+    for (key, value) in overrides {
+        if let Some(value) = value {
+            labels.insert(key.clone(), value.clone());
+        } else {
+            labels.remove(key);
+        }
+    }
+    labels
+}
+
+/// Ensures that a label with `key` may be changed using the control interface
+fn ensure_mutable_label(key: &str) -> anyhow::Result<()> {
+    ensure!(!key.is_empty(), "label key cannot be empty");
+    ensure!(
+        !key.to_ascii_lowercase().starts_with("hostcore."),
+        "`hostcore.*` labels cannot be changed"
+    );
+    Ok(())
+}
 
+impl Host {
     const NAME_ADJECTIVES: &str = "
     autumn hidden bitter misty silent empty dry dark summer
     icy delicate quiet white cool spring winter patient
@@ -1744,15 +3151,7 @@ impl Host {
             Arc::new(KeyPair::new(KeyPairType::Server))
         };
 
-        let mut labels = HashMap::from([
-            ("hostcore.arch".into(), ARCH.into()),
-            ("hostcore.os".into(), OS.into()),
-            ("hostcore.osfamily".into(), FAMILY.into()),
-        ]);
-        labels.extend(env::vars().filter_map(|(k, v)| {
-            let k = k.strip_prefix("HOST_")?;
-            Some((k.to_lowercase(), v))
-        }));
+        let labels = host_labels(&config.labels);
         let friendly_name =
             Self::generate_friendly_name().context("failed to generate friendly name")?;
 
@@ -1822,23 +3221,36 @@ impl Host {
             }
         )?;
 
+        ensure!(
+            !config.heartbeat_interval.is_zero(),
+            "heartbeat interval must be non-zero"
+        );
         let start_at = Instant::now();
 
         let heartbeat_start_at = start_at
-            .checked_add(Self::HEARTBEAT_INTERVAL)
+            .checked_add(config.heartbeat_interval)
             .context("failed to compute heartbeat start time")?;
         let heartbeat =
-            IntervalStream::new(interval_at(heartbeat_start_at, Self::HEARTBEAT_INTERVAL));
+            IntervalStream::new(interval_at(heartbeat_start_at, config.heartbeat_interval));
 
         let (stop_tx, stop_rx) = watch::channel(None);
 
         // TODO: Configure
-        let runtime = Runtime::builder()
-            .actor_config(wasmcloud_runtime::ActorConfig {
-                require_signature: true,
-            })
-            .build()
-            .context("failed to build runtime")?;
+        let mut runtime = Runtime::builder().actor_config(wasmcloud_runtime::ActorConfig {
+            require_signature: true,
+            max_fuel: config.max_fuel,
+            max_execution_time: config.max_execution_time,
+            limits: config.actor_limits,
+        });
+        if let Some(dir) = &config.artifact_cache_dir {
+            runtime = runtime.artifact_cache(dir, config.artifact_cache_max_size);
+        }
+        let runtime = runtime.build().context("failed to build runtime")?;
+        let http_client = HostHttpClient::new(
+            config.allowed_http_authorities.iter().cloned(),
+            config.rpc_timeout,
+        )
+        .context("failed to construct host HTTP client")?;
         let event_builder = EventBuilderV10::new().source(host_key.public_key());
 
         let ctl_jetstream = if let Some(domain) = config.js_domain.as_ref() {
@@ -1897,7 +3309,9 @@ impl Host {
             heartbeat: heartbeat_abort.clone(),
             ctl_topic_prefix: config.ctl_topic_prefix.clone(),
             host_key,
-            labels,
+            labels: RwLock::new(labels),
+            label_overrides: Mutex::default(),
+            log_level: RwLock::new(config.log_level.clone()),
             ctl_nats,
             rpc_nats,
             prov_rpc_nats,
@@ -1908,6 +3322,7 @@ impl Host {
             providers: RwLock::default(),
             registry_config,
             runtime,
+            http_client,
             start_at,
             stop_rx,
             stop_tx,
@@ -1980,10 +3395,23 @@ impl Host {
             data_watch_abort.abort();
             host.policy_manager.policy_changes.abort();
             let _ = try_join!(queue, data_watch, heartbeat).context("failed to await tasks")?;
+            // Use the deadline requested via the stop command, if any
+            let deadline = match *host.stop_rx.borrow() {
+                Some(deadline) => deadline,
+                None => Instant::now()
+                    .checked_add(host.host_config.drain_timeout)
+                    .context("failed to compute drain deadline")?,
+            };
+            let (drained, abandoned) = host.drain_actors(deadline).await;
+            if abandoned > 0 {
+                warn!(abandoned, "abandoned in-flight actor invocations");
+            }
             host.publish_event(
                 "host_stopped",
                 json!({
-                    "labels": host.labels,
+                    "labels": *host.labels.read().await,
+                    "drained": drained,
+                    "abandoned": abandoned,
                 }),
             )
             .await
@@ -2001,41 +3429,132 @@ impl Host {
             host.prov_rpc_nats
                 .flush()
                 .await
-                .context("failed to flush prov rpc client")
-        }))
-    }
+                .context("failed to flush prov rpc client")
+        }))
+    }
+
+    /// Waits for host to be stopped via lattice commands and returns the shutdown deadline on
+    /// success
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if internal stop channel is closed prematurely
+    #[instrument(skip(self))]
+    pub async fn stopped(&self) -> anyhow::Result<Option<Instant>> {
+        self.stop_rx
+            .clone()
+            .changed()
+            .await
+            .context("failed to wait for stop")?;
+        Ok(*self.stop_rx.borrow())
+    }
+
+    /// Applies the settings of a host configuration `file`, which can be changed on a running
+    /// host, and publishes a `host_config_updated` event. Settings omitted from the file are left
+    /// unchanged, except for labels, which are reset. Labels put or deleted using the control
+    /// interface take precedence over the labels in the file. See [`config::File`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the policy service could not be reconfigured or the event could not be
+    /// published
+    #[instrument(skip_all)]
+    pub async fn reload_config(&self, file: &config::File) -> anyhow::Result<()> {
+        let restart_required = file.restart_required(&self.host_config);
+        if !restart_required.is_empty() {
+            warn!(
+                ?restart_required,
+                "host configuration changes require a restart to take effect"
+            );
+        }
+
+        let labels = {
+            let mut labels = self.labels.write().await;
+            let configured = overridden_host_labels(
+                file.labels.as_ref().unwrap_or(&HashMap::default()),
+                &*self.label_overrides.lock().await,
+            );
+            if *labels != configured {
+                *labels = configured.clone();
+                self.labels_changed(configured.clone()).await?;
+            }
+            configured
+        };
+
+        let log_level = {
+            let mut log_level = self.log_level.write().await;
+            if let Some(configured) = &file.log_level {
+                if let Err(e) = wasmcloud_tracing::set_log_level(configured) {
+                    warn!(error = %e, "failed to update host log level");
+                }
+                *log_level = configured.clone();
+            }
+            log_level.clone()
+        };
+
+        if let Some(oci_opts) = &file.oci_opts {
+            merge_registry_config(&self.registry_config, oci_opts.clone()).await;
+        }
+
+        if let Some(PolicyService {
+            policy_topic,
+            policy_changes_topic,
+            policy_timeout_ms,
+        }) = &file.policy_service_config
+        {
+            self.policy_manager
+                .reconfigure(
+                    policy_topic.clone(),
+                    *policy_timeout_ms,
+                    policy_changes_topic.clone(),
+                )
+                .await
+                .context("failed to reconfigure policy service")?;
+        }
 
-    /// Waits for host to be stopped via lattice commands and returns the shutdown deadline on
-    /// success
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if internal stop channel is closed prematurely
-    #[instrument(skip(self))]
-    pub async fn stopped(&self) -> anyhow::Result<Option<Instant>> {
-        self.stop_rx
-            .clone()
-            .changed()
-            .await
-            .context("failed to wait for stop")?;
-        Ok(*self.stop_rx.borrow())
+        info!("host configuration reloaded");
+        self.publish_event(
+            "host_config_updated",
+            event::host_config_updated(&labels, &log_level, &restart_required),
+        )
+        .await
     }
 
     #[instrument(skip(self))]
     async fn heartbeat(&self) -> serde_json::Value {
         let actors = self.actors.read().await;
-        let actors: HashMap<&String, usize> = stream::iter(actors.iter())
+        let actor_stats: HashMap<&String, serde_json::Value> = stream::iter(actors.iter())
             .filter_map(|(id, actor)| async move {
                 let instances = actor.instances.read().await;
-                let count = instances.values().map(Vec::len).sum();
+                let instances = instances.values().flatten();
+                let count = instances.clone().count();
                 if count == 0 {
-                    None
-                } else {
-                    Some((id, count))
+                    return None;
                 }
+                let (in_flight, invocations, errors) =
+                    instances.fold((0, 0, 0), |(in_flight, invocations, errors), instance| {
+                        (
+                            in_flight + *instance.in_flight.borrow(),
+                            invocations + instance.invocations.swap(0, Ordering::Relaxed),
+                            errors + instance.errors.swap(0, Ordering::Relaxed),
+                        )
+                    });
+                Some((
+                    id,
+                    json!({
+                        "instances": count,
+                        "in_flight": in_flight,
+                        "invocations": invocations,
+                        "errors": errors,
+                    }),
+                ))
             })
             .collect()
             .await;
+        let actors: HashMap<_, _> = actor_stats
+            .iter()
+            .map(|(id, stats)| (*id, &stats["instances"]))
+            .collect();
         let providers: Vec<_> = self
             .providers
             .read()
@@ -2048,25 +3567,31 @@ impl Host {
                         claims, instances, ..
                     },
                 )| {
-                    instances.keys().map(move |link_name| {
-                        let metadata = claims.metadata.as_ref();
-                        let contract_id =
-                            metadata.map(|jwt::CapabilityProvider { capid, .. }| capid.as_str());
-                        json!({
-                            "public_key": public_key,
-                            "link_name": link_name,
-                            "contract_id": contract_id.unwrap_or("n/a"),
+                    instances
+                        .iter()
+                        .map(move |(link_name, ProviderInstance { health, .. })| {
+                            let metadata = claims.metadata.as_ref();
+                            let contract_id = metadata
+                                .map(|jwt::CapabilityProvider { capid, .. }| capid.as_str());
+                            json!({
+                                "public_key": public_key,
+                                "link_name": link_name,
+                                "contract_id": contract_id.unwrap_or("n/a"),
+                                "health": *health.borrow(),
+                            })
                         })
-                    })
                 },
             )
             .collect();
         let uptime = self.start_at.elapsed();
         json!({
             "actors": actors,
+            "actor_stats": actor_stats,
             "friendly_name": self.friendly_name,
-            "labels": self.labels,
+            "labels": *self.labels.read().await,
+            "memory_rss_bytes": process_rss(),
             "providers": providers,
+            "runtime_version": self.runtime.version(),
             "uptime_human": human_friendly_uptime(uptime),
             "uptime_seconds": uptime.as_secs(),
             "version": env!("CARGO_PKG_VERSION"),
@@ -2089,9 +3614,25 @@ impl Host {
         .await
     }
 
+    /// Refreshes the host info used in policy requests and publishes a `labels_changed` event
+    /// after the host labels were changed to `labels`
+    async fn labels_changed(&self, labels: HashMap<String, String>) -> anyhow::Result<()> {
+        let host_id = self.host_key.public_key();
+        self.policy_manager
+            .update_host_info(PolicyHostInfo {
+                public_key: host_id.clone(),
+                lattice_id: self.host_config.lattice_prefix.clone(),
+                labels: labels.clone(),
+                cluster_issuers: self.cluster_issuers.clone(),
+            })
+            .await;
+        self.publish_event("labels_changed", event::labels_changed(host_id, &labels))
+            .await
+    }
+
     /// Instantiate an actor and publish the actor start events.
     #[allow(clippy::too_many_arguments)] // TODO: refactor into a config struct
-    #[instrument(skip(self, claims, annotations, host_id, actor_ref, actor, handler))]
+    #[instrument(skip(self, claims, annotations, host_id, actor_ref, pool, handler))]
     async fn instantiate_actor(
         &self,
         claims: &jwt::Claims<jwt::Actor>,
@@ -2099,12 +3640,19 @@ impl Host {
         host_id: impl AsRef<str>,
         actor_ref: impl AsRef<str>,
         count: NonZeroUsize,
-        actor: wasmcloud_runtime::Actor,
+        pool: InstancePool,
         handler: Handler,
     ) -> anyhow::Result<Vec<Arc<ActorInstance>>> {
         trace!(actor_ref = actor_ref.as_ref(), count, "instantiating actor");
 
         let actor_ref = actor_ref.as_ref();
+        // Each started instance allows for one more concurrent invocation of the actor
+        pool.resize(pool.size() + usize::from(count));
+        if let Err(e) = pool.fill().await {
+            pool.resize(pool.size() - usize::from(count));
+            return Err(e).context("failed to pre-instantiate actor");
+        }
+
         let instances = stream::repeat(format!(
             "wasmbus.rpc.{lattice_prefix}.{subject}",
             lattice_prefix = self.host_config.lattice_prefix,
@@ -2112,7 +3660,7 @@ impl Host {
         ))
         .take(count.into())
         .then(|topic| {
-            let actor = actor.clone();
+            let pool = pool.clone();
             let handler = handler.clone();
             let claims = claims.clone();
             async move {
@@ -2123,25 +3671,47 @@ impl Host {
                     .context("failed to subscribe to actor call queue")?;
 
                 let (calls_abort, calls_abort_reg) = AbortHandle::new_pair();
+                let (draining, draining_rx) = watch::channel(false);
                 let id = Ulid::new();
                 let instance = Arc::new(ActorInstance {
                     nats: self.rpc_nats.clone(),
-                    actor,
+                    pool,
                     id,
                     calls: calls_abort,
+                    draining,
+                    in_flight: watch::channel(0).0,
+                    invocations: AtomicU64::default(),
+                    errors: AtomicU64::default(),
                     handler: handler.clone(),
                     chunk_endpoint: self.chunk_endpoint.clone(),
+                    ctl_nats: self.ctl_nats.clone(),
+                    event_builder: self.event_builder.clone(),
                     valid_issuers: self.cluster_issuers.clone(),
                     policy_manager: Arc::clone(&self.policy_manager),
                     actor_claims: Arc::clone(&self.actor_claims),
                     provider_claims: Arc::clone(&self.provider_claims),
                 });
 
+                // Once draining, the subscription is dropped, which unsubscribes from the queue
+                let calls = stream::unfold(
+                    (calls, draining_rx),
+                    |(mut calls, mut draining)| async move {
+                        let msg = select! {
+                            msg = calls.next() => msg,
+                            _ = draining.wait_for(|&draining| draining) => None,
+                        };
+                        msg.map(|msg| (msg, (calls, draining)))
+                    },
+                );
                 let _calls = spawn({
                     let instance = Arc::clone(&instance);
                     Abortable::new(calls, calls_abort_reg).for_each_concurrent(None, move |msg| {
                         let instance = Arc::clone(&instance);
-                        async move { instance.handle_message(msg).await }
+                        instance.in_flight.send_modify(|n| *n += 1);
+                        async move {
+                            instance.handle_message(msg).await;
+                            instance.in_flight.send_modify(|n| *n -= 1);
+                        }
                     })
                 });
 
@@ -2170,6 +3740,7 @@ impl Host {
     }
 
     /// Uninstantiate an actor and publish the actor stop events.
+    /// In-flight invocations are given [`HostConfig::drain_timeout`] to complete.
     #[instrument(skip(self, instances))]
     async fn uninstantiate_actor(
         &self,
@@ -2187,30 +3758,67 @@ impl Host {
             "uninstantiating actor instances"
         );
 
-        stream::iter(instances.drain(..usize::from(count)))
-            .map(Ok)
-            .try_for_each_concurrent(None, |instance| {
-                instance.calls.abort();
-                async move {
-                    self.publish_event(
-                        "actor_stopped",
-                        event::actor_stopped(
-                            claims,
-                            annotations,
-                            Uuid::from_u128(instance.id.into()),
-                        ),
-                    )
-                    .await
-                }
+        let deadline = Instant::now()
+            .checked_add(self.host_config.drain_timeout)
+            .context("failed to compute drain deadline")?;
+        let (drained, abandoned) = stream::iter(instances.drain(..usize::from(count)))
+            .map(|instance| async move {
+                let (drained, abandoned) = instance.drain(deadline).await;
+                instance.pool.resize(instance.pool.size().saturating_sub(1));
+                self.publish_event(
+                    "actor_stopped",
+                    event::actor_stopped(claims, annotations, Uuid::from_u128(instance.id.into())),
+                )
+                .await?;
+                anyhow::Result::<_>::Ok((drained, abandoned))
+            })
+            .buffer_unordered(count.into())
+            .try_fold((0, 0), |(drained, abandoned), (d, a)| async move {
+                Ok((drained + d, abandoned + a))
             })
             .await?;
+        if abandoned > 0 {
+            warn!(
+                subject = claims.subject,
+                abandoned, "abandoned in-flight invocations of stopped actor instances"
+            );
+        }
         self.publish_event(
             "actors_stopped",
-            event::actors_stopped(claims, annotations, host_id, count, remaining),
+            event::actors_stopped(
+                claims,
+                annotations,
+                host_id,
+                count,
+                remaining,
+                drained,
+                abandoned,
+            ),
         )
         .await
     }
 
+    /// Stops accepting new invocations on all actor instances and waits for in-flight
+    /// invocations to complete until `deadline`.
+    /// Returns the amounts of drained and abandoned invocations.
+    #[instrument(skip(self))]
+    async fn drain_actors(&self, deadline: Instant) -> (usize, usize) {
+        let actors = self.actors.read().await;
+        let instances = stream::iter(actors.values())
+            .then(|actor| async move {
+                let instances = actor.instances.read().await;
+                instances.values().flatten().cloned().collect::<Vec<_>>()
+            })
+            .concat()
+            .await;
+        join_all(instances.iter().map(|instance| instance.drain(deadline)))
+            .await
+            .into_iter()
+            .fold((0, 0), |(drained, abandoned), (d, a)| {
+                (drained + d, abandoned + a)
+            })
+    }
+
     #[instrument(skip(self, entry, actor, annotations))]
     async fn start_actor<'a>(
         &self,
@@ -2224,6 +3832,10 @@ impl Host {
         trace!(actor_ref, "starting new actor");
 
         let annotations = annotations.into();
+        // NOTE: Limits are determined by the annotations the actor was started with
+        let limits = annotated_actor_limits(self.host_config.actor_limits, &annotations)
+            .context("failed to parse actor limits")?;
+        let actor = actor.with_limits(limits);
         let claims = actor.claims().context("claims missing")?;
         self.store_claims(Claims::Actor(claims.clone()))
             .await
@@ -2233,26 +3845,13 @@ impl Host {
         let links = links
             .values()
             .filter(|ld| ld.actor_id == claims.subject)
-            .fold(
-                HashMap::<_, HashMap<_, _>>::default(),
-                |mut links,
-                 LinkDefinition {
-                     link_name,
-                     contract_id,
-                     provider_id,
-                     ..
-                 }| {
-                    links.entry(contract_id.clone()).or_default().insert(
-                        link_name.clone(),
-                        WasmCloudEntity {
-                            link_name: link_name.clone(),
-                            contract_id: contract_id.clone(),
-                            public_key: provider_id.clone(),
-                        },
-                    );
-                    links
-                },
-            );
+            .fold(HashMap::<_, HashMap<_, _>>::default(), |mut links, ld| {
+                links
+                    .entry(ld.contract_id.clone())
+                    .or_default()
+                    .insert(ld.link_name.clone(), LinkTarget::new(ld));
+                links
+            });
         let origin = WasmCloudEntity {
             public_key: claims.subject.clone(),
             ..Default::default()
@@ -2264,12 +3863,15 @@ impl Host {
             cluster_key: Arc::clone(&self.cluster_key),
             claims: claims.clone(),
             aliases: Arc::clone(&self.aliases),
+            rpc_timeout: self.host_config.rpc_timeout,
             links: Arc::new(RwLock::new(links)),
             targets: Arc::new(RwLock::default()),
             host_key: Arc::clone(&self.host_key),
             chunk_endpoint: self.chunk_endpoint.clone(),
+            http_client: self.http_client.clone(),
         };
 
+        let pool = InstancePool::new(&self.runtime, actor.clone(), 0);
         let instances = self
             .instantiate_actor(
                 claims,
@@ -2277,16 +3879,18 @@ impl Host {
                 host_id,
                 &actor_ref,
                 count,
-                actor.clone(),
+                pool.clone(),
                 handler.clone(),
             )
             .await
             .context("failed to instantiate actor")?;
         let actor = Arc::new(Actor {
             actor,
+            pool,
             instances: RwLock::new(HashMap::from([(annotations, instances)])),
             image_ref: actor_ref,
             handler,
+            update: Arc::default(),
         });
         Ok(entry.insert(actor))
     }
@@ -2330,7 +3934,10 @@ impl Host {
     }
 
     #[instrument(skip(self, payload))]
-    async fn handle_auction_actor(&self, payload: impl AsRef<[u8]>) -> anyhow::Result<Bytes> {
+    async fn handle_auction_actor(
+        &self,
+        payload: impl AsRef<[u8]>,
+    ) -> anyhow::Result<Option<Bytes>> {
         let ActorAuctionRequest {
             actor_ref,
             constraints,
@@ -2339,13 +3946,33 @@ impl Host {
 
         debug!(actor_ref, ?constraints, "auction actor");
 
-        let buf = serde_json::to_vec(&ActorAuctionAck {
-            actor_ref,
-            constraints,
-            host_id: self.host_key.public_key(),
-        })
+        if !constraints_satisfied(&*self.labels.read().await, &constraints) {
+            return Ok(None);
+        }
+        let running = self
+            .actors
+            .read()
+            .await
+            .values()
+            .any(|actor| actor.image_ref == actor_ref);
+        let image_cached = running || image_cached(&actor_ref).await;
+        let capacity = self.capacity(image_cached).await;
+        if let Some(reason) = actor_auction_declined(&self.host_config.capacity, &capacity, running)
+        {
+            debug!(actor_ref, reason, "declining actor auction");
+            return Ok(None);
+        }
+
+        // TODO: ActorAuctionAck is missing `capacity` field.
+        // Either replace this by ActorAuctionAck or update upstream.
+        let buf = serde_json::to_vec(&json!({
+          "actor_ref": actor_ref,
+          "constraints": constraints,
+          "host_id": self.host_key.public_key(),
+          "capacity": capacity,
+        }))
         .context("failed to encode reply")?;
-        Ok(buf.into())
+        Ok(Some(buf.into()))
     }
 
     #[instrument(skip(self, payload))]
@@ -2362,30 +3989,99 @@ impl Host {
 
         debug!(provider_ref, link_name, ?constraints, "auction provider");
 
-        let providers = self.providers.read().await;
-        if providers.values().any(
-            |Provider {
-                 image_ref,
-                 instances,
-                 ..
-             }| { *image_ref == provider_ref && instances.contains_key(&link_name) },
-        ) {
-            // Do not reply if the provider is already running
+        if !constraints_satisfied(&*self.labels.read().await, &constraints) {
+            return Ok(None);
+        }
+        let running = {
+            let providers = self.providers.read().await;
+            if providers.values().any(
+                |Provider {
+                     image_ref,
+                     instances,
+                     ..
+                 }| {
+                    *image_ref == provider_ref && instances.contains_key(&link_name)
+                },
+            ) {
+                // Do not reply if the provider is already running
+                return Ok(None);
+            }
+            providers
+                .values()
+                .any(|Provider { image_ref, .. }| *image_ref == provider_ref)
+        };
+        let image_cached = running || image_cached(&provider_ref).await;
+        let capacity = self.capacity(image_cached).await;
+        if let Some(reason) = provider_auction_declined(&self.host_config.capacity, &capacity) {
+            debug!(
+                provider_ref,
+                link_name, reason, "declining provider auction"
+            );
             return Ok(None);
         }
 
-        // TODO: ProviderAuctionAck is missing `constraints` field sent by OTP.
+        // TODO: ProviderAuctionAck is missing `constraints` and `capacity` fields sent by OTP.
         // Either replace this by ProviderAuctionAck or update upstream.
         let buf = serde_json::to_vec(&json!({
           "provider_ref": provider_ref,
           "link_name": link_name,
           "constraints": constraints,
           "host_id": self.host_key.public_key(),
+          "capacity": capacity,
         }))
         .context("failed to encode reply")?;
         Ok(Some(buf.into()))
     }
 
+    /// Returns the current capacity of the host, `image_cached` denotes whether the auctioned
+    /// image is available locally
+    async fn capacity(&self, image_cached: bool) -> HostCapacity {
+        let config::Capacity {
+            max_actors,
+            max_actor_instances,
+            max_providers,
+            ..
+        } = self.host_config.capacity;
+        let (actors, actor_instances) = {
+            let actors = self.actors.read().await;
+            (actors.len(), count_actor_instances(&actors).await)
+        };
+        HostCapacity {
+            actors,
+            max_actors,
+            actor_instances,
+            max_actor_instances,
+            providers: count_providers(&*self.providers.read().await),
+            max_providers,
+            memory_available: available_memory(),
+            image_cached,
+        }
+    }
+
+    /// Publishes a `host_capacity_exceeded` event if `err` was caused by a command exceeding the
+    /// host capacity. This is called once the command failed, so that no locks are held while
+    /// publishing
+    async fn publish_capacity_exceeded(&self, err: &anyhow::Error) {
+        let Some(CapacityExceeded {
+            limit,
+            max,
+            requested,
+        }) = err.downcast_ref()
+        else {
+            return;
+        };
+        warn!(limit, max, requested, "host capacity exceeded");
+        if let Err(e) = self
+            .publish_event(
+                "host_capacity_exceeded",
+                event::host_capacity_exceeded(self.host_key.public_key(), limit, *max, *requested),
+            )
+            .await
+        {
+            warn!(?e, "failed to publish host capacity exceeded event");
+        }
+    }
+
     #[instrument(skip(self))]
     async fn fetch_actor(&self, actor_ref: &str) -> anyhow::Result<wasmcloud_runtime::Actor> {
         let registry_config = self.registry_config.read().await;
@@ -2484,17 +4180,23 @@ impl Host {
         };
 
         let annotations: Annotations = annotations.unwrap_or_default().into_iter().collect();
-        match (
-            self.actors.write().await.entry(actor_id),
-            NonZeroUsize::new(count.into()),
-        ) {
+        let mut actors = self.actors.write().await;
+        let actor_count = actors.len();
+        let instance_count = count_actor_instances(&actors).await;
+        match (actors.entry(actor_id), NonZeroUsize::new(count.into())) {
             (hash_map::Entry::Vacant(_), None) => {}
             (hash_map::Entry::Vacant(entry), Some(count)) => {
+                ensure_actor_capacity(
+                    &self.host_config.capacity,
+                    actor_count + 1,
+                    instance_count + usize::from(count),
+                )?;
                 self.start_actor(entry, actor, actor_ref, count, host_id, annotations)
                     .await?;
             }
             (hash_map::Entry::Occupied(entry), _) => {
                 let actor = entry.get();
+                actor.ensure_not_updating()?;
                 if actor.image_ref != actor_ref {
                     let err = anyhow!(
                         "actor is already running with a different image reference `{}`",
@@ -2524,6 +4226,11 @@ impl Host {
 
                 let claims = actor.claims().context("claims missing")?;
                 if let Some(delta) = count.checked_sub(current).and_then(NonZeroUsize::new) {
+                    ensure_actor_capacity(
+                        &self.host_config.capacity,
+                        actor_count,
+                        instance_count + usize::from(delta),
+                    )?;
                     let mut delta = self
                         .instantiate_actor(
                             claims,
@@ -2531,7 +4238,7 @@ impl Host {
                             host_id,
                             &actor.image_ref,
                             delta,
-                            actor.actor.clone(),
+                            actor.pool.clone(),
                             actor.handler.clone(),
                         )
                         .await
@@ -2633,8 +4340,16 @@ impl Host {
             return Ok(());
         };
 
-        match self.actors.write().await.entry(actor_id) {
+        let mut actors = self.actors.write().await;
+        let actor_count = actors.len();
+        let actor_instances = count_actor_instances(&actors).await + usize::from(count);
+        match actors.entry(actor_id) {
             hash_map::Entry::Vacant(entry) => {
+                ensure_actor_capacity(
+                    &self.host_config.capacity,
+                    actor_count + 1,
+                    actor_instances,
+                )?;
                 if let Err(err) = self
                     .start_actor(
                         entry,
@@ -2655,6 +4370,7 @@ impl Host {
             }
             hash_map::Entry::Occupied(entry) => {
                 let actor = entry.get();
+                actor.ensure_not_updating()?;
                 if actor.image_ref != actor_ref {
                     let err = anyhow!(
                         "actor is already running with a different image reference `{}`",
@@ -2667,6 +4383,7 @@ impl Host {
                     .await?;
                     bail!(err);
                 }
+                ensure_actor_capacity(&self.host_config.capacity, actor_count, actor_instances)?;
                 let mut instances = actor.instances.write().await;
                 let claims = actor.claims().context("claims missing")?;
                 let mut delta = self
@@ -2676,7 +4393,7 @@ impl Host {
                         host_id,
                         &actor.image_ref,
                         count,
-                        actor.actor.clone(),
+                        actor.pool.clone(),
                         actor.handler.clone(),
                     )
                     .await
@@ -2701,6 +4418,16 @@ impl Host {
         } = serde_json::from_slice(payload.as_ref())
             .context("failed to deserialize actor launch command")?;
 
+        if count > 0 {
+            let actors = self.actors.read().await;
+            let running = actors.values().any(|actor| actor.image_ref == actor_ref);
+            ensure_actor_capacity(
+                &self.host_config.capacity,
+                actors.len() + usize::from(!running),
+                count_actor_instances(&actors).await + usize::from(count),
+            )?;
+        }
+
         let host_id = host_id.to_string();
         spawn(async move {
             if let Err(err) = self
@@ -2712,6 +4439,7 @@ impl Host {
                 )
                 .await
             {
+                self.publish_capacity_exceeded(&err).await;
                 if let Err(err) = self
                     .publish_event(
                         "actor_start_failed",
@@ -2748,10 +4476,12 @@ impl Host {
             NonZeroUsize::new(count.into()),
         ) {
             (hash_map::Entry::Occupied(entry), None) => {
+                entry.get().ensure_not_updating()?;
                 self.stop_actor(entry, host_id).await?;
             }
             (hash_map::Entry::Occupied(entry), Some(count)) => {
                 let actor = entry.get();
+                actor.ensure_not_updating()?;
                 let claims = actor.claims().context("claims missing")?;
                 let mut instances = actor.instances.write().await;
                 if let hash_map::Entry::Occupied(mut entry) = instances.entry(annotations.clone()) {
@@ -2800,61 +4530,381 @@ impl Host {
 
         debug!(actor_id, new_actor_ref, ?annotations, "update actor");
 
-        let actors = self.actors.write().await;
-        let actor = actors.get(&actor_id).context("actor not found")?;
-        let annotations = annotations.unwrap_or_default().into_iter().collect(); // convert from HashMap to BTreeMap
-        let mut all_instances = actor.instances.write().await;
-        let matching_instances = all_instances
-            .get_mut(&annotations)
-            .context("actor instances with matching annotations not found")?;
-        let count =
-            NonZeroUsize::new(matching_instances.len()).context("zero instances of actor found")?;
-
-        let new_actor = self.fetch_actor(&new_actor_ref).await?;
-        let new_claims = new_actor
-            .claims()
-            .context("claims missing from new actor")?;
-        self.store_claims(Claims::Actor(new_claims.clone()))
-            .await
-            .context("failed to store claims")?;
+        let annotations: Annotations = annotations.unwrap_or_default().into_iter().collect(); // convert from HashMap to BTreeMap
+
+        // NOTE: The update guard is acquired while holding the actor map read lock, so that no
+        // scale or stop of the actor can be in progress concurrently
+        let (actor, _update) = {
+            let actors = self.actors.read().await;
+            let actor = actors.get(&actor_id).cloned().context("actor not found")?;
+            let update = Arc::clone(&actor.update)
+                .try_lock_owned()
+                .map_err(|_| anyhow!("actor `{actor_id}` is already being updated"))?;
+            (actor, update)
+        };
+        let (old_ids, old_pool) = {
+            let instances = actor.instances.read().await;
+            let matching_instances = instances
+                .get(&annotations)
+                .context("actor instances with matching annotations not found")?;
+            // The image reference and pool are tracked per actor, so instances with other
+            // annotations would keep running the previous version after the update
+            ensure!(
+                instances.len() == 1,
+                "actor `{actor_id}` has instances with other annotations, which cannot be updated"
+            );
+            let pool = matching_instances
+                .first()
+                .context("zero instances of actor found")?
+                .pool
+                .clone();
+            let ids: Vec<_> = matching_instances
+                .iter()
+                .map(|instance| instance.id)
+                .collect();
+            (ids, pool)
+        };
+        let total = old_ids.len();
+
+        // Fetch and compile the new actor before touching any of the running instances
+        // NOTE: Limits are determined by the annotations the actor was started with
+        let limits = annotated_actor_limits(self.host_config.actor_limits, &annotations)
+            .context("failed to parse actor limits")?;
+        let new_actor = self.fetch_actor(&new_actor_ref).await?.with_limits(limits);
         let old_claims = actor
             .claims()
             .context("claims missing from running actor")?;
+        let new_claims = new_actor
+            .claims()
+            .context("claims missing from new actor")?;
+        ensure!(
+            new_claims.subject == old_claims.subject,
+            "new actor `{}` does not match the running actor `{}`",
+            new_claims.subject,
+            old_claims.subject
+        );
+        let new_pool = InstancePool::new(&self.runtime, new_actor.clone(), 0);
+        let new_handler = Handler {
+            claims: new_claims.clone(),
+            ..actor.handler.clone()
+        };
+
+        let publish_progress = |updated, status, error| {
+            self.publish_event(
+                "actor_update_progress",
+                event::actor_update_progress(
+                    old_claims,
+                    &annotations,
+                    host_id,
+                    &actor.image_ref,
+                    &new_actor_ref,
+                    updated,
+                    total,
+                    status,
+                    error,
+                ),
+            )
+            .unwrap_or_else(|e| warn!(?e, "failed to publish actor update progress event"))
+        };
+
+        // Bring up new instances in batches, replacing the same amount of old instances each time.
+        // The claims of the new actor are only stored once all instances were replaced, so that
+        // policy and capability checks use the claims of the running version until then
+        let mut new_ids = Vec::with_capacity(total);
+        let res = async {
+            for batch in old_ids.chunks(self.host_config.actor_update_batch_size.get()) {
+                let count = NonZeroUsize::new(batch.len()).context("empty update batch")?;
+                let new_instances = self
+                    .instantiate_actor(
+                        new_claims,
+                        &annotations,
+                        host_id,
+                        &new_actor_ref,
+                        count,
+                        new_pool.clone(),
+                        new_handler.clone(),
+                    )
+                    .await
+                    .context("failed to instantiate actor from new reference")?;
+                new_ids.extend(new_instances.iter().map(|instance| instance.id));
+                self.replace_actor_instances(
+                    old_claims,
+                    &actor,
+                    &annotations,
+                    host_id,
+                    new_instances,
+                    batch,
+                )
+                .await
+                .context("failed to stop replaced actor instances")?;
+                if new_ids.len() < total {
+                    publish_progress(new_ids.len(), "in_progress", None).await;
+                }
+            }
+            self.store_claims(Claims::Actor(new_claims.clone()))
+                .await
+                .context("failed to store claims")
+        }
+        .await;
+        if let Err(err) = res {
+            if let Err(e) = self
+                .rollback_actor_update(
+                    &actor,
+                    old_claims,
+                    new_claims,
+                    &annotations,
+                    host_id,
+                    old_pool,
+                    &new_ids,
+                )
+                .await
+            {
+                error!(?e, "failed to roll back actor update");
+            }
+            // Storing the new claims may have failed after they were partially stored
+            if let Err(e) = self.store_claims(Claims::Actor(old_claims.clone())).await {
+                error!(?e, "failed to restore claims of running actor");
+            }
+            publish_progress(new_ids.len(), "rolled_back", Some(&err)).await;
+            return Err(err);
+        }
+
+        // Commit the update, all further instances of the actor are instantiated from the new
+        // reference
+        {
+            let mut actors = self.actors.write().await;
+            let entry = actors
+                .get_mut(&actor_id)
+                .context("actor stopped during update")?;
+            *entry = Arc::new(
+                entry
+                    .updated(new_actor, new_pool, new_actor_ref.clone())
+                    .await
+                    .context("failed to update actor")?,
+            );
+        }
+        publish_progress(new_ids.len(), "completed", None).await;
+        Ok(SUCCESS.into())
+    }
 
+    /// Adds `added` instances to `actor` and removes the instances with `ids`, which are then
+    /// uninstantiated
+    async fn replace_actor_instances(
+        &self,
+        claims: &jwt::Claims<jwt::Actor>,
+        actor: &Actor,
+        annotations: &Annotations,
+        host_id: &str,
+        added: Vec<Arc<ActorInstance>>,
+        ids: &[Ulid],
+    ) -> anyhow::Result<()> {
+        let (mut replaced, remaining) = {
+            let mut instances = actor.instances.write().await;
+            let matching_instances = instances.entry(annotations.clone()).or_default();
+            let replaced = replace_instances(matching_instances, added, |instance| {
+                ids.contains(&instance.id)
+            });
+            (replaced, matching_instances.len())
+        };
+        let Some(count) = NonZeroUsize::new(replaced.len()) else {
+            return Ok(());
+        };
         self.uninstantiate_actor(
-            old_claims,
-            &annotations,
+            claims,
+            annotations,
             host_id,
-            matching_instances,
+            &mut replaced,
             count,
-            0,
+            remaining,
         )
         .await
-        .context("failed to uninstantiate running actor")?;
+    }
 
-        let mut new_instances = self
+    /// Restores instances of the running actor replaced by a failed rolling update and stops
+    /// instances of the new actor with `new_ids`
+    #[allow(clippy::too_many_arguments)]
+    #[instrument(skip(self, actor, claims, new_claims, pool))]
+    async fn rollback_actor_update(
+        &self,
+        actor: &Actor,
+        claims: &jwt::Claims<jwt::Actor>,
+        new_claims: &jwt::Claims<jwt::Actor>,
+        annotations: &Annotations,
+        host_id: &str,
+        pool: InstancePool,
+        new_ids: &[Ulid],
+    ) -> anyhow::Result<()> {
+        let Some(count) = NonZeroUsize::new(new_ids.len()) else {
+            return Ok(());
+        };
+        warn!(count, "rolling back actor update");
+        let old_instances = self
             .instantiate_actor(
-                new_claims,
+                claims,
+                annotations,
+                host_id,
+                &actor.image_ref,
+                count,
+                pool,
+                actor.handler.clone(),
+            )
+            .await
+            .context("failed to restore replaced actor instances")?;
+        self.replace_actor_instances(
+            new_claims,
+            actor,
+            annotations,
+            host_id,
+            old_instances,
+            new_ids,
+        )
+        .await
+        .context("failed to stop new actor instances")
+    }
+
+    /// Spawns the provider process at `path`, writes `host_data` to its stdin and forwards its
+    /// output to the host log
+    async fn spawn_provider_process(
+        &self,
+        path: &Path,
+        host_data: &HostData,
+        claims: &jwt::Claims<jwt::CapabilityProvider>,
+        link_name: &str,
+    ) -> anyhow::Result<process::Child> {
+        let host_data =
+            serde_json::to_vec(host_data).context("failed to serialize provider data")?;
+        debug!(
+            ?path,
+            host_data = &*String::from_utf8_lossy(&host_data),
+            "spawn provider process"
+        );
+
+        let mut child_cmd = process::Command::new(path);
+        child_cmd
+            .env_clear()
+            // TODO: remove these once all providers are updated to use the new SDK
+            .env(
+                "OTEL_TRACES_EXPORTER",
+                self.host_config
+                    .otel_config
+                    .traces_exporter
+                    .clone()
+                    .unwrap_or_default(),
+            )
+            .env(
+                "OTEL_EXPORTER_OTLP_ENDPOINT",
+                self.host_config
+                    .otel_config
+                    .exporter_otlp_endpoint
+                    .clone()
+                    .unwrap_or_default(),
+            );
+
+        if cfg!(windows) {
+            // Proxy SYSTEMROOT to providers. Without this, providers on Windows won't be able to start
+            child_cmd.env(
+                "SYSTEMROOT",
+                env::var("SYSTEMROOT")
+                    .context("SYSTEMROOT is not set. Providers cannot be started")?,
+            );
+        }
+
+        let mut child = child_cmd
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .context("failed to spawn provider process")?;
+        let output = ProviderOutput {
+            provider_id: claims.subject.clone(),
+            link_name: link_name.into(),
+            contract_id: claims.metadata.clone().map(|m| m.capid).unwrap_or_default(),
+            nats: self.host_config.publish_provider_output.then(|| {
+                (
+                    self.ctl_nats.clone(),
+                    format!(
+                        "wasmbus.log.{}.{}.{link_name}",
+                        self.host_config.lattice_prefix, claims.subject
+                    ),
+                )
+            }),
+        };
+        let stdout = child.stdout.take().context("failed to take stdout")?;
+        let stderr = child.stderr.take().context("failed to take stderr")?;
+        spawn(output.clone().forward(stdout, "stdout"));
+        spawn(output.forward(stderr, "stderr"));
+        let mut stdin = child.stdin.take().context("failed to take stdin")?;
+        stdin
+            .write_all(STANDARD.encode(host_data).as_bytes())
+            .await
+            .context("failed to write provider data")?;
+        stdin
+            .write_all(b"\r\n")
+            .await
+            .context("failed to write newline")?;
+        stdin.shutdown().await.context("failed to close stdin")?;
+        Ok(child)
+    }
+
+    /// Whether provider instance `id` is still registered on the host
+    async fn provider_instance_running(
+        &self,
+        provider_id: &str,
+        link_name: &str,
+        id: Ulid,
+    ) -> bool {
+        self.providers
+            .read()
+            .await
+            .get(provider_id)
+            .and_then(|Provider { instances, .. }| instances.get(link_name))
+            .is_some_and(|instance| instance.id == id)
+    }
+
+    /// Removes provider instance `id`, the process of which has exited for good, and publishes
+    /// a `provider_stopped` event if it was still registered on the host
+    #[instrument(skip(self))]
+    async fn remove_provider_instance(
+        &self,
+        provider_id: &str,
+        link_name: &str,
+        id: Ulid,
+        reason: &str,
+    ) -> anyhow::Result<()> {
+        let mut providers = self.providers.write().await;
+        let hash_map::Entry::Occupied(mut entry) = providers.entry(provider_id.into()) else {
+            return Ok(());
+        };
+        let provider = entry.get_mut();
+        let hash_map::Entry::Occupied(instance) = provider.instances.entry(link_name.into()) else {
+            return Ok(());
+        };
+        if instance.get().id != id {
+            return Ok(());
+        }
+        let ProviderInstance { annotations, .. } = instance.remove();
+        self.publish_event(
+            "provider_stopped",
+            event::provider_stopped(
+                &provider.claims,
                 &annotations,
-                host_id,
-                new_actor_ref,
-                count,
-                new_actor.clone(),
-                actor.handler.clone(),
-            )
-            .await
-            .context("failed to instantiate actor from new reference")?;
-        all_instances
-            .entry(annotations)
-            .or_default()
-            .append(&mut new_instances);
-
-        Ok(SUCCESS.into())
+                Uuid::from_u128(id.into()),
+                self.host_key.public_key(),
+                link_name,
+                reason,
+            ),
+        )
+        .await?;
+        if provider.instances.is_empty() {
+            entry.remove();
+        }
+        Ok(())
     }
 
     #[instrument(skip(self))]
     async fn handle_launch_provider_task(
-        &self,
+        self: Arc<Self>,
         configuration: Option<String>,
         link_name: &str,
         provider_ref: &str,
@@ -2893,6 +4943,16 @@ impl Host {
 
         let annotations: Annotations = annotations.into_iter().collect();
         let mut providers = self.providers.write().await;
+        let running = providers
+            .get(&claims.subject)
+            .is_some_and(|Provider { instances, .. }| instances.contains_key(link_name));
+        if !running {
+            ensure_capacity(
+                "providers",
+                self.host_config.capacity.max_providers,
+                count_providers(&providers) + 1,
+            )?;
+        }
         let Provider { instances, .. } =
             providers.entry(claims.subject.clone()).or_insert(Provider {
                 claims: claims.clone(),
@@ -2905,20 +4965,8 @@ impl Host {
                 .cluster_key
                 .seed()
                 .context("cluster key seed missing")?;
-            let links = self.links.read().await;
-            // TODO: update type of links to use wasmcloud_core::LinkDefinition
-            let link_definitions: Vec<_> = links
-                .clone()
-                .into_values()
-                .filter(|ld| ld.provider_id == claims.subject && ld.link_name == link_name)
-                .map(|ld| wasmcloud_core::LinkDefinition {
-                    actor_id: ld.actor_id,
-                    provider_id: ld.provider_id,
-                    link_name: ld.link_name,
-                    contract_id: ld.contract_id,
-                    values: ld.values.into_iter().collect(),
-                })
-                .collect();
+            let link_definitions =
+                provider_link_definitions(&*self.links.read().await, &claims.subject, link_name);
             let lattice_rpc_user_seed = self
                 .host_config
                 .prov_rpc_key
@@ -2952,165 +5000,34 @@ impl Host {
                 default_rpc_timeout_ms,
                 cluster_issuers: self.cluster_issuers.clone(),
                 invocation_seed,
-                log_level: Some(self.host_config.log_level.clone()),
+                log_level: Some(self.log_level.read().await.clone()),
                 structured_logging: self.host_config.enable_structured_logging,
                 otel_config,
             };
-            let host_data =
-                serde_json::to_vec(&host_data).context("failed to serialize provider data")?;
-
-            debug!(
-                ?path,
-                host_data = &*String::from_utf8_lossy(&host_data),
-                "spawn provider process"
-            );
-
-            let mut child_cmd = process::Command::new(&path);
-            child_cmd
-                .env_clear()
-                // TODO: remove these once all providers are updated to use the new SDK
-                .env(
-                    "OTEL_TRACES_EXPORTER",
-                    self.host_config
-                        .otel_config
-                        .traces_exporter
-                        .clone()
-                        .unwrap_or_default(),
-                )
-                .env(
-                    "OTEL_EXPORTER_OTLP_ENDPOINT",
-                    self.host_config
-                        .otel_config
-                        .exporter_otlp_endpoint
-                        .clone()
-                        .unwrap_or_default(),
-                );
-
-            if cfg!(windows) {
-                // Proxy SYSTEMROOT to providers. Without this, providers on Windows won't be able to start
-                child_cmd.env(
-                    "SYSTEMROOT",
-                    env::var("SYSTEMROOT")
-                        .context("SYSTEMROOT is not set. Providers cannot be started")?,
-                );
-            }
-
-            let mut child = child_cmd
-                .stdin(Stdio::piped())
-                .kill_on_drop(true)
-                .spawn()
-                .context("failed to spawn provider process")?;
-            let mut stdin = child.stdin.take().context("failed to take stdin")?;
-            stdin
-                .write_all(STANDARD.encode(&host_data).as_bytes())
-                .await
-                .context("failed to write provider data")?;
-            stdin
-                .write_all(b"\r\n")
-                .await
-                .context("failed to write newline")?;
-            stdin.shutdown().await.context("failed to close stdin")?;
-
-            // TODO: Change method receiver to Arc<Self> and `move` into the closure
-            let prov_nats = self.prov_rpc_nats.clone();
-            let ctl_nats = self.ctl_nats.clone();
-            let event_builder = self.event_builder.clone();
-            // NOTE: health_ prefix here is to allow us to move the variables into the closure
-            let health_lattice_prefix = self.host_config.lattice_prefix.clone();
-            let health_provider_id = claims.subject.to_string();
-            let health_link_name = link_name.to_string();
-            let health_contract_id = claims.metadata.clone().map(|m| m.capid).unwrap_or_default();
-            let child = spawn(async move {
-                // Check the health of the provider every 30 seconds
-                let mut health_check = tokio::time::interval(Duration::from_secs(30));
-                let mut previous_healthy = false;
-                // Allow the provider 5 seconds to initialize
-                health_check.reset_after(Duration::from_secs(5));
-                let health_topic =
-                    format!("wasmbus.rpc.{health_lattice_prefix}.{health_provider_id}.{health_link_name}.health");
-                // TODO: Refactor this logic to simplify nesting
-                loop {
-                    select! {
-                        _ = health_check.tick() => {
-                            trace!(provider_id=health_provider_id, "performing provider health check");
-                            let request = async_nats::Request::new()
-                                .payload(Bytes::new())
-                                .headers(injector_to_headers(&TraceContextInjector::default_with_span()));
-                            if let Ok(async_nats::Message { payload, ..}) = prov_nats.send_request(
-                                health_topic.clone(),
-                                request,
-                                ).await {
-                                    match (rmp_serde::from_slice::<HealthCheckResponse>(&payload), previous_healthy) {
-                                        (Ok(HealthCheckResponse { healthy: true, ..}), false) => {
-                                            trace!(provider_id=health_provider_id, "provider health check succeeded");
-                                            previous_healthy = true;
-                                            if let Err(e) = event::publish(
-                                                &event_builder,
-                                                &ctl_nats,
-                                                &health_lattice_prefix,
-                                                "health_check_passed",
-                                                event::provider_health_check(
-                                                    &health_provider_id,
-                                                    &health_link_name,
-                                                    &health_contract_id,
-                                                )
-                                            ).await {
-                                                warn!(?e, "failed to publish provider health check succeeded event");
-                                            }
-                                        },
-                                        (Ok(HealthCheckResponse { healthy: false, ..}), true) => {
-                                            trace!(provider_id=health_provider_id, "provider health check failed");
-                                            previous_healthy = false;
-                                            if let Err(e) = event::publish(
-                                                &event_builder,
-                                                &ctl_nats,
-                                                &health_lattice_prefix,
-                                                "health_check_failed",
-                                                event::provider_health_check(
-                                                    &health_provider_id,
-                                                    &health_link_name,
-                                                    &health_contract_id,
-                                                )
-                                            ).await {
-                                                warn!(?e, "failed to publish provider health check failed event");
-                                            }
-                                        }
-                                        // If the provider health status didn't change, we simply publish a health check status event
-                                        (Ok(_), _) => {
-                                            if let Err(e) = event::publish(
-                                                &event_builder,
-                                                &ctl_nats,
-                                                &health_lattice_prefix,
-                                                "health_check_status",
-                                                event::provider_health_check(
-                                                    &health_provider_id,
-                                                    &health_link_name,
-                                                    &health_contract_id,
-                                                )
-                                            ).await {
-                                                warn!(?e, "failed to publish provider health check status event");
-                                            }
-                                        },
-                                        _ => warn!("failed to deserialize provider health check response"),
-                                    }
-                                }
-                                else {
-                                    warn!("failed to request provider health, retrying in 30 seconds");
-                                }
-                        }
-                        exit_status = child.wait() => match exit_status {
-                            Ok(status) => {
-                                debug!("`{}` exited with `{status:?}`", path.display());
-                                break;
-                            }
-                            Err(e) => {
-                                warn!("failed to wait for `{}` to execute: {e}", path.display());
-                                break;
-                            }
-                        }
-                    }
-                }
-            });
+            let child = self
+                .spawn_provider_process(&path, &host_data, &claims, link_name)
+                .await?;
+            let (health, health_rx) = watch::channel(ProviderHealth::default());
+            let supervisor = ProviderSupervisor {
+                host: Arc::downgrade(&self),
+                path,
+                host_data,
+                claims: claims.clone(),
+                annotations: annotations.clone(),
+                id,
+                host_id: host_id.into(),
+                provider_ref: provider_ref.into(),
+                link_name: link_name.into(),
+                contract_id: claims.metadata.clone().map(|m| m.capid).unwrap_or_default(),
+                lattice_prefix: self.host_config.lattice_prefix.clone(),
+                supervision: self.host_config.provider_supervision.clone(),
+                health_check: self.host_config.provider_health_check.clone(),
+                health,
+                prov_nats: self.prov_rpc_nats.clone(),
+                ctl_nats: self.ctl_nats.clone(),
+                event_builder: self.event_builder.clone(),
+            };
+            let child = spawn(supervisor.run(child));
             self.publish_event(
                 "provider_started",
                 event::provider_started(
@@ -3127,6 +5044,7 @@ impl Host {
                 child,
                 id,
                 annotations,
+                health: health_rx,
             });
         } else {
             bail!("provider is already running")
@@ -3148,9 +5066,30 @@ impl Host {
             ..
         } = serde_json::from_slice(payload.as_ref())
             .context("failed to deserialize provider launch command")?;
+
+        {
+            let providers = self.providers.read().await;
+            let running = providers.values().any(
+                |Provider {
+                     image_ref,
+                     instances,
+                     ..
+                 }| {
+                    *image_ref == provider_ref && instances.contains_key(&link_name)
+                },
+            );
+            if !running {
+                ensure_capacity(
+                    "providers",
+                    self.host_config.capacity.max_providers,
+                    count_providers(&providers) + 1,
+                )?;
+            }
+        }
+
         let host_id = host_id.to_string();
         spawn(async move {
-            if let Err(err) = self
+            if let Err(err) = Arc::clone(&self)
                 .handle_launch_provider_task(
                     configuration,
                     &link_name,
@@ -3160,6 +5099,7 @@ impl Host {
                 )
                 .await
             {
+                self.publish_capacity_exceeded(&err).await;
                 if let Err(err) = self
                     .publish_event(
                         "provider_start_failed",
@@ -3351,7 +5291,7 @@ impl Host {
         let buf = serde_json::to_vec(&HostInventory {
             host_id: self.host_key.public_key(),
             issuer: self.cluster_key.public_key(),
-            labels: self.labels.clone(),
+            labels: self.labels.read().await.clone(),
             friendly_name: self.friendly_name.clone(),
             actors,
             providers,
@@ -3469,6 +5409,52 @@ impl Host {
         Ok(SUCCESS.into())
     }
 
+    #[instrument(skip(self, payload))]
+    async fn handle_label_put(
+        &self,
+        payload: impl AsRef<[u8]>,
+        host_id: &str,
+    ) -> anyhow::Result<Bytes> {
+        let HostLabel { key, value } = serde_json::from_slice(payload.as_ref())
+            .context("failed to deserialize label put command")?;
+        ensure_mutable_label(&key)?;
+
+        debug!(key, value, "put host label");
+
+        let mut labels = self.labels.write().await;
+        self.label_overrides
+            .lock()
+            .await
+            .insert(key.clone(), Some(value.clone()));
+        if labels.get(&key) != Some(&value) {
+            labels.insert(key, value);
+            let labels = labels.clone();
+            self.labels_changed(labels).await?;
+        }
+        Ok(SUCCESS.into())
+    }
+
+    #[instrument(skip(self, payload))]
+    async fn handle_label_del(
+        &self,
+        payload: impl AsRef<[u8]>,
+        host_id: &str,
+    ) -> anyhow::Result<Bytes> {
+        let HostLabelKey { key } = serde_json::from_slice(payload.as_ref())
+            .context("failed to deserialize label delete command")?;
+        ensure_mutable_label(&key)?;
+
+        debug!(key, "delete host label");
+
+        let mut labels = self.labels.write().await;
+        self.label_overrides.lock().await.insert(key.clone(), None);
+        if labels.remove(&key).is_some() {
+            let labels = labels.clone();
+            self.labels_changed(labels).await?;
+        }
+        Ok(SUCCESS.into())
+    }
+
     #[instrument(skip(self, _payload))]
     async fn handle_ping_hosts(&self, _payload: impl AsRef<[u8]>) -> anyhow::Result<Bytes> {
         let uptime = self.start_at.elapsed();
@@ -3477,7 +5463,7 @@ impl Host {
         let buf = serde_json::to_vec(&json!({
           "id": self.host_key.public_key(),
           "issuer": self.cluster_key.public_key(),
-          "labels": self.labels,
+          "labels": *self.labels.read().await,
           "friendly_name": self.friendly_name,
           "uptime_seconds": uptime.as_secs(),
           "uptime_human": human_friendly_uptime(uptime),
@@ -3513,7 +5499,7 @@ impl Host {
             .skip(1);
         let res = match (parts.next(), parts.next(), parts.next(), parts.next()) {
             (Some("auction"), Some("actor"), None, None) => {
-                self.handle_auction_actor(payload).await.map(Some)
+                self.handle_auction_actor(payload).await
             }
             (Some("auction"), Some("provider"), None, None) => {
                 self.handle_auction_provider(payload).await
@@ -3552,6 +5538,12 @@ impl Host {
             (Some("linkdefs"), Some("del"), None, None) => {
                 self.handle_linkdef_del(payload).await.map(Some)
             }
+            (Some("labels"), Some(host_id), Some("put"), None) => {
+                self.handle_label_put(payload, host_id).await.map(Some)
+            }
+            (Some("labels"), Some(host_id), Some("del"), None) => {
+                self.handle_label_del(payload, host_id).await.map(Some)
+            }
             (Some("registries"), Some("put"), None, None) => {
                 self.handle_registries_put(payload).await.map(Some)
             }
@@ -3565,6 +5557,7 @@ impl Host {
         };
         if let Err(e) = &res {
             warn!("failed to handle `{subject}` request: {e:?}");
+            self.publish_capacity_exceeded(e).await;
         }
         let headers = injector_to_headers(&TraceContextInjector::default_with_span());
         match (reply, res) {
@@ -3658,14 +5651,10 @@ impl Host {
         links.insert(id.to_string(), ld.clone());
         if let Some(actor) = self.actors.read().await.get(actor_id) {
             let mut links = actor.handler.links.write().await;
-            links.entry(contract_id.clone()).or_default().insert(
-                ld.link_name.clone(),
-                WasmCloudEntity {
-                    link_name: ld.link_name.clone(),
-                    contract_id: ld.contract_id.clone(),
-                    public_key: ld.provider_id.clone(),
-                },
-            );
+            links
+                .entry(contract_id.clone())
+                .or_default()
+                .insert(ld.link_name.clone(), LinkTarget::new(ld));
         }
 
         self.publish_event(
@@ -4057,16 +6046,71 @@ fn annotations_match_filter(annotations: &Annotations, filter: &Annotations) ->
     })
 }
 
+/// Overrides `limits` with values of limit annotations present in `annotations`, if any
+fn annotated_actor_limits(
+    mut limits: ActorLimits,
+    annotations: &Annotations,
+) -> anyhow::Result<ActorLimits> {
+    if let Some(v) = annotations.get(MAX_MEMORY_BYTES_ANNOTATION) {
+        let v = v
+            .parse()
+            .with_context(|| format!("invalid `{MAX_MEMORY_BYTES_ANNOTATION}` value `{v}`"))?;
+        limits.max_memory_bytes = Some(v);
+    }
+    if let Some(v) = annotations.get(MAX_TABLE_ELEMENTS_ANNOTATION) {
+        let v = v
+            .parse()
+            .with_context(|| format!("invalid `{MAX_TABLE_ELEMENTS_ANNOTATION}` value `{v}`"))?;
+        limits.max_table_elements = Some(v);
+    }
+    if let Some(v) = annotations.get(MAX_WASM_INSTANCES_ANNOTATION) {
+        let v = v
+            .parse()
+            .with_context(|| format!("invalid `{MAX_WASM_INSTANCES_ANNOTATION}` value `{v}`"))?;
+        limits.max_instances = Some(v);
+    }
+    Ok(limits)
+}
+
 #[cfg(test)]
 mod test {
+    use core::time::Duration;
+
+    use std::collections::HashMap;
+    use std::io::Cursor;
+    use std::net::SocketAddr;
+    use std::sync::Arc;
+
+    use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use tokio::spawn;
+    use tokio::sync::RwLock;
+    use wasmcloud_runtime::capability::http::types::RequestOptions;
+    use wasmcloud_runtime::capability::OutgoingHttp;
+
     use nkeys::KeyPair;
     use ulid::Ulid;
     use uuid::Uuid;
     use wascap::jwt;
+    use wascap::prelude::ClaimsBuilder;
+    use wascap::wasm::embed_claims;
+    use wasmcloud_core::chunking::ChunkEndpoint;
+    use wasmcloud_core::logging::Level;
     use wasmcloud_core::{invocation_hash, WasmCloudEntity};
     use wasmcloud_tracing::context::TraceContextInjector;
 
-    use super::Invocation;
+    use wasmcloud_runtime::{ActorLimits, InstancePool, Runtime};
+
+    use super::config::{self, ProviderRestartPolicy, ProviderSupervision};
+    use super::{
+        actor_auction_declined, annotated_actor_limits, constraints_satisfied,
+        encode_http_response_head, ensure_actor_capacity, ensure_capacity, ensure_mutable_label,
+        is_method_not_handled, operation_rpc_timeout, overridden_host_labels,
+        provider_auction_declined, provider_link_definitions, replace_instances, Actor,
+        Annotations, CapacityExceeded, Handler, HostCapacity, HostHttpClient, Invocation,
+        LinkDefinition, LinkTarget, LINK_RPC_TIMEOUT_MS, MAX_MEMORY_BYTES_ANNOTATION,
+        MAX_TABLE_ELEMENTS_ANNOTATION, MAX_WASM_INSTANCES_ANNOTATION, OPERATION_TIMEOUT_MARGIN,
+    };
 
     const CLUSTER_PUBKEY: &str = "CAQQHYABXBPDBZIGDZIT7E73HW66RPCFC3GGLQKSDDTVWUVOYZBYHUND";
     const CLUSTER_SEED: &str = "SCAIYCZTW775GJYX3MVWLURALVC3PULW43PTEKGH72JBMA3A7LOLGLQ2JA";
@@ -4208,6 +6252,689 @@ mod test {
                 .contains("invocation claims and invocation target URL do not match")));
     }
 
+    #[test]
+    fn annotations_override_actor_limits() {
+        let limits = ActorLimits {
+            max_memory_bytes: Some(1 << 20),
+            max_table_elements: Some(100),
+            max_instances: None,
+        };
+        let annotations = Annotations::from([
+            (MAX_MEMORY_BYTES_ANNOTATION.into(), "4096".into()),
+            (MAX_WASM_INSTANCES_ANNOTATION.into(), "2".into()),
+            ("foo".into(), "bar".into()),
+        ]);
+        assert_eq!(
+            annotated_actor_limits(limits, &annotations).expect("failed to parse limits"),
+            ActorLimits {
+                max_memory_bytes: Some(4096),
+                max_table_elements: Some(100),
+                max_instances: Some(2),
+            }
+        );
+        assert_eq!(
+            annotated_actor_limits(limits, &Annotations::default())
+                .expect("failed to parse limits"),
+            limits
+        );
+        let annotations =
+            Annotations::from([(MAX_TABLE_ELEMENTS_ANNOTATION.into(), "lots".into())]);
+        assert!(annotated_actor_limits(limits, &annotations).is_err());
+    }
+
+    #[test]
+    fn provider_restart_backoff() {
+        let supervision = ProviderSupervision {
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(10),
+            ..Default::default()
+        };
+        assert_eq!(supervision.backoff(0), Duration::from_secs(1));
+        assert_eq!(supervision.backoff(1), Duration::from_secs(2));
+        assert_eq!(supervision.backoff(3), Duration::from_secs(8));
+        assert_eq!(supervision.backoff(4), Duration::from_secs(10));
+        assert_eq!(supervision.backoff(u32::MAX), Duration::from_secs(10));
+
+        assert!(!ProviderRestartPolicy::Never.should_restart(true));
+        assert!(ProviderRestartPolicy::OnFailure.should_restart(true));
+        assert!(!ProviderRestartPolicy::OnFailure.should_restart(false));
+        assert!(ProviderRestartPolicy::Always.should_restart(false));
+        assert!("sometimes".parse::<ProviderRestartPolicy>().is_err());
+    }
+
+    #[test]
+    fn host_http_client_allowed_authorities() {
+        assert!(HostHttpClient::new([], Duration::from_secs(1))
+            .expect("failed to construct client")
+            .is_none());
+        let client = HostHttpClient::new(
+            ["example.com".into(), "api.test:8080".into()],
+            Duration::from_secs(1),
+        )
+        .expect("failed to construct client")
+        .expect("client missing");
+        let allows = |uri: &str| client.allows(&uri.parse().expect("failed to parse URI"));
+        assert!(allows("https://example.com/foo?bar"));
+        assert!(allows("http://EXAMPLE.com:8443/"));
+        assert!(allows("http://api.test:8080/"));
+        assert!(!allows("http://api.test/"));
+        assert!(!allows("http://api.test:8081/"));
+        assert!(!allows("https://example.org/"));
+        assert!(!allows("/relative"));
+    }
+
+    /// Serves `redirect_to` as a redirect on `/` and `ok` on any other path, returns the local
+    /// address of the server
+    async fn serve_redirect(
+        redirect_to: impl Fn(SocketAddr) -> String + Send + 'static,
+    ) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("failed to bind listener");
+        let addr = listener.local_addr().expect("failed to get local address");
+        spawn(async move {
+            while let Ok((mut conn, _)) = listener.accept().await {
+                let mut buf = vec![0; 4096];
+                let n = conn.read(&mut buf).await.expect("failed to read request");
+                let req = String::from_utf8_lossy(&buf[..n]);
+                let res = if req.starts_with("GET / ") {
+                    format!(
+                        "HTTP/1.1 302 Found\r\nlocation: {}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                        redirect_to(addr)
+                    )
+                } else {
+                    "HTTP/1.1 200 OK\r\ncontent-length: 2\r\nconnection: close\r\n\r\nok".into()
+                };
+                conn.write_all(res.as_bytes())
+                    .await
+                    .expect("failed to write response");
+            }
+        });
+        addr
+    }
+
+    async fn host_http_get(
+        client: &HostHttpClient,
+        addr: SocketAddr,
+        options: Option<RequestOptions>,
+    ) -> anyhow::Result<http::Response<Box<dyn AsyncRead + Sync + Send + Unpin>>> {
+        let req = http::Request::get(format!("http://{addr}/"))
+            .body(Box::new(Cursor::new(vec![])) as Box<dyn AsyncRead + Sync + Send + Unpin>)
+            .expect("failed to build request");
+        client.handle(req, options).await
+    }
+
+    #[tokio::test]
+    async fn host_http_client_timeouts() {
+        // Accepts connections, but never responds
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("failed to bind listener");
+        let addr = listener.local_addr().expect("failed to get local address");
+        spawn(async move {
+            let mut conns = vec![];
+            while let Ok((conn, _)) = listener.accept().await {
+                conns.push(conn);
+            }
+        });
+
+        let client = HostHttpClient::new([addr.to_string()], Duration::from_millis(500))
+            .expect("failed to construct client")
+            .expect("client missing");
+        let start = std::time::Instant::now();
+        assert!(host_http_get(&client, addr, None).await.is_err());
+        assert!(start.elapsed() < Duration::from_secs(5));
+
+        let client = HostHttpClient::new([addr.to_string()], Duration::from_secs(30))
+            .expect("failed to construct client")
+            .expect("client missing");
+        let start = std::time::Instant::now();
+        let options = RequestOptions {
+            connect_timeout_ms: None,
+            first_byte_timeout_ms: Some(100),
+            between_bytes_timeout_ms: None,
+        };
+        assert!(host_http_get(&client, addr, Some(options)).await.is_err());
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn host_http_client_redirects() {
+        let addr = serve_redirect(|addr| format!("http://{addr}/target")).await;
+        let client = HostHttpClient::new([addr.to_string()], Duration::from_secs(5))
+            .expect("failed to construct client")
+            .expect("client missing");
+        let res = host_http_get(&client, addr, None)
+            .await
+            .expect("redirect to allowed authority should be followed");
+        assert_eq!(res.status(), http::StatusCode::OK);
+
+        let addr = serve_redirect(|addr| format!("http://localhost:{}/target", addr.port())).await;
+        let client = HostHttpClient::new([addr.to_string()], Duration::from_secs(5))
+            .expect("failed to construct client")
+            .expect("client missing");
+        assert!(
+            host_http_get(&client, addr, None).await.is_err(),
+            "redirect to an authority outside of the allow-list should be refused"
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn process_rss() {
+        let rss = super::process_rss().expect("failed to determine process RSS");
+        assert!(rss > 0);
+    }
+
+    #[test]
+    fn auction_constraints() {
+        let labels = HashMap::from([
+            ("zone".into(), "eu-west".into()),
+            ("gpu".into(), "true".into()),
+        ]);
+        assert!(constraints_satisfied(&labels, &HashMap::default()));
+        assert!(constraints_satisfied(
+            &labels,
+            &HashMap::from([("zone".into(), "eu-west".into())])
+        ));
+        assert!(!constraints_satisfied(
+            &labels,
+            &HashMap::from([("zone".into(), "us-east".into())])
+        ));
+        assert!(!constraints_satisfied(
+            &labels,
+            &HashMap::from([("arch".into(), "aarch64".into())])
+        ));
+    }
+
+    #[test]
+    fn mutable_labels() {
+        ensure_mutable_label("zone").expect("`zone` label should be mutable");
+        ensure_mutable_label("").expect_err("empty label key should be rejected");
+        ensure_mutable_label("hostcore.os").expect_err("`hostcore.os` should be immutable");
+        ensure_mutable_label("HostCore.arch").expect_err("`HostCore.arch` should be immutable");
+    }
+
+    #[test]
+    fn config_file() {
+        let file: config::File = toml::from_str(
+            r#"
+            log_level = "debug"
+            rpc_timeout = "5s"
+
+            [labels]
+            zone = "eu-west"
+
+            [policy_service_config]
+            policy_topic = "wasmcloud.policy"
+            policy_timeout_ms = "500ms"
+            "#,
+        )
+        .expect("failed to parse config file");
+        let mut host = config::Host::default();
+        file.apply(&mut host);
+        assert_eq!(host.log_level, Level::Debug);
+        assert_eq!(host.rpc_timeout, Duration::from_secs(5));
+        assert_eq!(
+            host.labels,
+            HashMap::from([("zone".into(), "eu-west".into())])
+        );
+        assert_eq!(
+            host.policy_service_config,
+            config::PolicyService {
+                policy_topic: Some("wasmcloud.policy".into()),
+                policy_changes_topic: None,
+                policy_timeout_ms: Some(Duration::from_millis(500)),
+            }
+        );
+        assert_eq!(file.restart_required(&host), Vec::<&str>::new());
+        assert_eq!(
+            file.restart_required(&config::Host::default()),
+            ["rpc_timeout"]
+        );
+        assert!(toml::from_str::<config::File>("unknown = true").is_err());
+    }
+
+    #[test]
+    fn config_file_limits() {
+        let file: config::File = toml::from_str(
+            r#"
+            actor_update_batch_size = 4
+
+            [actor_limits]
+            max_memory_bytes = 1048576
+
+            [provider_supervision]
+            restart_policy = "on-failure"
+            initial_backoff = "500ms"
+
+            [provider_health_check]
+            action = "event"
+            timeout = "1s"
+            "#,
+        )
+        .expect("failed to parse config file");
+        let mut host = config::Host::default();
+        file.apply(&mut host);
+        assert_eq!(
+            host.actor_limits,
+            ActorLimits {
+                max_memory_bytes: Some(1 << 20),
+                ..Default::default()
+            }
+        );
+        assert_eq!(host.actor_update_batch_size.get(), 4);
+        assert_eq!(
+            host.provider_supervision,
+            ProviderSupervision {
+                restart_policy: ProviderRestartPolicy::OnFailure,
+                initial_backoff: Duration::from_millis(500),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            host.provider_health_check,
+            config::ProviderHealthCheck {
+                action: config::ProviderHealthAction::Event,
+                timeout: Duration::from_secs(1),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            file.restart_required(&config::Host::default()),
+            [
+                "actor_limits",
+                "actor_update_batch_size",
+                "provider_supervision",
+                "provider_health_check"
+            ]
+        );
+        assert!(toml::from_str::<config::File>(
+            r#"
+            [actor_limits]
+            max_fuel = 1
+            "#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn label_overrides() {
+        let configured = HashMap::from([
+            ("zone".into(), "eu-west".into()),
+            ("tier".into(), "gold".into()),
+        ]);
+        let overrides = HashMap::from([
+            ("tier".into(), None),
+            ("zone".into(), Some("drain".into())),
+            ("rack".into(), Some("a1".into())),
+        ]);
+        let labels = overridden_host_labels(&configured, &overrides);
+        assert_eq!(labels.get("zone").map(String::as_str), Some("drain"));
+        assert_eq!(labels.get("rack").map(String::as_str), Some("a1"));
+        assert!(!labels.contains_key("tier"));
+        assert!(labels.contains_key("hostcore.os"));
+
+        // labels removed from the configuration are removed, unless overridden
+        let labels = overridden_host_labels(&HashMap::default(), &overrides);
+        assert_eq!(labels.get("zone").map(String::as_str), Some("drain"));
+        let labels = overridden_host_labels(&HashMap::default(), &HashMap::default());
+        assert!(!labels.contains_key("zone"));
+        assert!(labels.contains_key("hostcore.os"));
+    }
+
+    #[test]
+    fn auction_declined() {
+        let limits = config::Capacity {
+            max_actors: Some(2),
+            max_actor_instances: Some(4),
+            max_providers: Some(1),
+            min_memory_headroom: Some(1024),
+        };
+        let capacity = HostCapacity {
+            actors: 1,
+            max_actors: limits.max_actors,
+            actor_instances: 3,
+            max_actor_instances: limits.max_actor_instances,
+            providers: 0,
+            max_providers: limits.max_providers,
+            memory_available: Some(2048),
+            image_cached: false,
+        };
+        assert_eq!(actor_auction_declined(&limits, &capacity, false), None);
+        assert_eq!(provider_auction_declined(&limits, &capacity), None);
+        assert_eq!(
+            actor_auction_declined(&config::Capacity::default(), &capacity, false),
+            None
+        );
+
+        let full = HostCapacity {
+            actors: 2,
+            ..capacity
+        };
+        assert_eq!(
+            actor_auction_declined(&limits, &full, false),
+            Some("maximum actors reached")
+        );
+        // a running actor can still be scaled up
+        assert_eq!(actor_auction_declined(&limits, &full, true), None);
+
+        let full = HostCapacity {
+            actor_instances: 4,
+            ..full
+        };
+        assert_eq!(
+            actor_auction_declined(&limits, &full, true),
+            Some("maximum actor instances reached")
+        );
+
+        let full = HostCapacity {
+            providers: 1,
+            ..full
+        };
+        assert_eq!(
+            provider_auction_declined(&limits, &full),
+            Some("maximum providers reached")
+        );
+
+        let low_memory = HostCapacity {
+            actors: 0,
+            actor_instances: 0,
+            providers: 0,
+            memory_available: Some(1023),
+            ..full
+        };
+        assert_eq!(
+            actor_auction_declined(&limits, &low_memory, false),
+            Some("minimum memory headroom not available")
+        );
+        assert_eq!(
+            provider_auction_declined(&limits, &low_memory),
+            Some("minimum memory headroom not available")
+        );
+        // the headroom is assumed to be available if the available memory is unknown
+        let unknown_memory = HostCapacity {
+            memory_available: None,
+            ..low_memory
+        };
+        assert_eq!(
+            actor_auction_declined(&limits, &unknown_memory, false),
+            None
+        );
+        assert_eq!(provider_auction_declined(&limits, &unknown_memory), None);
+    }
+
+    #[test]
+    fn capacity() {
+        assert_eq!(ensure_capacity("providers", None, 100), Ok(()));
+        assert_eq!(ensure_capacity("providers", Some(1), 1), Ok(()));
+        assert_eq!(
+            ensure_capacity("providers", Some(1), 2),
+            Err(CapacityExceeded {
+                limit: "providers",
+                max: 1,
+                requested: 2,
+            })
+        );
+
+        let capacity = config::Capacity {
+            max_actors: Some(1),
+            max_actor_instances: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(ensure_actor_capacity(&capacity, 1, 2), Ok(()));
+        assert_eq!(
+            ensure_actor_capacity(&capacity, 2, 2),
+            Err(CapacityExceeded {
+                limit: "actors",
+                max: 1,
+                requested: 2,
+            })
+        );
+        let err = ensure_actor_capacity(&capacity, 1, 3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "host capacity exceeded: 3 actor_instances requested, at most 2 allowed"
+        );
+        assert_eq!(
+            ensure_actor_capacity(&config::Capacity::default(), 100, 100),
+            Ok(())
+        );
+
+        // the limit is recovered from the failed command to publish `host_capacity_exceeded`
+        let err = anyhow::Error::from(err).context("failed to scale actor");
+        assert_eq!(
+            err.downcast_ref(),
+            Some(&CapacityExceeded {
+                limit: "actor_instances",
+                max: 2,
+                requested: 3,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn http_response_head() -> anyhow::Result<()> {
+        let body = vec![0x42; 1 << 16];
+        let res = || {
+            http::Response::builder()
+                .status(404)
+                .header(http::header::CONTENT_LENGTH, body.len())
+        };
+        let buffered =
+            wasmcloud_compat::HttpResponse::from_http(res().body(Cursor::new(&body))?).await?;
+        let buffered = rmp_serde::to_vec_named(&buffered)?;
+
+        let mut streamed = encode_http_response_head(res().body(())?, body.len().try_into()?)?;
+        streamed.extend_from_slice(&body);
+        assert_eq!(streamed, buffered);
+        Ok(())
+    }
+
+    #[test]
+    fn messaging_request_timeout() {
+        let rpc_timeout = Duration::from_secs(2);
+        assert_eq!(operation_rpc_timeout(rpc_timeout, None), rpc_timeout);
+        // Requests shorter than the RPC timeout are covered by it
+        assert_eq!(
+            operation_rpc_timeout(rpc_timeout, Some(Duration::from_millis(500))),
+            rpc_timeout
+        );
+        // Requests longer than the RPC timeout must not be cut short, otherwise all replies
+        // collected by the provider are lost
+        let request_timeout = Duration::from_secs(10);
+        let timeout = operation_rpc_timeout(rpc_timeout, Some(request_timeout));
+        assert!(timeout > request_timeout);
+        assert_eq!(timeout, request_timeout + OPERATION_TIMEOUT_MARGIN);
+        assert_eq!(
+            operation_rpc_timeout(rpc_timeout, Some(Duration::MAX)),
+            Duration::MAX
+        );
+    }
+
+    #[test]
+    fn link_rpc_timeout() {
+        let link = |timeout: Option<&str>| {
+            let mut ld = LinkDefinition::default();
+            ld.actor_id = ACTOR_PUBKEY.into();
+            ld.provider_id = PROVIDER_PUBKEY.into();
+            ld.link_name = "default".into();
+            ld.contract_id = "wasmcloud:keyvalue".into();
+            ld.values = timeout
+                .map(|timeout| (LINK_RPC_TIMEOUT_MS.into(), timeout.into()))
+                .into_iter()
+                .collect();
+            LinkTarget::new(&ld)
+        };
+        let target = link(None);
+        assert_eq!(target.entity.public_key, PROVIDER_PUBKEY);
+        assert_eq!(target.entity.contract_id, "wasmcloud:keyvalue");
+        assert_eq!(target.rpc_timeout, None);
+        assert_eq!(
+            link(Some("500")).rpc_timeout,
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(link(Some("soon")).rpc_timeout, None);
+
+        let mut invocation = Invocation::default();
+        assert_eq!(invocation.time_remaining(), None);
+        invocation.set_timeout(Duration::from_secs(60));
+        let remaining = invocation.time_remaining().expect("deadline should be set");
+        assert!(remaining > Duration::from_secs(50) && remaining <= Duration::from_secs(60));
+        invocation.deadline = Some(0);
+        assert_eq!(invocation.time_remaining(), Some(Duration::ZERO));
+    }
+
+    /// Compiles an empty actor module named `name` with claims of `subject` signed by `issuer`
+    fn signed_actor(
+        rt: &Runtime,
+        issuer: &KeyPair,
+        subject: &KeyPair,
+        name: &str,
+    ) -> wasmcloud_runtime::Actor {
+        let claims = ClaimsBuilder::new()
+            .issuer(&issuer.public_key())
+            .subject(&subject.public_key())
+            .with_metadata(jwt::Actor {
+                name: Some(name.into()),
+                ..Default::default()
+            })
+            .build();
+        let wasm = embed_claims(b"\0asm\x01\0\0\0", &claims, issuer)
+            .expect("failed to embed actor claims");
+        wasmcloud_runtime::Actor::new(rt, wasm).expect("failed to compile actor")
+    }
+
+    fn actor_name(claims: Option<&jwt::Claims<jwt::Actor>>) -> Option<&str> {
+        claims?.metadata.as_ref()?.name.as_deref()
+    }
+
+    #[test]
+    fn provider_links() {
+        let link = |actor_id: &str, link_name: &str| {
+            let mut ld = LinkDefinition::default();
+            ld.actor_id = actor_id.into();
+            ld.provider_id = PROVIDER_PUBKEY.into();
+            ld.link_name = link_name.into();
+            ld.contract_id = "wasmcloud:keyvalue".into();
+            ld.values = HashMap::from([("URL".into(), "redis://127.0.0.1/".into())]);
+            ld
+        };
+        let mut links = HashMap::from([
+            ("a".into(), link(ACTOR_PUBKEY, "default")),
+            ("b".into(), link(ACTOR_PUBKEY, "other")),
+        ]);
+        let lds = provider_link_definitions(&links, PROVIDER_PUBKEY, "default");
+        assert_eq!(lds.len(), 1);
+        assert_eq!(lds[0].actor_id, ACTOR_PUBKEY);
+        assert_eq!(lds[0].link_name, "default");
+        assert_eq!(
+            lds[0].values,
+            [("URL".to_string(), "redis://127.0.0.1/".to_string())]
+        );
+
+        // Links put and deleted after the provider was started are reflected on restart
+        links.remove("a");
+        links.insert("c".into(), link(OUTSIDE_CLUSTER_PUBKEY, "default"));
+        let lds = provider_link_definitions(&links, PROVIDER_PUBKEY, "default");
+        assert_eq!(lds.len(), 1);
+        assert_eq!(lds[0].actor_id, OUTSIDE_CLUSTER_PUBKEY);
+        assert!(provider_link_definitions(&links, ACTOR_PUBKEY, "default").is_empty());
+    }
+
+    #[test]
+    fn method_not_handled() {
+        assert!(is_method_not_handled(
+            "method not handled Blobstore::CopyObject"
+        ));
+        assert!(!is_method_not_handled("Could not copy object: not found"));
+        assert!(!is_method_not_handled(""));
+    }
+
+    #[tokio::test]
+    async fn actor_update() {
+        let rt = Runtime::new().expect("failed to construct runtime");
+        let issuer = KeyPair::new_account();
+        let subject = KeyPair::new_module();
+        let old = signed_actor(&rt, &issuer, &subject, "old");
+        let new = signed_actor(&rt, &issuer, &subject, "new");
+
+        // NOTE: The client is never used, so it does not need to connect
+        let nats = async_nats::ConnectOptions::new()
+            .retry_on_initial_connect()
+            .connect("127.0.0.1:4222")
+            .await
+            .expect("failed to construct NATS client");
+        let handler = Handler {
+            nats: nats.clone(),
+            lattice_prefix: "default".into(),
+            cluster_key: Arc::new(KeyPair::new_cluster()),
+            host_key: Arc::new(KeyPair::new_server()),
+            claims: old.claims().expect("claims missing").clone(),
+            origin: actor_entity(&subject.public_key()),
+            rpc_timeout: Duration::from_secs(2),
+            links: Arc::default(),
+            targets: Arc::default(),
+            aliases: Arc::default(),
+            chunk_endpoint: ChunkEndpoint::with_client("default", nats, None::<&str>),
+            http_client: None,
+        };
+        let actor = Actor {
+            actor: old.clone(),
+            pool: InstancePool::new(&rt, old, 0),
+            instances: RwLock::default(),
+            image_ref: "old".into(),
+            handler,
+            update: Arc::default(),
+        };
+        actor
+            .ensure_not_updating()
+            .expect("actor should not be updating");
+
+        let update = Arc::clone(&actor.update)
+            .try_lock_owned()
+            .expect("failed to acquire update guard");
+        assert!(actor.ensure_not_updating().is_err());
+
+        let updated = actor
+            .updated(new.clone(), InstancePool::new(&rt, new, 0), "new".into())
+            .await
+            .expect("failed to update actor");
+        assert_eq!(updated.image_ref, "new");
+        assert_eq!(actor_name(updated.claims()), Some("new"));
+        assert_eq!(actor_name(Some(&updated.handler.claims)), Some("new"));
+        assert_eq!(actor_name(Some(&actor.handler.claims)), Some("old"));
+        assert!(Arc::ptr_eq(&updated.handler.links, &actor.handler.links));
+
+        // The update guard is shared by the updated actor
+        assert!(updated.ensure_not_updating().is_err());
+        drop(update);
+        updated
+            .ensure_not_updating()
+            .expect("actor should not be updating");
+    }
+
+    #[test]
+    fn actor_update_rollback() {
+        let old: Vec<_> = (0..5).map(|_| ("old", Ulid::new())).collect();
+        let mut instances = old.clone();
+
+        // The first batch of instances is replaced by new instances
+        let batch: Vec<_> = old[..2].iter().map(|(_, id)| *id).collect();
+        let new: Vec<_> = (0..2).map(|_| ("new", Ulid::new())).collect();
+        let replaced = replace_instances(&mut instances, new.clone(), |(_, id)| batch.contains(id));
+        assert_eq!(replaced, old[..2]);
+        assert_eq!(instances.len(), old.len());
+
+        // Instantiating the second batch fails, so new instances are replaced by old ones again
+        let new_ids: Vec<_> = new.iter().map(|(_, id)| *id).collect();
+        let restored: Vec<_> = (0..2).map(|_| ("old", Ulid::new())).collect();
+        let stopped = replace_instances(&mut instances, restored, |(_, id)| new_ids.contains(id));
+        assert_eq!(stopped, new);
+        assert_eq!(instances.len(), old.len());
+        assert!(instances.iter().all(|(version, _)| *version == "old"));
+    }
+
     /// Helper test function for oneline creation of an actor [`WasmCloudEntity`]. Consider adding to the
     /// actual impl block if it's useful elsewhere.
     fn actor_entity(public_key: &str) -> WasmCloudEntity {
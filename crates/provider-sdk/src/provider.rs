@@ -305,6 +305,19 @@ impl ProviderConnection {
         provider: P,
         inv: Invocation,
     ) -> Result<Vec<u8>, ProviderInvocationError>
+    where
+        P: Provider + Clone,
+    {
+        // Abandon the invocation once the caller has stopped waiting for a response
+        let remaining = inv.time_remaining();
+        abandon_after(remaining, self.handle_rpc_inner(provider, inv)).await
+    }
+
+    async fn handle_rpc_inner<P>(
+        &self,
+        provider: P,
+        inv: Invocation,
+    ) -> Result<Vec<u8>, ProviderInvocationError>
     where
         P: Provider + Clone,
     {
@@ -541,3 +554,76 @@ impl ProviderConnection {
         Ok(())
     }
 }
+
+/// Runs `handler` to completion, unless `remaining` is set and elapses first, in which case
+/// [`InvocationError::Timeout`] is returned
+async fn abandon_after<T>(
+    remaining: Option<Duration>,
+    handler: impl std::future::Future<Output = Result<T, ProviderInvocationError>>,
+) -> Result<T, ProviderInvocationError> {
+    let Some(remaining) = remaining else {
+        return handler.await;
+    };
+    tokio::time::timeout(remaining, handler)
+        .await
+        .map_err(|_| InvocationError::Timeout)?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::future::{pending, ready};
+
+    fn invocation() -> Invocation {
+        Invocation {
+            operation: "KeyValue.Get".into(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn abandon_at_deadline() {
+        let mut inv = invocation();
+        inv.set_timeout(Duration::from_millis(50));
+        let res = tokio::time::timeout(
+            Duration::from_secs(5),
+            abandon_after(inv.time_remaining(), pending::<Result<(), _>>()),
+        )
+        .await
+        .expect("invocation should be abandoned at its deadline");
+        assert!(matches!(
+            res,
+            Err(ProviderInvocationError::Invocation(
+                InvocationError::Timeout
+            ))
+        ));
+
+        // deadline already passed
+        inv.deadline = Some(0);
+        let res = abandon_after(inv.time_remaining(), pending::<Result<(), _>>()).await;
+        assert!(matches!(
+            res,
+            Err(ProviderInvocationError::Invocation(
+                InvocationError::Timeout
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn complete_before_deadline() {
+        let mut inv = invocation();
+        let res = abandon_after(inv.time_remaining(), ready(Ok(vec![42]))).await;
+        assert_eq!(
+            res.expect("invocation without deadline should complete"),
+            [42]
+        );
+
+        inv.set_timeout(Duration::from_secs(60));
+        let res = abandon_after(inv.time_remaining(), ready(Ok(vec![42]))).await;
+        assert_eq!(
+            res.expect("invocation should complete before deadline"),
+            [42]
+        );
+    }
+}
@@ -165,6 +165,12 @@ impl RpcClient {
         let len = data.len();
         let needs_chunking = len > CHUNK_THRESHOLD_BYTES;
 
+        let timeout = if needs_chunking {
+            timeout.map(|t| t + CHUNK_RPC_EXTRA_TIME)
+        } else {
+            timeout
+        };
+
         let (invocation, body) = {
             let mut inv = Invocation {
                 origin,
@@ -178,6 +184,10 @@ impl RpcClient {
                 trace_context: TraceContextInjector::default_with_span().into(),
                 ..Default::default()
             };
+            // Let the recipient know when we stop waiting for a response
+            if let Some(timeout) = timeout {
+                inv.set_timeout(timeout);
+            }
             if needs_chunking {
                 (inv, Some(data))
             } else {
@@ -203,12 +213,6 @@ impl RpcClient {
             // error
         }
 
-        let timeout = if needs_chunking {
-            timeout.map(|t| t + CHUNK_RPC_EXTRA_TIME)
-        } else {
-            timeout
-        };
-
         // let this = self.clone();
         // let topic_ = topic.clone();
         let payload = if let Some(timeout) = timeout {
@@ -5,7 +5,7 @@
 use std::time::SystemTime;
 use std::{
     collections::HashMap,
-    io::{Error as IoError, ErrorKind as IoErrorKind},
+    io::{Error as IoError, ErrorKind as IoErrorKind, SeekFrom},
     path::{Path, PathBuf},
     sync::Arc,
 };
@@ -13,13 +13,15 @@ use std::{
 use path_clean::PathClean;
 use serde::Deserialize;
 use tokio::fs::{
-    create_dir_all, metadata, read, read_dir, remove_dir_all, remove_file, File, OpenOptions,
+    copy, create_dir_all, metadata, read_dir, remove_dir_all, remove_file, rename, File,
+    OpenOptions,
 };
-use tokio::io::AsyncWriteExt;
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
 use tokio::sync::RwLock;
 use tracing::{error, info};
 use wasmbus_rpc::provider::prelude::*;
 use wasmbus_rpc::Timestamp;
+use wasmcloud_compat::blobstore::{CopyObjectRequest, MoveObjectRequest};
 use wasmcloud_interface_blobstore::*;
 
 use wasmcloud_provider_blobstore_fs::fs_utils::all_dirs;
@@ -49,8 +51,7 @@ struct FsProviderConfig {
 
 /// fs capability provider implementation
 #[allow(dead_code)]
-#[derive(Clone, Provider)]
-#[services(Blobstore)]
+#[derive(Clone)]
 struct FsProvider {
     config: Arc<RwLock<HashMap<String, FsProviderConfig>>>,
     upload_chunks: Arc<RwLock<HashMap<String, u64>>>, // keep track of the next offset for chunks to be uploaded
@@ -263,6 +264,87 @@ impl FsProvider {
     }
 }
 
+impl FsProvider {
+    /// Resolve the path of an object within the root of the actor
+    async fn resolve_object_path(
+        &self,
+        ctx: &Context,
+        container_id: &str,
+        object_id: &str,
+    ) -> RpcResult<PathBuf> {
+        let root = self.get_root(ctx).await?;
+        let object_subpath = Path::new(container_id).join(object_id);
+        Ok(self.resolve_subpath(&root, object_subpath).await?)
+    }
+
+    /// Copies an object within the root of the actor
+    async fn copy_object(&self, ctx: &Context, req: &CopyObjectRequest) -> RpcResult<()> {
+        info!("Called copy_object({:?})", req);
+
+        let src = self
+            .resolve_object_path(ctx, &req.src_container, &req.src_object)
+            .await?;
+        let dest = self
+            .resolve_object_path(ctx, &req.dest_container, &req.dest_object)
+            .await?;
+        copy(&src, &dest).await.map_err(|e| {
+            RpcError::InvalidParameter(format!("Could not copy {:?} to {:?}: {:?}", src, dest, e))
+        })?;
+        Ok(())
+    }
+
+    /// Moves an object within the root of the actor
+    async fn move_object(&self, ctx: &Context, req: &MoveObjectRequest) -> RpcResult<()> {
+        info!("Called move_object({:?})", req);
+
+        let src = self
+            .resolve_object_path(ctx, &req.src_container, &req.src_object)
+            .await?;
+        let dest = self
+            .resolve_object_path(ctx, &req.dest_container, &req.dest_object)
+            .await?;
+        rename(&src, &dest).await.map_err(|e| {
+            RpcError::InvalidParameter(format!("Could not move {:?} to {:?}: {:?}", src, dest, e))
+        })
+    }
+}
+
+/// Handles the `Blobstore.CopyObject` and `Blobstore.MoveObject` operations, which are not part
+/// of the `wasmcloud:blobstore` interface crate, and dispatches everything else to [`Blobstore`]
+#[async_trait]
+impl MessageDispatch for FsProvider {
+    async fn dispatch(&self, ctx: &Context, message: Message<'_>) -> RpcResult<Vec<u8>> {
+        match message.method {
+            "Blobstore.CopyObject" => {
+                let req: CopyObjectRequest = wasmbus_rpc::common::deserialize(&message.arg)?;
+                self.copy_object(ctx, &req).await?;
+                Ok(Vec::new())
+            }
+            "Blobstore.MoveObject" => {
+                let req: MoveObjectRequest = wasmbus_rpc::common::deserialize(&message.arg)?;
+                self.move_object(ctx, &req).await?;
+                Ok(Vec::new())
+            }
+            method => match method.split_once('.') {
+                Some(("Blobstore", method)) => {
+                    BlobstoreReceiver::dispatch(
+                        self,
+                        ctx,
+                        Message {
+                            method,
+                            arg: message.arg,
+                        },
+                    )
+                    .await
+                }
+                _ => Err(RpcError::MethodNotHandled(format!(
+                    "{method} - unknown method"
+                ))),
+            },
+        }
+    }
+}
+
 /// use default implementations of provider message handlers
 impl ProviderDispatch for FsProvider {}
 
@@ -643,36 +725,35 @@ impl Blobstore for FsProvider {
         let object_subpath = Path::new(&req.container_id).join(&req.object_id);
         let file_path = self.resolve_subpath(root, &object_subpath).await?;
 
-        // Read the file in
-        let file = read(file_path).await?;
-
-        let start_offset = match req.range_start {
-            Some(o) => o as usize,
-            None => 0,
-        };
+        // Read only the requested range of the file, so that large objects can be streamed
+        // by requesting consecutive ranges
+        let mut file = File::open(&file_path).await?;
+        let file_len = file.metadata().await?.len();
 
+        let start_offset = req.range_start.unwrap_or_default().min(file_len);
         let end_offset = match req.range_end {
-            Some(o) => std::cmp::min(o as usize + 1, file.len()),
-            None => file.len(),
-        };
-
-        let mut dcm = self.download_chunks.write().await;
-
-        let actor_id = self.get_actor_id(ctx).await?;
-
-        let slice = &file[start_offset..end_offset];
+            Some(o) => o.saturating_add(1).min(file_len),
+            None => file_len,
+        }
+        .max(start_offset);
 
         info!(
             "Retriving chunk start offset: {}, end offset: {} (exclusive)",
             start_offset, end_offset
         );
 
+        file.seek(SeekFrom::Start(start_offset)).await?;
+        let mut bytes = Vec::with_capacity((end_offset - start_offset) as usize);
+        file.take(end_offset - start_offset)
+            .read_to_end(&mut bytes)
+            .await?;
+
         let chunk = Chunk {
             object_id: req.object_id.clone(),
             container_id: req.container_id.clone(),
-            bytes: slice.to_vec(),
-            offset: start_offset as u64,
-            is_last: end_offset >= file.len(),
+            bytes,
+            offset: start_offset,
+            is_last: end_offset >= file_len,
         };
 
         Ok(GetObjectResponse {
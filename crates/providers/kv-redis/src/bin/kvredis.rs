@@ -19,8 +19,8 @@ use serde::Deserialize;
 use tokio::sync::RwLock;
 use tracing::{info, instrument, warn};
 use wasmcloud_compat::keyvalue::{
-    GetResponse, IncrementRequest, ListAddRequest, ListDelRequest, ListRangeRequest, SetAddRequest,
-    SetDelRequest, SetRequest,
+    BucketRequest, CompareAndSwapRequest, GetResponse, IncrementRequest, ListAddRequest,
+    ListDelRequest, ListRangeRequest, SetAddRequest, SetDelRequest, SetRequest,
 };
 use wasmcloud_provider_sdk::core::LinkDefinition;
 use wasmcloud_provider_sdk::error::ProviderInvocationError;
@@ -29,6 +29,25 @@ use wasmcloud_provider_sdk::{load_host_data, Context, ProviderHandler};
 
 const REDIS_URL_KEY: &str = "URL";
 const DEFAULT_CONNECT_URL: &str = "redis://127.0.0.1:6379/";
+/// Lua script atomically setting `KEYS[1]` to `ARGV[2]` if its current value is `ARGV[1]`.
+/// Like for increments, a missing key is treated as having the value 0.
+/// Returns 1 if the value was swapped and 0 otherwise.
+const COMPARE_AND_SWAP_SCRIPT: &str = r#"
+local current = redis.call('GET', KEYS[1])
+if current == false then
+    current = '0'
+end
+if current == ARGV[1] then
+    redis.call('SET', KEYS[1], ARGV[2])
+    return 1
+end
+return 0
+"#;
+/// Separator between the bucket name and the key in Redis keys of non-default buckets
+const BUCKET_SEPARATOR: char = ':';
+/// Prefix of Redis keys of non-default buckets, which keys in the default bucket must not start
+/// with
+const BUCKET_PREFIX: &str = "wasmcloud:bucket:";
 
 #[derive(Deserialize)]
 struct KvRedisConfig {
@@ -151,24 +170,53 @@ impl ProviderHandler for KvRedisProvider {
 impl KvRedisProvider {
     /// Increments a numeric value, returning the new value
     #[instrument(level = "debug", skip(self, ctx, arg), fields(actor_id = ?ctx.actor, key = %arg.key))]
-    async fn increment(&self, ctx: Context, arg: IncrementRequest) -> Result<i32, String> {
-        let mut cmd = redis::Cmd::incr(&arg.key, arg.value);
+    async fn increment(
+        &self,
+        ctx: Context,
+        bucket: Option<&str>,
+        arg: IncrementRequest,
+    ) -> Result<i32, String> {
+        let mut cmd = redis::Cmd::incr(bucket_key(bucket, &arg.key)?, arg.value);
         let val: i32 = self.exec(&ctx, &mut cmd).await?;
         Ok(val)
     }
 
+    /// Sets a numeric value to `new` if its current value is `old`,
+    /// returning true if the value was swapped
+    #[instrument(level = "debug", skip(self, ctx, arg), fields(actor_id = ?ctx.actor, key = %arg.key))]
+    async fn compare_and_swap(
+        &self,
+        ctx: Context,
+        bucket: Option<&str>,
+        arg: CompareAndSwapRequest,
+    ) -> Result<bool, String> {
+        let mut cmd = redis::cmd("EVAL");
+        cmd.arg(COMPARE_AND_SWAP_SCRIPT)
+            .arg(1)
+            .arg(bucket_key(bucket, &arg.key)?)
+            .arg(arg.old)
+            .arg(arg.new);
+        let swapped: i32 = self.exec(&ctx, &mut cmd).await?;
+        Ok(swapped == 1)
+    }
+
     /// Returns true if the store contains the key
     #[instrument(level = "debug", skip(self, ctx, arg), fields(actor_id = ?ctx.actor, key = %arg.to_string()))]
-    async fn contains(&self, ctx: Context, arg: String) -> Result<bool, String> {
-        let mut cmd = redis::Cmd::exists(arg.to_string());
+    async fn contains(
+        &self,
+        ctx: Context,
+        bucket: Option<&str>,
+        arg: String,
+    ) -> Result<bool, String> {
+        let mut cmd = redis::Cmd::exists(bucket_key(bucket, &arg)?);
         let val: bool = self.exec(&ctx, &mut cmd).await?;
         Ok(val)
     }
 
     /// Deletes a key, returning true if the key was deleted
     #[instrument(level = "debug", skip(self, ctx, arg), fields(actor_id = ?ctx.actor, key = %arg.to_string()))]
-    async fn del(&self, ctx: Context, arg: String) -> Result<bool, String> {
-        let mut cmd = redis::Cmd::del(arg.to_string());
+    async fn del(&self, ctx: Context, bucket: Option<&str>, arg: String) -> Result<bool, String> {
+        let mut cmd = redis::Cmd::del(bucket_key(bucket, &arg)?);
         let val: i32 = self.exec(&ctx, &mut cmd).await?;
         Ok(val > 0)
     }
@@ -177,13 +225,18 @@ impl KvRedisProvider {
     /// the return structure contains exists: true and the value,
     /// otherwise the return structure contains exists == false.
     #[instrument(level = "debug", skip(self, ctx, arg), fields(actor_id = ?ctx.actor, key = %arg.to_string()))]
-    async fn get(&self, ctx: Context, arg: String) -> Result<GetResponse, String> {
-        let mut cmd = redis::Cmd::get(arg.to_string());
-        let val: Option<String> = self.exec(&ctx, &mut cmd).await?;
+    async fn get(
+        &self,
+        ctx: Context,
+        bucket: Option<&str>,
+        arg: String,
+    ) -> Result<GetResponse, String> {
+        let mut cmd = redis::Cmd::get(bucket_key(bucket, &arg)?);
+        let val: Option<Vec<u8>> = self.exec(&ctx, &mut cmd).await?;
         let resp = match val {
-            Some(s) => GetResponse {
+            Some(value) => GetResponse {
                 exists: true,
-                value: s,
+                value,
             },
             None => GetResponse {
                 exists: false,
@@ -196,7 +249,7 @@ impl KvRedisProvider {
     /// Append a value onto the end of a list. Returns the new list size
     #[instrument(level = "debug", skip(self, ctx, arg), fields(actor_id = ?ctx.actor, key = %arg.list_name))]
     async fn list_add(&self, ctx: Context, arg: ListAddRequest) -> Result<u32, String> {
-        let mut cmd = redis::Cmd::rpush(&arg.list_name, &arg.value);
+        let mut cmd = redis::Cmd::rpush(default_key(&arg.list_name)?, &arg.value);
         let val: u32 = self.exec(&ctx, &mut cmd).await?;
         Ok(val)
     }
@@ -206,13 +259,13 @@ impl KvRedisProvider {
     /// returns: true if the list existed and was deleted
     #[instrument(level = "debug", skip(self, ctx, arg), fields(actor_id = ?ctx.actor, key = %arg.to_string()))]
     async fn list_clear(&self, ctx: Context, arg: String) -> Result<bool, String> {
-        self.del(ctx, arg).await
+        self.del(ctx, None, arg).await
     }
 
     /// Deletes an item from a list. Returns true if the item was removed.
     #[instrument(level = "debug", skip(self, ctx, arg), fields(actor_id = ?ctx.actor, key = %arg.list_name))]
     async fn list_del(&self, ctx: Context, arg: ListDelRequest) -> Result<bool, String> {
-        let mut cmd = redis::Cmd::lrem(&arg.list_name, 1, &arg.value);
+        let mut cmd = redis::Cmd::lrem(default_key(&arg.list_name)?, 1, &arg.value);
         let val: u32 = self.exec(&ctx, &mut cmd).await?;
         Ok(val > 0)
     }
@@ -223,7 +276,11 @@ impl KvRedisProvider {
     /// is beyond the end of the list, it is treated as the end of the list.
     #[instrument(level = "debug", skip(self, ctx, arg), fields(actor_id = ?ctx.actor, key = %arg.list_name))]
     async fn list_range(&self, ctx: Context, arg: ListRangeRequest) -> Result<Vec<String>, String> {
-        let mut cmd = redis::Cmd::lrange(&arg.list_name, arg.start as isize, arg.stop as isize);
+        let mut cmd = redis::Cmd::lrange(
+            default_key(&arg.list_name)?,
+            arg.start as isize,
+            arg.stop as isize,
+        );
         let val: Vec<String> = self.exec(&ctx, &mut cmd).await?;
         Ok(val)
     }
@@ -232,10 +289,11 @@ impl KvRedisProvider {
     /// expires is an optional number of seconds before the value should be automatically deleted,
     /// or 0 for no expiration.
     #[instrument(level = "debug", skip(self, ctx, arg), fields(actor_id = ?ctx.actor, key = %arg.key))]
-    async fn set(&self, ctx: Context, arg: SetRequest) -> Result<(), String> {
+    async fn set(&self, ctx: Context, bucket: Option<&str>, arg: SetRequest) -> Result<(), String> {
+        let key = bucket_key(bucket, &arg.key)?;
         let mut cmd = match arg.expires {
-            0 => redis::Cmd::set(&arg.key, &arg.value),
-            _ => redis::Cmd::set_ex(&arg.key, &arg.value, arg.expires as usize),
+            0 => redis::Cmd::set(&key, &arg.value),
+            _ => redis::Cmd::set_ex(&key, &arg.value, arg.expires as usize),
         };
         let _value: Option<String> = self.exec(&ctx, &mut cmd).await?;
         Ok(())
@@ -244,7 +302,7 @@ impl KvRedisProvider {
     /// Add an item into a set. Returns number of items added
     #[instrument(level = "debug", skip(self, ctx, arg), fields(actor_id = ?ctx.actor, key = %arg.set_name))]
     async fn set_add(&self, ctx: Context, arg: SetAddRequest) -> Result<u32, String> {
-        let mut cmd = redis::Cmd::sadd(&arg.set_name, &arg.value);
+        let mut cmd = redis::Cmd::sadd(default_key(&arg.set_name)?, &arg.value);
         let value: u32 = self.exec(&ctx, &mut cmd).await?;
         Ok(value)
     }
@@ -252,7 +310,7 @@ impl KvRedisProvider {
     /// Remove a item from the set. Returns
     #[instrument(level = "debug", skip(self, ctx, arg), fields(actor_id = ?ctx.actor, key = %arg.set_name))]
     async fn set_del(&self, ctx: Context, arg: SetDelRequest) -> Result<u32, String> {
-        let mut cmd = redis::Cmd::srem(&arg.set_name, &arg.value);
+        let mut cmd = redis::Cmd::srem(default_key(&arg.set_name)?, &arg.value);
         let value: u32 = self.exec(&ctx, &mut cmd).await?;
         Ok(value)
     }
@@ -262,7 +320,7 @@ impl KvRedisProvider {
     /// returns: true if the set existed and was deleted
     #[instrument(level = "debug", skip(self, ctx, arg), fields(actor_id = ?ctx.actor, key = %arg.to_string()))]
     async fn set_clear(&self, ctx: Context, arg: String) -> Result<bool, String> {
-        self.del(ctx, arg).await
+        self.del(ctx, None, arg).await
     }
 
     #[instrument(level = "debug", skip(self, ctx, arg), fields(actor_id = ?ctx.actor, keys = ?arg))]
@@ -271,6 +329,9 @@ impl KvRedisProvider {
         ctx: Context,
         arg: Vec<String>,
     ) -> Result<Vec<String>, String> {
+        for key in &arg {
+            default_key(key)?;
+        }
         let mut cmd = redis::Cmd::sinter(arg);
         let value: Vec<String> = self.exec(&ctx, &mut cmd).await?;
         Ok(value)
@@ -278,13 +339,16 @@ impl KvRedisProvider {
 
     #[instrument(level = "debug", skip(self, ctx, arg), fields(actor_id = ?ctx.actor, key = %arg.to_string()))]
     async fn set_query(&self, ctx: Context, arg: String) -> Result<Vec<String>, String> {
-        let mut cmd = redis::Cmd::smembers(arg.to_string());
+        let mut cmd = redis::Cmd::smembers(default_key(&arg)?);
         let values: Vec<String> = self.exec(&ctx, &mut cmd).await?;
         Ok(values)
     }
 
     #[instrument(level = "debug", skip(self, ctx, arg), fields(actor_id = ?ctx.actor, keys = ?arg))]
     async fn set_union(&self, ctx: Context, arg: Vec<String>) -> Result<Vec<String>, String> {
+        for key in &arg {
+            default_key(key)?;
+        }
         let mut cmd = redis::Cmd::sunion(arg);
         let values: Vec<String> = self.exec(&ctx, &mut cmd).await?;
         Ok(values)
@@ -330,6 +394,31 @@ impl KvRedisProvider {
     }
 }
 
+/// Returns the Redis key of `key` in `bucket`. Buckets are mapped onto key prefixes starting
+/// with [`BUCKET_PREFIX`], keys in the default bucket are used as-is.
+/// Bucket names containing the separator are rejected, since their keys would collide with
+/// keys of other buckets, e.g. key `b:c` in bucket `a` and key `c` in bucket `a:b`.
+fn bucket_key(bucket: Option<&str>, key: &str) -> Result<String, String> {
+    match bucket {
+        Some(bucket) if bucket.contains(BUCKET_SEPARATOR) => Err(format!(
+            "bucket name `{bucket}` must not contain `{BUCKET_SEPARATOR}`"
+        )),
+        Some(bucket) => Ok(format!("{BUCKET_PREFIX}{bucket}{BUCKET_SEPARATOR}{key}")),
+        None => default_key(key).map(ToString::to_string),
+    }
+}
+
+/// Returns `key` if it can be used as a Redis key in the default bucket, i.e. does not start
+/// with [`BUCKET_PREFIX`] and therefore cannot collide with keys of non-default buckets.
+/// This also applies to names of lists and sets, which share the keys of the default bucket
+fn default_key(key: &str) -> Result<&str, String> {
+    if key.starts_with(BUCKET_PREFIX) {
+        Err(format!("key `{key}` must not start with `{BUCKET_PREFIX}`"))
+    } else {
+        Ok(key)
+    }
+}
+
 fn get_redis_url(link_values: &[(String, String)], default_connect_url: &str) -> String {
     link_values
         .iter()
@@ -348,26 +437,54 @@ impl wasmcloud_provider_sdk::MessageDispatch for KvRedisProvider {
     ) -> Result<Vec<u8>, ProviderInvocationError> {
         match method.as_str() {
             "KeyValue.Increment" => {
-                let input: IncrementRequest = ::wasmcloud_provider_sdk::deserialize(&body)?;
-                let result = self.increment(ctx, input).await.map_err(|e| {
-                    ::wasmcloud_provider_sdk::error::ProviderInvocationError::Provider(
-                        e.to_string(),
-                    )
-                })?;
+                let (bucket, input) = ::wasmcloud_provider_sdk::deserialize::<
+                    BucketRequest<IncrementRequest>,
+                >(&body)?
+                .into_parts();
+                let result = self
+                    .increment(ctx, bucket.as_deref(), input)
+                    .await
+                    .map_err(|e| {
+                        ::wasmcloud_provider_sdk::error::ProviderInvocationError::Provider(
+                            e.to_string(),
+                        )
+                    })?;
+                Ok(::wasmcloud_provider_sdk::serialize(&result)?)
+            }
+            "KeyValue.CompareAndSwap" => {
+                let (bucket, input) = ::wasmcloud_provider_sdk::deserialize::<
+                    BucketRequest<CompareAndSwapRequest>,
+                >(&body)?
+                .into_parts();
+                let result = self
+                    .compare_and_swap(ctx, bucket.as_deref(), input)
+                    .await
+                    .map_err(|e| {
+                        ::wasmcloud_provider_sdk::error::ProviderInvocationError::Provider(
+                            e.to_string(),
+                        )
+                    })?;
                 Ok(::wasmcloud_provider_sdk::serialize(&result)?)
             }
             "KeyValue.Contains" => {
-                let input: String = ::wasmcloud_provider_sdk::deserialize(&body)?;
-                let result = self.contains(ctx, input).await.map_err(|e| {
-                    ::wasmcloud_provider_sdk::error::ProviderInvocationError::Provider(
-                        e.to_string(),
-                    )
-                })?;
+                let (bucket, input) =
+                    ::wasmcloud_provider_sdk::deserialize::<BucketRequest<String>>(&body)?
+                        .into_parts();
+                let result = self
+                    .contains(ctx, bucket.as_deref(), input)
+                    .await
+                    .map_err(|e| {
+                        ::wasmcloud_provider_sdk::error::ProviderInvocationError::Provider(
+                            e.to_string(),
+                        )
+                    })?;
                 Ok(::wasmcloud_provider_sdk::serialize(&result)?)
             }
             "KeyValue.Del" => {
-                let input: String = ::wasmcloud_provider_sdk::deserialize(&body)?;
-                let result = self.del(ctx, input).await.map_err(|e| {
+                let (bucket, input) =
+                    ::wasmcloud_provider_sdk::deserialize::<BucketRequest<String>>(&body)?
+                        .into_parts();
+                let result = self.del(ctx, bucket.as_deref(), input).await.map_err(|e| {
                     ::wasmcloud_provider_sdk::error::ProviderInvocationError::Provider(
                         e.to_string(),
                     )
@@ -375,8 +492,10 @@ impl wasmcloud_provider_sdk::MessageDispatch for KvRedisProvider {
                 Ok(::wasmcloud_provider_sdk::serialize(&result)?)
             }
             "KeyValue.Get" => {
-                let input: String = ::wasmcloud_provider_sdk::deserialize(&body)?;
-                let result = self.get(ctx, input).await.map_err(|e| {
+                let (bucket, input) =
+                    ::wasmcloud_provider_sdk::deserialize::<BucketRequest<String>>(&body)?
+                        .into_parts();
+                let result = self.get(ctx, bucket.as_deref(), input).await.map_err(|e| {
                     ::wasmcloud_provider_sdk::error::ProviderInvocationError::Provider(
                         e.to_string(),
                     )
@@ -411,8 +530,10 @@ impl wasmcloud_provider_sdk::MessageDispatch for KvRedisProvider {
                 Ok(::wasmcloud_provider_sdk::serialize(&result)?)
             }
             "KeyValue.Set" => {
-                let input: SetRequest = ::wasmcloud_provider_sdk::deserialize(&body)?;
-                let result = self.set(ctx, input).await.map_err(|e| {
+                let (bucket, input) =
+                    ::wasmcloud_provider_sdk::deserialize::<BucketRequest<SetRequest>>(&body)?
+                        .into_parts();
+                let result = self.set(ctx, bucket.as_deref(), input).await.map_err(|e| {
                     ::wasmcloud_provider_sdk::error::ProviderInvocationError::Provider(
                         e.to_string(),
                     )
@@ -487,10 +608,184 @@ impl wasmcloud_provider_sdk::Provider for KvRedisProvider {}
 
 #[cfg(test)]
 mod test {
-    use super::{get_redis_url, KvRedisConfig};
+    use super::{bucket_key, get_redis_url, KvRedisConfig, KvRedisProvider};
+    use std::env;
+    use std::time::Duration;
+    use tokio::net::{TcpListener, TcpStream};
+    use tokio::process::{Child, Command};
+    use wasmcloud_compat::keyvalue::{
+        BucketRequest, CompareAndSwapRequest, GetResponse, IncrementRequest, SetRequest,
+    };
+    use wasmcloud_provider_sdk::core::LinkDefinition;
+    use wasmcloud_provider_sdk::{Context, ProviderHandler};
 
     const PROPER_URL: &str = "redis://127.0.0.1:6379";
 
+    /// Starts a Redis server and returns it along with a provider linked to it for actor `actor`
+    async fn linked_provider() -> (Child, KvRedisProvider) {
+        let port = TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let server = Command::new(
+            env::var("WASMCLOUD_REDIS")
+                .as_deref()
+                .unwrap_or("redis-server"),
+        )
+        .args(["--port", &port.to_string(), "--save", ""])
+        .kill_on_drop(true)
+        .spawn()
+        .expect("failed to start Redis");
+        for _ in 0..50 {
+            if TcpStream::connect(("127.0.0.1", port)).await.is_ok() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+
+        let provider = KvRedisProvider::new(&format!("redis://127.0.0.1:{port}/"));
+        assert!(
+            provider
+                .put_link(&LinkDefinition {
+                    actor_id: "actor".to_string(),
+                    ..Default::default()
+                })
+                .await
+        );
+        (server, provider)
+    }
+
+    #[tokio::test]
+    async fn can_compare_and_swap() {
+        let (_server, provider) = linked_provider().await;
+
+        // a missing key is treated as 0
+        assert!(cas(&provider, "counter", 0, 1).await);
+        assert_eq!(get(&provider, "counter").await, 1);
+        assert!(!cas(&provider, "counter", 0, 2).await);
+        assert_eq!(get(&provider, "counter").await, 1);
+        assert!(cas(&provider, "counter", 1, 5).await);
+        assert_eq!(get(&provider, "counter").await, 5);
+
+        assert!(!cas(&provider, "missing", 1, 2).await);
+        assert!(!provider
+            .contains(ctx(), None, "missing".to_string())
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn can_isolate_buckets() {
+        let (_server, provider) = linked_provider().await;
+
+        provider
+            .set(
+                ctx(),
+                Some("a"),
+                SetRequest {
+                    key: "b:c".to_string(),
+                    value: b"a".to_vec(),
+                    expires: 0,
+                },
+            )
+            .await
+            .unwrap();
+        for (bucket, key) in [
+            (Some("b"), "b:c"),
+            (None, "b:c"),
+            (None, "c"),
+            (Some("b"), "c"),
+        ] {
+            assert!(!provider
+                .contains(ctx(), bucket, key.to_string())
+                .await
+                .unwrap());
+        }
+        let GetResponse { value, exists } = provider
+            .get(ctx(), Some("a"), "b:c".to_string())
+            .await
+            .unwrap();
+        assert!(exists);
+        assert_eq!(value, b"a");
+
+        // default key `a:b:c` must not address `b:c` in bucket `a`
+        provider
+            .set(
+                ctx(),
+                None,
+                SetRequest {
+                    key: "a:b:c".to_string(),
+                    value: b"default".to_vec(),
+                    expires: 0,
+                },
+            )
+            .await
+            .unwrap();
+        let GetResponse { value, .. } = provider
+            .get(ctx(), Some("a"), "b:c".to_string())
+            .await
+            .unwrap();
+        assert_eq!(value, b"a");
+        let GetResponse { value, .. } = provider
+            .get(ctx(), None, "a:b:c".to_string())
+            .await
+            .unwrap();
+        assert_eq!(value, b"default");
+        assert!(provider
+            .del(ctx(), None, "wasmcloud:bucket:a:b:c".to_string())
+            .await
+            .is_err());
+
+        // `c` in bucket `a:b` would collide with `b:c` in bucket `a`
+        assert!(provider
+            .get(ctx(), Some("a:b"), "c".to_string())
+            .await
+            .is_err());
+        assert!(provider
+            .del(ctx(), Some("a:b"), "c".to_string())
+            .await
+            .is_err());
+    }
+
+    fn ctx() -> Context {
+        Context {
+            actor: Some("actor".to_string()),
+            ..Default::default()
+        }
+    }
+
+    async fn cas(provider: &KvRedisProvider, key: &str, old: u64, new: u64) -> bool {
+        provider
+            .compare_and_swap(
+                ctx(),
+                None,
+                CompareAndSwapRequest {
+                    key: key.to_string(),
+                    old,
+                    new,
+                },
+            )
+            .await
+            .unwrap()
+    }
+
+    /// Reads a numeric value by incrementing it by 0
+    async fn get(provider: &KvRedisProvider, key: &str) -> i32 {
+        provider
+            .increment(
+                ctx(),
+                None,
+                IncrementRequest {
+                    key: key.to_string(),
+                    value: 0,
+                },
+            )
+            .await
+            .unwrap()
+    }
+
     #[test]
     fn can_deserialize_config_case_insensitive() {
         let lowercase_config = format!("{{\"url\": \"{}\"}}", PROPER_URL);
@@ -517,6 +812,57 @@ mod test {
         );
     }
 
+    #[test]
+    fn can_map_buckets_to_key_prefixes() {
+        assert_eq!(bucket_key(None, "foo").unwrap(), "foo");
+        assert_eq!(bucket_key(None, "a:b:c").unwrap(), "a:b:c");
+        assert_eq!(
+            bucket_key(Some("sessions"), "foo").unwrap(),
+            "wasmcloud:bucket:sessions:foo"
+        );
+        assert_eq!(
+            bucket_key(Some("a"), "b:c").unwrap(),
+            "wasmcloud:bucket:a:b:c"
+        );
+        // `a:b` would address the same key as `b:c` in bucket `a`
+        assert!(bucket_key(Some("a:b"), "c").is_err());
+        // default keys must not address keys of other buckets
+        assert!(bucket_key(None, "wasmcloud:bucket:a:b:c").is_err());
+    }
+
+    #[test]
+    fn can_decode_bucket_requests() {
+        let req = SetRequest {
+            key: "foo".to_string(),
+            value: b"bar".to_vec(),
+            expires: 0,
+        };
+        for bucket in ["", "sessions"] {
+            let buf = wasmcloud_provider_sdk::serialize(&BucketRequest::new(bucket, req.clone()))
+                .unwrap();
+            let (decoded_bucket, decoded) =
+                wasmcloud_provider_sdk::deserialize::<BucketRequest<SetRequest>>(&buf)
+                    .unwrap()
+                    .into_parts();
+            assert_eq!(
+                decoded_bucket.as_deref(),
+                (!bucket.is_empty()).then_some(bucket)
+            );
+            assert_eq!(decoded, req);
+        }
+        // Requests to the default bucket are compatible with providers unaware of buckets
+        let buf =
+            wasmcloud_provider_sdk::serialize(&BucketRequest::new("", "foo".to_string())).unwrap();
+        assert_eq!(
+            wasmcloud_provider_sdk::deserialize::<String>(&buf).unwrap(),
+            "foo"
+        );
+        let buf =
+            wasmcloud_provider_sdk::serialize(&BucketRequest::new("sessions", "foo".to_string()))
+                .unwrap();
+        assert!(wasmcloud_provider_sdk::deserialize::<String>(&buf).is_err());
+    }
+
     #[test]
     fn can_accept_case_insensitive_url_parameters() {
         assert_eq!(
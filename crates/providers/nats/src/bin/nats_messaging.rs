@@ -16,7 +16,9 @@ use tokio::task::JoinHandle;
 use tracing::{debug, error, instrument, warn};
 use tracing_futures::Instrument;
 use wascap::prelude::KeyPair;
-use wasmcloud_compat::messaging::{PubMessage, ReplyMessage, RequestMessage, SubMessage};
+use wasmcloud_compat::messaging::{
+    PubMessage, ReplyMessage, RequestMessage, RequestMultiMessage, SubMessage,
+};
 use wasmcloud_provider_sdk::core::{HostData, LinkDefinition, WasmCloudEntity};
 use wasmcloud_provider_sdk::error::ProviderInvocationError;
 use wasmcloud_provider_sdk::{load_host_data, start_provider, Context, ProviderHandler};
@@ -495,6 +497,72 @@ impl NatsMessagingProvider {
             }),
         }
     }
+
+    /// Publishes a request with a unique reply inbox and collects up to `max_results` replies
+    /// received within the timeout. Returns the replies collected so far once the timeout elapses.
+    #[instrument(level = "debug", skip(self, ctx, msg), fields(actor_id = ?ctx.actor, subject = %msg.subject, max_results = %msg.max_results))]
+    async fn request_multi(
+        &self,
+        ctx: Context,
+        msg: RequestMultiMessage,
+    ) -> Result<Vec<ReplyMessage>, String> {
+        let actor_id = ctx
+            .actor
+            .as_ref()
+            .ok_or_else(|| "no actor in request".to_string())?;
+
+        let nats_client = {
+            let rd = self.actors.read().await;
+            let nats_bundle = rd
+                .get(actor_id)
+                .ok_or_else(|| format!("actor not linked:{}", actor_id))?;
+            nats_bundle.client.clone()
+        }; // early release of actor-client map
+
+        // Subscribe to the reply inbox before publishing, so that no replies are missed
+        let inbox = nats_client.new_inbox();
+        let mut replies_sub = nats_client
+            .subscribe(inbox.clone())
+            .await
+            .map_err(|e| format!("nats subscribe error: {}", e))?;
+
+        if should_strip_headers(&msg.subject) {
+            nats_client
+                .publish_with_reply(msg.subject.to_string(), inbox, msg.body.into())
+                .await
+        } else {
+            nats_client
+                .publish_with_reply_and_headers(
+                    msg.subject.to_string(),
+                    inbox,
+                    NatsHeaderInjector::default_with_span().into(),
+                    msg.body.into(),
+                )
+                .await
+        }
+        .map_err(|e| format!("nats send error: {}", e))?;
+        let _ = nats_client.flush().await;
+
+        let max_results = msg.max_results as usize;
+        let mut replies = Vec::with_capacity(max_results);
+        let _ = tokio::time::timeout(Duration::from_millis(msg.timeout_ms as u64), async {
+            while replies.len() < max_results {
+                let Some(resp) = replies_sub.next().await else {
+                    break;
+                };
+                replies.push(ReplyMessage {
+                    body: resp.payload.to_vec(),
+                    reply_to: resp.reply,
+                    subject: resp.subject,
+                });
+            }
+        })
+        .await;
+        if let Err(err) = replies_sub.unsubscribe().await {
+            warn!(?err, "failed to unsubscribe from reply inbox");
+        }
+        Ok(replies)
+    }
 }
 
 // In the current version of the NATS server, using headers on certain $SYS.REQ topics will cause server-side
@@ -530,6 +598,15 @@ impl wasmcloud_provider_sdk::MessageDispatch for NatsMessagingProvider {
                 })?;
                 Ok(::wasmcloud_provider_sdk::serialize(&result)?)
             }
+            "Messaging.RequestMulti" => {
+                let input: RequestMultiMessage = ::wasmcloud_provider_sdk::deserialize(&body)?;
+                let result = self.request_multi(ctx, input).await.map_err(|e| {
+                    ::wasmcloud_provider_sdk::error::ProviderInvocationError::Provider(
+                        e.to_string(),
+                    )
+                })?;
+                Ok(::wasmcloud_provider_sdk::serialize(&result)?)
+            }
             _ => Err(
                 ::wasmcloud_provider_sdk::error::InvocationError::Malformed(format!(
                     "Invalid method name {method}",
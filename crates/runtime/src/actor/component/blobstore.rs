@@ -11,7 +11,7 @@ use crate::io::AsyncVec;
 
 use std::sync::Arc;
 
-use anyhow::{bail, ensure, Context as _};
+use anyhow::{ensure, Context as _};
 use async_trait::async_trait;
 use futures::{Stream, StreamExt};
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeekExt};
@@ -283,16 +283,28 @@ impl blobstore::Host for Ctx {
         }
     }
 
-    #[allow(unused)] // TODO: Implement and remove
     #[instrument]
     async fn copy_object(&mut self, src: ObjectId, dest: ObjectId) -> anyhow::Result<Result<()>> {
-        bail!("not supported yet")
+        match self
+            .handler
+            .copy_object(&src.container, src.object, &dest.container, dest.object)
+            .await
+        {
+            Ok(()) => Ok(Ok(())),
+            Err(err) => Ok(Err(format!("{err:#}"))),
+        }
     }
 
-    #[allow(unused)] // TODO: Implement and remove
     #[instrument]
     async fn move_object(&mut self, src: ObjectId, dest: ObjectId) -> anyhow::Result<Result<()>> {
-        bail!("not supported yet")
+        match self
+            .handler
+            .move_object(&src.container, src.object, &dest.container, dest.object)
+            .await
+        {
+            Ok(()) => Ok(Ok(())),
+            Err(err) => Ok(Err(format!("{err:#}"))),
+        }
     }
 }
 
@@ -3,6 +3,7 @@ use super::{Ctx, Instance, TableResult};
 use crate::capability::bus::{host, lattice};
 use crate::capability::{Bus, TargetInterface};
 
+use core::any::Any;
 use core::future::Future;
 use core::pin::Pin;
 
@@ -12,9 +13,12 @@ use anyhow::{anyhow, bail, Context as _};
 use async_trait::async_trait;
 use futures::future::Shared;
 use futures::FutureExt;
+use tokio::spawn;
 use tracing::instrument;
 use wasmtime_wasi::preview2::pipe::{AsyncReadStream, AsyncWriteStream};
-use wasmtime_wasi::preview2::{self, TableStreamExt};
+use wasmtime_wasi::preview2::{
+    self, HostPollable, PollableFuture, TablePollableExt, TableStreamExt,
+};
 
 impl Instance {
     /// Set [`Bus`] handler for this [Instance].
@@ -26,10 +30,22 @@ impl Instance {
 
 type FutureResult = Pin<Box<dyn Future<Output = Result<(), String>> + Send>>;
 
+/// Constructs a [`PollableFuture`] ready once the [`FutureResult`] is resolved
+fn future_result_ready(f: &mut dyn Any) -> PollableFuture<'_> {
+    let Some(f) = f.downcast_mut::<Shared<FutureResult>>() else {
+        return Box::pin(async { bail!("table entry is not a future result") });
+    };
+    let f = f.clone();
+    Box::pin(async move {
+        f.await.ok();
+        Ok(())
+    })
+}
+
 trait TableHostExt {
     fn push_future_result(&mut self, res: FutureResult) -> TableResult<u32>;
-    fn get_future_result(&self, res: u32) -> TableResult<Box<Shared<FutureResult>>>;
-    fn delete_future_result(&mut self, res: u32) -> TableResult<Box<Shared<FutureResult>>>;
+    fn get_future_result(&self, res: u32) -> TableResult<Shared<FutureResult>>;
+    fn delete_future_result(&mut self, res: u32) -> TableResult<Shared<FutureResult>>;
 }
 
 trait TableLatticeExt {
@@ -42,10 +58,10 @@ impl TableHostExt for preview2::Table {
     fn push_future_result(&mut self, res: FutureResult) -> TableResult<u32> {
         self.push(Box::new(res.shared()))
     }
-    fn get_future_result(&self, res: u32) -> TableResult<Box<Shared<FutureResult>>> {
+    fn get_future_result(&self, res: u32) -> TableResult<Shared<FutureResult>> {
         self.get(res).cloned()
     }
-    fn delete_future_result(&mut self, res: u32) -> TableResult<Box<Shared<FutureResult>>> {
+    fn delete_future_result(&mut self, res: u32) -> TableResult<Shared<FutureResult>> {
         self.delete(res)
     }
 }
@@ -87,6 +103,15 @@ impl host::Host for Ctx {
             .context("failed to parse target")?;
         match self.handler.call(target, operation).await {
             Ok((result, stdin, stdout)) => {
+                // Drive the call in the background, so that it makes progress while the guest
+                // writes the request and reads the response, regardless of whether the result
+                // is ever polled
+                let result = spawn(result);
+                let result: FutureResult = Box::pin(async move {
+                    result
+                        .await
+                        .map_err(|err| format!("failed to join call task: {err}"))?
+                });
                 let result = self
                     .table
                     .push_future_result(result)
@@ -123,20 +148,25 @@ impl host::Host for Ctx {
     }
 
     #[instrument]
-    async fn listen_to_future_result(&mut self, _res: u32) -> anyhow::Result<u32> {
-        bail!("not supported") // TODO: Support
+    async fn listen_to_future_result(&mut self, res: u32) -> anyhow::Result<u32> {
+        self.table
+            .get::<Shared<FutureResult>>(res)
+            .context("failed to get future result")?;
+        self.table
+            .push_host_pollable(HostPollable::TableEntry {
+                index: res,
+                make_future: future_result_ready,
+            })
+            .context("failed to push pollable")
     }
 
     #[instrument]
     async fn future_result_get(&mut self, res: u32) -> anyhow::Result<Option<Result<(), String>>> {
-        let fut = self.table.get_future_result(res)?;
-        if let Some(result) = fut.clone().now_or_never() {
-            let fut = self.table.delete_future_result(res)?;
-            drop(fut);
-            Ok(Some(result))
-        } else {
-            Ok(None)
-        }
+        let fut = self
+            .table
+            .get_future_result(res)
+            .context("failed to get future result")?;
+        Ok(fut.now_or_never())
     }
 
     #[instrument]
@@ -1,20 +1,32 @@
-use super::{Ctx, Instance, InterfaceBindings, InterfaceInstance, TableResult};
+use super::{apply_limits, Ctx, Instance, InterfaceBindings, InterfaceInstance, TableResult};
 
-use crate::capability::http::types;
-use crate::capability::IncomingHttp;
+use crate::actor::is_timeout;
+use crate::capability::http::{outgoing_handler, types};
+use crate::capability::{HttpTrailers, IncomingHttp, OutgoingHttp};
 use crate::io::AsyncVec;
 
-use std::collections::BTreeMap;
-use std::io::Cursor;
-use std::sync::Arc;
+use core::any::Any;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::{self, Cursor};
+use std::sync::{Arc, PoisonError};
 
 use anyhow::{anyhow, bail, Context as _};
 use async_trait::async_trait;
-use tokio::io::{AsyncRead, AsyncSeekExt};
-use tokio::sync::Mutex;
+use futures::{join, FutureExt};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeekExt, AsyncWrite, DuplexStream};
+use tokio::sync::{oneshot, Mutex};
+use tokio::task::JoinHandle;
 use tracing::instrument;
 use wasmtime_wasi::preview2::pipe::{AsyncReadStream, AsyncWriteStream};
-use wasmtime_wasi::preview2::{self, TableStreamExt};
+use wasmtime_wasi::preview2::{
+    self, HostPollable, OutputStreamError, PollableFuture, TablePollableExt, TableStreamExt,
+};
+
+/// Size of the buffer used to stream response bodies from the guest
+const RESPONSE_BODY_BUFFER_SIZE: usize = 1 << 16;
 
 pub mod incoming_http_bindings {
     wasmtime::component::bindgen!({
@@ -23,6 +35,9 @@ pub mod incoming_http_bindings {
     });
 }
 
+type IncomingHttpResponse =
+    anyhow::Result<http::Response<Box<dyn AsyncRead + Sync + Send + Unpin>>>;
+
 struct IncomingRequest {
     method: types::Method,
     path_with_query: Option<String>,
@@ -30,12 +45,262 @@ struct IncomingRequest {
     authority: Option<String>,
     headers: types::Headers,
     body: Box<dyn AsyncRead + Sync + Send + Unpin>,
+    trailers: Option<HttpTrailers>,
+}
+
+/// Writable end of a response body pipe, which the host closes once the guest returns
+#[derive(Clone)]
+struct BodyWriter(Arc<std::sync::Mutex<Option<DuplexStream>>>);
+
+impl BodyWriter {
+    /// Closes the pipe, after which the readable end reaches EOF once it is drained
+    fn close(&self) {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner).take();
+    }
+
+    fn poll_with<T>(
+        &self,
+        f: impl FnOnce(Pin<&mut DuplexStream>) -> Poll<io::Result<T>>,
+    ) -> Poll<io::Result<T>> {
+        let mut w = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        match w.as_mut() {
+            Some(w) => f(Pin::new(w)),
+            None => Poll::Ready(Err(io::ErrorKind::BrokenPipe.into())),
+        }
+    }
+}
+
+impl AsyncWrite for BodyWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.poll_with(|w| w.poll_write(cx, buf))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.poll_with(|w| w.poll_flush(cx))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.poll_with(|w| w.poll_shutdown(cx))
+    }
 }
 
 struct OutgoingResponse {
     status_code: types::StatusCode,
     headers: types::Headers,
+    body: BodyWriter,
+    /// Sender of the trailers, taken by `outgoing-response-write`
+    trailers: Option<oneshot::Sender<http::HeaderMap>>,
+    /// Readable end of the body pipe and the trailers, taken once the response is set
+    sent: Option<(DuplexStream, HttpTrailers)>,
+}
+
+/// `response-outparam` of an incoming HTTP request
+struct ResponseOutparam {
+    /// Sender of the response, taken once the response is set
+    response: Option<oneshot::Sender<IncomingHttpResponse>>,
+}
+
+struct OutgoingRequest {
+    method: types::Method,
+    path_with_query: Option<String>,
+    scheme: Option<types::Scheme>,
+    authority: Option<String>,
+    headers: types::Headers,
     body: AsyncVec,
+    /// Sender of the trailers, taken by `outgoing-request-write`
+    trailers_tx: Option<oneshot::Sender<http::HeaderMap>>,
+    trailers: HttpTrailers,
+}
+
+struct IncomingResponse {
+    status_code: types::StatusCode,
+    headers: types::Headers,
+    body: Box<dyn AsyncRead + Sync + Send + Unpin>,
+    trailers: Option<HttpTrailers>,
+}
+
+/// State of `wasi:http` body streams, which is not tracked by the table
+#[derive(Default)]
+pub(super) struct HttpStreams {
+    /// Trailers of incoming body streams
+    incoming_trailers: HashMap<types::IncomingStream, HttpTrailers>,
+    /// Senders of the trailers of outgoing body streams
+    outgoing_trailers: HashMap<types::OutgoingStream, oneshot::Sender<http::HeaderMap>>,
+    /// Bodies of responses sent to the host
+    response_bodies: Vec<BodyWriter>,
+}
+
+impl HttpStreams {
+    /// Closes all response bodies and completes all outgoing trailers, which have not been sent
+    fn close(&mut self) {
+        self.incoming_trailers.clear();
+        self.outgoing_trailers.clear();
+        for body in self.response_bodies.drain(..) {
+            body.close();
+        }
+    }
+}
+
+/// Trailers of an incoming body stream
+enum FutureTrailers {
+    Pending(HttpTrailers),
+    Ready(Option<http::HeaderMap>),
+    Consumed,
+}
+
+impl FutureTrailers {
+    /// Waits for the trailers to become available
+    async fn ready(&mut self) {
+        if let Self::Pending(trailers) = self {
+            let trailers = trailers.await;
+            *self = Self::Ready(trailers);
+        }
+    }
+
+    /// Takes the trailers if they are available without blocking. A body completed without
+    /// trailers results in empty trailers
+    fn take(&mut self) -> anyhow::Result<Option<http::HeaderMap>> {
+        match self {
+            Self::Pending(trailers) => {
+                let Some(trailers) = trailers.now_or_never() else {
+                    return Ok(None);
+                };
+                *self = Self::Consumed;
+                Ok(Some(trailers.unwrap_or_default()))
+            }
+            Self::Ready(..) => {
+                let Self::Ready(trailers) = std::mem::replace(self, Self::Consumed) else {
+                    unreachable!()
+                };
+                Ok(Some(trailers.unwrap_or_default()))
+            }
+            Self::Consumed => bail!("trailers already consumed"),
+        }
+    }
+}
+
+/// Constructs a [`PollableFuture`] ready once the [`FutureTrailers`] are available
+fn future_trailers_ready(f: &mut dyn Any) -> PollableFuture<'_> {
+    let Some(f) = f.downcast_mut::<FutureTrailers>() else {
+        return Box::pin(async { bail!("table entry is not a future trailers") });
+    };
+    Box::pin(async move {
+        f.ready().await;
+        Ok(())
+    })
+}
+
+/// Result of writing trailers of an outgoing body stream, which is known as soon as they are
+/// written. Taken once read by the guest
+struct FutureWriteTrailersResult(Option<Result<(), types::Error>>);
+
+type OutgoingHttpResult =
+    Result<http::Response<Box<dyn AsyncRead + Sync + Send + Unpin>>, types::Error>;
+
+/// Response to an outgoing HTTP request, which is handled by [`OutgoingHttp`] in a background task
+enum FutureIncomingResponse {
+    Pending(JoinHandle<OutgoingHttpResult>),
+    Ready(OutgoingHttpResult),
+    Consumed,
+}
+
+impl FutureIncomingResponse {
+    /// Waits for the response to become available
+    async fn ready(&mut self) {
+        if let Self::Pending(task) = self {
+            let res = task.await.unwrap_or_else(|err| {
+                Err(types::Error::UnexpectedError(format!(
+                    "outgoing HTTP request task failed: {err}"
+                )))
+            });
+            *self = Self::Ready(res);
+        }
+    }
+
+    /// Takes the response if it is available without blocking
+    fn take(&mut self) -> anyhow::Result<Option<OutgoingHttpResult>> {
+        match self {
+            Self::Pending(task) => {
+                let Some(res) = task.now_or_never() else {
+                    return Ok(None);
+                };
+                *self = Self::Consumed;
+                Ok(Some(res.unwrap_or_else(|err| {
+                    Err(types::Error::UnexpectedError(format!(
+                        "outgoing HTTP request task failed: {err}"
+                    )))
+                })))
+            }
+            Self::Ready(..) => {
+                let Self::Ready(res) = std::mem::replace(self, Self::Consumed) else {
+                    unreachable!()
+                };
+                Ok(Some(res))
+            }
+            Self::Consumed => bail!("response already consumed"),
+        }
+    }
+}
+
+/// Constructs a [`PollableFuture`] ready once the [`FutureIncomingResponse`] is resolved
+fn future_incoming_response_ready(f: &mut dyn Any) -> PollableFuture<'_> {
+    let Some(f) = f.downcast_mut::<FutureIncomingResponse>() else {
+        return Box::pin(async { bail!("table entry is not a future incoming response") });
+    };
+    Box::pin(async move {
+        f.ready().await;
+        Ok(())
+    })
+}
+
+/// Flushes the output stream `s` and waits until all buffered contents are written
+async fn flush_output_stream(
+    table: &mut preview2::Table,
+    s: types::OutgoingStream,
+) -> anyhow::Result<()> {
+    let stream = table
+        .get_output_stream_mut(s)
+        .context("failed to get output stream")?;
+    match stream.flush() {
+        Ok(()) => {}
+        Err(OutputStreamError::Closed) => return Ok(()),
+        Err(err) => return Err(err).context("failed to flush output stream"),
+    }
+    match stream.write_ready().await {
+        Ok(_) | Err(OutputStreamError::Closed) => Ok(()),
+        Err(err) => Err(err).context("failed to wait for output stream flush"),
+    }
+}
+
+fn fields_to_headers(fields: &BTreeMap<String, Vec<Vec<u8>>>) -> anyhow::Result<http::HeaderMap> {
+    let mut headers = http::HeaderMap::new();
+    for (name, values) in fields {
+        let name = http::HeaderName::try_from(name)
+            .with_context(|| format!("invalid header name `{name}`"))?;
+        for value in values {
+            let value = http::HeaderValue::from_bytes(value)
+                .with_context(|| format!("invalid `{name}` header value"))?;
+            headers.append(name.clone(), value);
+        }
+    }
+    Ok(headers)
+}
+
+fn headers_to_fields(headers: &http::HeaderMap) -> BTreeMap<String, Vec<Vec<u8>>> {
+    headers.iter().fold(
+        BTreeMap::<_, Vec<_>>::default(),
+        |mut fields, (name, value)| {
+            fields
+                .entry(name.as_str().into())
+                .or_default()
+                .push(value.as_bytes().into());
+            fields
+        },
+    )
 }
 
 trait TableKeyValueExt {
@@ -64,28 +329,96 @@ trait TableKeyValueExt {
         request: types::IncomingRequest,
     ) -> TableResult<IncomingRequest>;
 
-    fn new_response_outparam(&mut self) -> TableResult<types::ResponseOutparam>;
+    fn new_response_outparam(
+        &mut self,
+        response: oneshot::Sender<IncomingHttpResponse>,
+    ) -> TableResult<types::ResponseOutparam>;
     fn get_response_outparam_mut(
         &mut self,
         response: types::ResponseOutparam,
-    ) -> TableResult<&mut Option<Result<types::IncomingResponse, types::Error>>>;
+    ) -> TableResult<&mut ResponseOutparam>;
     fn delete_response_outparam(
         &mut self,
         response: types::ResponseOutparam,
-    ) -> TableResult<Option<Result<types::IncomingResponse, types::Error>>>;
+    ) -> TableResult<ResponseOutparam>;
 
     fn push_outgoing_response(
         &mut self,
         response: OutgoingResponse,
     ) -> TableResult<types::OutgoingResponse>;
-    fn get_outgoing_response(
-        &self,
+    fn get_outgoing_response_mut(
+        &mut self,
         response: types::OutgoingResponse,
-    ) -> TableResult<&OutgoingResponse>;
+    ) -> TableResult<&mut OutgoingResponse>;
     fn delete_outgoing_response(
         &mut self,
         response: types::OutgoingResponse,
     ) -> TableResult<OutgoingResponse>;
+
+    fn push_outgoing_request(
+        &mut self,
+        request: OutgoingRequest,
+    ) -> TableResult<types::OutgoingRequest>;
+    fn get_outgoing_request_mut(
+        &mut self,
+        request: types::OutgoingRequest,
+    ) -> TableResult<&mut OutgoingRequest>;
+    fn delete_outgoing_request(
+        &mut self,
+        request: types::OutgoingRequest,
+    ) -> TableResult<OutgoingRequest>;
+
+    fn push_incoming_response(
+        &mut self,
+        response: IncomingResponse,
+    ) -> TableResult<types::IncomingResponse>;
+    fn get_incoming_response(
+        &self,
+        response: types::IncomingResponse,
+    ) -> TableResult<&IncomingResponse>;
+    fn delete_incoming_response(
+        &mut self,
+        response: types::IncomingResponse,
+    ) -> TableResult<IncomingResponse>;
+
+    fn push_future_incoming_response(
+        &mut self,
+        response: FutureIncomingResponse,
+    ) -> TableResult<types::FutureIncomingResponse>;
+    fn get_future_incoming_response_mut(
+        &mut self,
+        response: types::FutureIncomingResponse,
+    ) -> TableResult<&mut FutureIncomingResponse>;
+    fn delete_future_incoming_response(
+        &mut self,
+        response: types::FutureIncomingResponse,
+    ) -> TableResult<FutureIncomingResponse>;
+
+    fn push_future_trailers(
+        &mut self,
+        trailers: FutureTrailers,
+    ) -> TableResult<types::FutureTrailers>;
+    fn get_future_trailers_mut(
+        &mut self,
+        trailers: types::FutureTrailers,
+    ) -> TableResult<&mut FutureTrailers>;
+    fn delete_future_trailers(
+        &mut self,
+        trailers: types::FutureTrailers,
+    ) -> TableResult<FutureTrailers>;
+
+    fn push_future_write_trailers_result(
+        &mut self,
+        result: FutureWriteTrailersResult,
+    ) -> TableResult<types::FutureWriteTrailersResult>;
+    fn get_future_write_trailers_result_mut(
+        &mut self,
+        result: types::FutureWriteTrailersResult,
+    ) -> TableResult<&mut FutureWriteTrailersResult>;
+    fn delete_future_write_trailers_result(
+        &mut self,
+        result: types::FutureWriteTrailersResult,
+    ) -> TableResult<FutureWriteTrailersResult>;
 }
 
 impl TableKeyValueExt for preview2::Table {
@@ -135,23 +468,26 @@ impl TableKeyValueExt for preview2::Table {
         self.delete(request)
     }
 
-    fn new_response_outparam(&mut self) -> TableResult<types::ResponseOutparam> {
-        self.push(Box::new(
-            None::<Result<types::IncomingResponse, types::Error>>,
-        ))
+    fn new_response_outparam(
+        &mut self,
+        response: oneshot::Sender<IncomingHttpResponse>,
+    ) -> TableResult<types::ResponseOutparam> {
+        self.push(Box::new(ResponseOutparam {
+            response: Some(response),
+        }))
     }
 
     fn get_response_outparam_mut(
         &mut self,
         response: types::ResponseOutparam,
-    ) -> TableResult<&mut Option<Result<types::IncomingResponse, types::Error>>> {
+    ) -> TableResult<&mut ResponseOutparam> {
         self.get_mut(response)
     }
 
     fn delete_response_outparam(
         &mut self,
         response: types::ResponseOutparam,
-    ) -> TableResult<Option<Result<types::IncomingResponse, types::Error>>> {
+    ) -> TableResult<ResponseOutparam> {
         self.delete(response)
     }
 
@@ -162,11 +498,11 @@ impl TableKeyValueExt for preview2::Table {
         self.push(Box::new(response))
     }
 
-    fn get_outgoing_response(
-        &self,
+    fn get_outgoing_response_mut(
+        &mut self,
         response: types::OutgoingResponse,
-    ) -> TableResult<&OutgoingResponse> {
-        self.get(response)
+    ) -> TableResult<&mut OutgoingResponse> {
+        self.get_mut(response)
     }
 
     fn delete_outgoing_response(
@@ -175,6 +511,111 @@ impl TableKeyValueExt for preview2::Table {
     ) -> TableResult<OutgoingResponse> {
         self.delete(response)
     }
+
+    fn push_outgoing_request(
+        &mut self,
+        request: OutgoingRequest,
+    ) -> TableResult<types::OutgoingRequest> {
+        self.push(Box::new(request))
+    }
+
+    fn get_outgoing_request_mut(
+        &mut self,
+        request: types::OutgoingRequest,
+    ) -> TableResult<&mut OutgoingRequest> {
+        self.get_mut(request)
+    }
+
+    fn delete_outgoing_request(
+        &mut self,
+        request: types::OutgoingRequest,
+    ) -> TableResult<OutgoingRequest> {
+        self.delete(request)
+    }
+
+    fn push_incoming_response(
+        &mut self,
+        response: IncomingResponse,
+    ) -> TableResult<types::IncomingResponse> {
+        self.push(Box::new(response))
+    }
+
+    fn get_incoming_response(
+        &self,
+        response: types::IncomingResponse,
+    ) -> TableResult<&IncomingResponse> {
+        self.get(response)
+    }
+
+    fn delete_incoming_response(
+        &mut self,
+        response: types::IncomingResponse,
+    ) -> TableResult<IncomingResponse> {
+        self.delete(response)
+    }
+
+    fn push_future_incoming_response(
+        &mut self,
+        response: FutureIncomingResponse,
+    ) -> TableResult<types::FutureIncomingResponse> {
+        self.push(Box::new(response))
+    }
+
+    fn get_future_incoming_response_mut(
+        &mut self,
+        response: types::FutureIncomingResponse,
+    ) -> TableResult<&mut FutureIncomingResponse> {
+        self.get_mut(response)
+    }
+
+    fn delete_future_incoming_response(
+        &mut self,
+        response: types::FutureIncomingResponse,
+    ) -> TableResult<FutureIncomingResponse> {
+        self.delete(response)
+    }
+
+    fn push_future_trailers(
+        &mut self,
+        trailers: FutureTrailers,
+    ) -> TableResult<types::FutureTrailers> {
+        self.push(Box::new(trailers))
+    }
+
+    fn get_future_trailers_mut(
+        &mut self,
+        trailers: types::FutureTrailers,
+    ) -> TableResult<&mut FutureTrailers> {
+        self.get_mut(trailers)
+    }
+
+    fn delete_future_trailers(
+        &mut self,
+        trailers: types::FutureTrailers,
+    ) -> TableResult<FutureTrailers> {
+        self.delete(trailers)
+    }
+
+    fn push_future_write_trailers_result(
+        &mut self,
+        result: FutureWriteTrailersResult,
+    ) -> TableResult<types::FutureWriteTrailersResult> {
+        self.push(Box::new(result))
+    }
+
+    fn get_future_write_trailers_result_mut(
+        &mut self,
+        result: types::FutureWriteTrailersResult,
+    ) -> TableResult<&mut FutureWriteTrailersResult> {
+        self.get_mut(result)
+    }
+
+    fn delete_future_write_trailers_result(
+        &mut self,
+        result: types::FutureWriteTrailersResult,
+    ) -> TableResult<FutureWriteTrailersResult> {
+        self.delete(result)
+    }
 }
 
 #[async_trait]
@@ -272,71 +713,125 @@ impl types::Host for Ctx {
     async fn finish_incoming_stream(
         &mut self,
         s: types::IncomingStream,
-    ) -> anyhow::Result<Option<types::Trailers>> {
+    ) -> anyhow::Result<Option<types::FutureTrailers>> {
         self.table
             .get_input_stream_mut(s)
-            .context("failed to get output stream")?;
-        // TODO: Read to end and get trailers
-        Ok(None)
+            .context("failed to get input stream")?;
+        let Some(trailers) = self.http_streams.incoming_trailers.remove(&s) else {
+            return Ok(None);
+        };
+        let trailers = self
+            .table
+            .push_future_trailers(FutureTrailers::Pending(trailers))
+            .context("failed to push future trailers")?;
+        Ok(Some(trailers))
     }
     async fn finish_outgoing_stream(&mut self, s: types::OutgoingStream) -> anyhow::Result<()> {
-        self.table
-            .get_output_stream_mut(s)
-            .context("failed to get output stream")?;
-        // TODO: Close
+        flush_output_stream(&mut self.table, s).await?;
+        // Dropping the sender completes the trailers without any being sent
+        self.http_streams.outgoing_trailers.remove(&s);
         Ok(())
     }
-    #[allow(unused)] // TODO: Remove
     async fn finish_outgoing_stream_with_trailers(
         &mut self,
         s: types::OutgoingStream,
         trailers: types::Trailers,
     ) -> anyhow::Result<types::FutureWriteTrailersResult> {
+        flush_output_stream(&mut self.table, s).await?;
+        let trailers = self
+            .table
+            .get_fields(trailers)
+            .context("failed to get trailers")?;
+        let res = match (
+            fields_to_headers(trailers),
+            self.http_streams.outgoing_trailers.remove(&s),
+        ) {
+            (Ok(trailers), Some(tx)) => tx
+                .send(trailers)
+                .map_err(|_| types::Error::UnexpectedError("trailer receiver dropped".into())),
+            (Ok(_), None) => Err(types::Error::UnexpectedError(
+                "stream does not support trailers".into(),
+            )),
+            (Err(err), _) => Err(types::Error::ProtocolError(format!("{err:#}"))),
+        };
         self.table
-            .get_output_stream_mut(s)
-            .context("failed to get output stream")?;
-        // TODO: Close
-        bail!("trailers not supported yet")
+            .push_future_write_trailers_result(FutureWriteTrailersResult(Some(res)))
+            .context("failed to push future write trailers result")
     }
 
-    #[allow(unused)] // TODO: Remove
     async fn drop_future_trailers(&mut self, f: types::FutureTrailers) -> anyhow::Result<()> {
-        bail!("trailers not supported yet")
+        self.table
+            .delete_future_trailers(f)
+            .context("failed to delete future trailers")?;
+        Ok(())
     }
-    #[allow(unused)] // TODO: Remove
     async fn future_trailers_get(
         &mut self,
         f: types::FutureTrailers,
     ) -> anyhow::Result<Option<Result<types::Trailers, types::Error>>> {
-        bail!("trailers not supported yet")
+        let Some(trailers) = self
+            .table
+            .get_future_trailers_mut(f)
+            .context("failed to get future trailers")?
+            .take()?
+        else {
+            return Ok(None);
+        };
+        let trailers = self
+            .table
+            .push_fields(headers_to_fields(&trailers))
+            .context("failed to push trailers")?;
+        Ok(Some(Ok(trailers)))
     }
-    #[allow(unused)] // TODO: Remove
     async fn listen_to_future_trailers(
         &mut self,
         f: types::FutureTrailers,
     ) -> anyhow::Result<types::Pollable> {
-        bail!("trailers not supported yet")
+        self.table
+            .get_future_trailers_mut(f)
+            .context("failed to get future trailers")?;
+        self.table
+            .push_host_pollable(HostPollable::TableEntry {
+                index: f,
+                make_future: future_trailers_ready,
+            })
+            .context("failed to push pollable")
     }
-    #[allow(unused)] // TODO: Remove
     async fn drop_future_write_trailers_result(
         &mut self,
         f: types::FutureWriteTrailersResult,
     ) -> anyhow::Result<()> {
-        bail!("trailers not supported yet")
+        self.table
+            .delete_future_write_trailers_result(f)
+            .context("failed to delete future write trailers result")?;
+        Ok(())
     }
-    #[allow(unused)] // TODO: Remove
     async fn future_write_trailers_result_get(
         &mut self,
         f: types::FutureWriteTrailersResult,
     ) -> anyhow::Result<Option<Result<(), types::Error>>> {
-        bail!("trailers not supported yet")
+        let FutureWriteTrailersResult(res) = self
+            .table
+            .get_future_write_trailers_result_mut(f)
+            .context("failed to get future write trailers result")?;
+        let res = res
+            .take()
+            .context("write trailers result already consumed")?;
+        Ok(Some(res))
     }
-    #[allow(unused)] // TODO: Remove
     async fn listen_to_future_write_trailers_result(
         &mut self,
         f: types::FutureWriteTrailersResult,
     ) -> anyhow::Result<types::Pollable> {
-        bail!("trailers not supported yet")
+        self.table
+            .get_future_write_trailers_result_mut(f)
+            .context("failed to get future write trailers result")?;
+        // Trailers are written synchronously, so the result is always ready
+        self.table
+            .push_host_pollable(HostPollable::Closure(Box::new(|| {
+                Box::pin(async { Ok(()) })
+            })))
+            .context("failed to push pollable")
     }
 
     async fn drop_incoming_request(
@@ -348,12 +843,14 @@ impl types::Host for Ctx {
             .context("failed to delete incoming request")?;
         Ok(())
     }
-    #[allow(unused)] // TODO: Remove
     async fn drop_outgoing_request(
         &mut self,
         request: types::OutgoingRequest,
     ) -> anyhow::Result<()> {
-        bail!("outgoing HTTP not supported yet")
+        self.table
+            .delete_outgoing_request(request)
+            .context("failed to delete outgoing request")?;
+        Ok(())
     }
     async fn incoming_request_method(
         &mut self,
@@ -411,7 +908,7 @@ impl types::Host for Ctx {
         &mut self,
         request: types::IncomingRequest,
     ) -> anyhow::Result<Result<types::IncomingStream, ()>> {
-        let IncomingRequest { body, .. } = self
+        let IncomingRequest { body, trailers, .. } = self
             .table
             .delete_incoming_request(request)
             .context("failed to delete incoming request")?;
@@ -419,9 +916,11 @@ impl types::Host for Ctx {
             .table
             .push_input_stream(Box::new(AsyncReadStream::new(body)))
             .context("failed to push input stream")?;
+        if let Some(trailers) = trailers {
+            self.http_streams.incoming_trailers.insert(stream, trailers);
+        }
         Ok(Ok(stream))
     }
-    #[allow(unused)] // TODO: Remove
     async fn new_outgoing_request(
         &mut self,
         method: types::Method,
@@ -430,14 +929,47 @@ impl types::Host for Ctx {
         authority: Option<String>,
         headers: types::Headers,
     ) -> anyhow::Result<Result<types::OutgoingRequest, types::Error>> {
-        bail!("outgoing HTTP not supported yet")
+        self.table
+            .get_fields(headers)
+            .context("failed to get headers")?;
+        let (trailers_tx, trailers) = HttpTrailers::channel();
+        let request = self
+            .table
+            .push_outgoing_request(OutgoingRequest {
+                method,
+                path_with_query,
+                scheme,
+                authority,
+                headers,
+                body: AsyncVec::default(),
+                trailers_tx: Some(trailers_tx),
+                trailers,
+            })
+            .context("failed to push outgoing request")?;
+        Ok(Ok(request))
     }
-    #[allow(unused)] // TODO: Remove
     async fn outgoing_request_write(
         &mut self,
         request: types::OutgoingRequest,
     ) -> anyhow::Result<Result<types::OutgoingStream, ()>> {
-        bail!("outgoing HTTP not supported yet")
+        let OutgoingRequest {
+            body, trailers_tx, ..
+        } = self
+            .table
+            .get_outgoing_request_mut(request)
+            .context("failed to get outgoing request")?;
+        let Some(trailers_tx) = trailers_tx.take() else {
+            return Ok(Err(()));
+        };
+        let body = body.clone();
+        let stream = self
+            .table
+            .push_output_stream(Box::new(AsyncWriteStream::new(1 << 16, body)))
+            .context("failed to push output stream")?;
+        self.http_streams
+            .outgoing_trailers
+            .insert(stream, trailers_tx);
+        Ok(Ok(stream))
     }
     async fn drop_response_outparam(
         &mut self,
@@ -453,20 +985,65 @@ impl types::Host for Ctx {
         param: types::ResponseOutparam,
         response: Result<types::OutgoingResponse, types::Error>,
     ) -> anyhow::Result<Result<(), ()>> {
-        let param = self
+        let ResponseOutparam { response: tx } = self
             .table
             .get_response_outparam_mut(param)
             .context("failed to get outgoing response parameter")?;
-        let _ = param.insert(response);
+        let Some(tx) = tx.take() else {
+            return Ok(Err(()));
+        };
+        let response = match response {
+            Ok(response) => {
+                let OutgoingResponse {
+                    status_code,
+                    headers,
+                    body,
+                    sent,
+                    ..
+                } = self
+                    .table
+                    .get_outgoing_response_mut(response)
+                    .context("failed to get outgoing response")?;
+                let Some((body_rx, trailers)) = sent.take() else {
+                    return Ok(Err(()));
+                };
+                let status_code = *status_code;
+                let headers = *headers;
+                self.http_streams.response_bodies.push(body.clone());
+                let headers = self
+                    .table
+                    .get_fields(headers)
+                    .context("failed to get headers")?;
+                fields_to_headers(headers).and_then(|headers| {
+                    let mut res = http::Response::builder()
+                        .status(status_code)
+                        .extension(trailers)
+                        .body(Box::new(body_rx) as Box<dyn AsyncRead + Sync + Send + Unpin>)
+                        .context("failed to create response")?;
+                    *res.headers_mut() = headers;
+                    Ok(res)
+                })
+            }
+            Err(types::Error::InvalidUrl(err)) => Err(anyhow!(err).context("invalid URL")),
+            Err(types::Error::TimeoutError(err)) => Err(anyhow!(err).context("timeout")),
+            Err(types::Error::ProtocolError(err)) => Err(anyhow!(err).context("protocol error")),
+            Err(types::Error::UnexpectedError(err)) => {
+                Err(anyhow!(err).context("unexpected error"))
+            }
+        };
+        // The receiver is dropped if the host is no longer interested in the response
+        let _ = tx.send(response);
         Ok(Ok(()))
     }
 
-    #[allow(unused)] // TODO: Remove
     async fn drop_incoming_response(
         &mut self,
         response: types::IncomingResponse,
     ) -> anyhow::Result<()> {
-        bail!("outgoing HTTP not supported yet")
+        self.table
+            .delete_incoming_response(response)
+            .context("failed to delete incoming response")?;
+        Ok(())
     }
     async fn drop_outgoing_response(
         &mut self,
@@ -477,38 +1054,58 @@ impl types::Host for Ctx {
             .context("failed to delete outgoing response")?;
         Ok(())
     }
-    #[allow(unused)] // TODO: Remove
     async fn incoming_response_status(
         &mut self,
         response: types::IncomingResponse,
     ) -> anyhow::Result<types::StatusCode> {
-        bail!("outgoing HTTP not supported yet")
+        let IncomingResponse { status_code, .. } = self
+            .table
+            .get_incoming_response(response)
+            .context("failed to get incoming response")?;
+        Ok(*status_code)
     }
-    #[allow(unused)] // TODO: Remove
     async fn incoming_response_headers(
         &mut self,
         response: types::IncomingResponse,
     ) -> anyhow::Result<types::Headers> {
-        bail!("outgoing HTTP not supported yet")
+        let IncomingResponse { headers, .. } = self
+            .table
+            .get_incoming_response(response)
+            .context("failed to get incoming response")?;
+        Ok(*headers)
     }
-    #[allow(unused)] // TODO: Remove
     async fn incoming_response_consume(
         &mut self,
         response: types::IncomingResponse,
     ) -> anyhow::Result<Result<types::IncomingStream, ()>> {
-        bail!("outgoing HTTP not supported yet")
+        let IncomingResponse { body, trailers, .. } = self
+            .table
+            .delete_incoming_response(response)
+            .context("failed to delete incoming response")?;
+        let stream = self
+            .table
+            .push_input_stream(Box::new(AsyncReadStream::new(body)))
+            .context("failed to push input stream")?;
+        if let Some(trailers) = trailers {
+            self.http_streams.incoming_trailers.insert(stream, trailers);
+        }
+        Ok(Ok(stream))
     }
     async fn new_outgoing_response(
         &mut self,
         status_code: types::StatusCode,
         headers: types::Headers,
     ) -> anyhow::Result<Result<types::OutgoingResponse, types::Error>> {
+        let (body, body_rx) = tokio::io::duplex(RESPONSE_BODY_BUFFER_SIZE);
+        let (trailers_tx, trailers) = HttpTrailers::channel();
         let response = self
             .table
             .push_outgoing_response(OutgoingResponse {
                 status_code,
                 headers,
-                body: AsyncVec::default(),
+                body: BodyWriter(Arc::new(std::sync::Mutex::new(Some(body)))),
+                trailers: Some(trailers_tx),
+                sent: Some((body_rx, trailers)),
             })
             .context("failed to push fields")?;
         Ok(Ok(response))
@@ -517,40 +1114,187 @@ impl types::Host for Ctx {
         &mut self,
         response: types::OutgoingResponse,
     ) -> anyhow::Result<Result<types::OutgoingStream, ()>> {
-        let OutgoingResponse { body, .. } = self
+        let OutgoingResponse { body, trailers, .. } = self
             .table
-            .get_outgoing_response(response)
+            .get_outgoing_response_mut(response)
             .context("failed to get outgoing response")?;
+        let Some(trailers) = trailers.take() else {
+            return Ok(Err(()));
+        };
+        let body = body.clone();
         let stream = self
             .table
-            .push_output_stream(Box::new(AsyncWriteStream::new(1 << 16, body.clone())))
+            .push_output_stream(Box::new(AsyncWriteStream::new(1 << 16, body)))
             .context("failed to push output stream")?;
+        self.http_streams.outgoing_trailers.insert(stream, trailers);
         Ok(Ok(stream))
     }
 
-    #[allow(unused)] // TODO: Remove
     async fn drop_future_incoming_response(
         &mut self,
         f: types::FutureIncomingResponse,
     ) -> anyhow::Result<()> {
-        bail!("outgoing HTTP not supported yet")
+        if let FutureIncomingResponse::Pending(task) = self
+            .table
+            .delete_future_incoming_response(f)
+            .context("failed to delete future incoming response")?
+        {
+            task.abort();
+        }
+        Ok(())
     }
-    #[allow(unused)] // TODO: Remove
     async fn future_incoming_response_get(
         &mut self,
         f: types::FutureIncomingResponse,
     ) -> anyhow::Result<Option<Result<types::IncomingResponse, types::Error>>> {
-        bail!("outgoing HTTP not supported yet")
+        let res = self
+            .table
+            .get_future_incoming_response_mut(f)
+            .context("failed to get future incoming response")?
+            .take()?;
+        let response = match res {
+            None => return Ok(None),
+            Some(Err(err)) => return Ok(Some(Err(err))),
+            Some(Ok(response)) => response,
+        };
+        let (
+            http::response::Parts {
+                status,
+                headers,
+                mut extensions,
+                ..
+            },
+            body,
+        ) = response.into_parts();
+        let headers = self
+            .table
+            .push_fields(headers_to_fields(&headers))
+            .context("failed to push headers")?;
+        let response = self
+            .table
+            .push_incoming_response(IncomingResponse {
+                status_code: status.as_u16(),
+                headers,
+                body,
+                trailers: extensions.remove(),
+            })
+            .context("failed to push incoming response")?;
+        Ok(Some(Ok(response)))
     }
-    #[allow(unused)] // TODO: Remove
     async fn listen_to_future_incoming_response(
         &mut self,
         f: types::FutureIncomingResponse,
     ) -> anyhow::Result<types::Pollable> {
-        bail!("outgoing HTTP not supported yet")
+        self.table
+            .get_future_incoming_response_mut(f)
+            .context("failed to get future incoming response")?;
+        self.table
+            .push_host_pollable(HostPollable::TableEntry {
+                index: f,
+                make_future: future_incoming_response_ready,
+            })
+            .context("failed to push pollable")
+    }
+}
+
+#[async_trait]
+impl outgoing_handler::Host for Ctx {
+    #[instrument]
+    async fn handle(
+        &mut self,
+        request: types::OutgoingRequest,
+        options: Option<types::RequestOptions>,
+    ) -> anyhow::Result<types::FutureIncomingResponse> {
+        let OutgoingRequest {
+            method,
+            path_with_query,
+            scheme,
+            authority,
+            headers,
+            mut body,
+            trailers,
+            ..
+        } = self
+            .table
+            .delete_outgoing_request(request)
+            .context("failed to delete outgoing request")?;
+        let headers = self
+            .table
+            .get_fields(headers)
+            .context("failed to get headers")?
+            .clone();
+        body.rewind()
+            .await
+            .context("failed to rewind request body")?;
+        let f = match build_outgoing_request(
+            method,
+            path_with_query.as_deref(),
+            scheme,
+            authority,
+            headers,
+            Box::new(body),
+        ) {
+            Ok(mut request) => {
+                request.extensions_mut().insert(trailers);
+                let handler = self.handler.clone();
+                FutureIncomingResponse::Pending(tokio::spawn(async move {
+                    OutgoingHttp::handle(&handler, request, options)
+                        .await
+                        .map_err(|err| types::Error::UnexpectedError(format!("{err:#}")))
+                }))
+            }
+            Err(err) => FutureIncomingResponse::Ready(Err(err)),
+        };
+        self.table
+            .push_future_incoming_response(f)
+            .context("failed to push future incoming response")
     }
 }
 
+/// Constructs an [`http::Request`] from the parts of a guest outgoing request
+fn build_outgoing_request(
+    method: types::Method,
+    path_with_query: Option<&str>,
+    scheme: Option<types::Scheme>,
+    authority: Option<String>,
+    headers: BTreeMap<String, Vec<Vec<u8>>>,
+    body: Box<dyn AsyncRead + Sync + Send + Unpin>,
+) -> Result<http::Request<Box<dyn AsyncRead + Sync + Send + Unpin>>, types::Error> {
+    let method = match method {
+        types::Method::Get => http::Method::GET,
+        types::Method::Head => http::Method::HEAD,
+        types::Method::Post => http::Method::POST,
+        types::Method::Put => http::Method::PUT,
+        types::Method::Delete => http::Method::DELETE,
+        types::Method::Connect => http::Method::CONNECT,
+        types::Method::Options => http::Method::OPTIONS,
+        types::Method::Trace => http::Method::TRACE,
+        types::Method::Patch => http::Method::PATCH,
+        types::Method::Other(method) => http::Method::from_bytes(method.as_bytes())
+            .map_err(|err| types::Error::ProtocolError(format!("invalid method: {err}")))?,
+    };
+    let scheme = match scheme {
+        None | Some(types::Scheme::Https) => "https".into(),
+        Some(types::Scheme::Http) => "http".into(),
+        Some(types::Scheme::Other(scheme)) => scheme,
+    };
+    let authority =
+        authority.ok_or_else(|| types::Error::InvalidUrl("authority missing".into()))?;
+    let uri = http::Uri::builder()
+        .scheme(scheme.as_str())
+        .authority(authority.as_str())
+        .path_and_query(path_with_query.unwrap_or("/"))
+        .build()
+        .map_err(|err| types::Error::InvalidUrl(err.to_string()))?;
+    let req = http::Request::builder().method(method).uri(uri);
+    let req = headers
+        .into_iter()
+        .flat_map(|(name, values)| values.into_iter().map(move |value| (name.clone(), value)))
+        .fold(req, |req, (name, value)| req.header(name, value));
+    req.body(body)
+        .map_err(|err| types::Error::ProtocolError(format!("invalid request: {err}")))
+}
+
 impl Instance {
     /// Set [`IncomingHttp`] handler for this [Instance].
     pub fn incoming_http(
@@ -561,6 +1305,50 @@ impl Instance {
         self
     }
 
+    /// Set [`OutgoingHttp`] handler for this [Instance].
+    pub fn outgoing_http(
+        &mut self,
+        outgoing_http: Arc<dyn OutgoingHttp + Send + Sync>,
+    ) -> &mut Self {
+        self.handler_mut().replace_outgoing_http(outgoing_http);
+        self
+    }
+
+    /// Instantiates and returns incoming HTTP bindings, falling back to guest bindings if
+    /// `wasi:http/incoming-handler` is not exported by the [`Instance`].
+    async fn incoming_http_bindings(
+        &mut self,
+    ) -> anyhow::Result<InterfaceBindings<incoming_http_bindings::IncomingHttp>> {
+        if let Some(bindings) = self.incoming_http.take() {
+            return Ok(bindings);
+        }
+        // Instantiation runs guest code, e.g. start functions, so it is subject to the limits
+        apply_limits(&mut self.store)?;
+        match incoming_http_bindings::IncomingHttp::instantiate_async(
+            &mut self.store,
+            &self.component,
+            &self.linker,
+        )
+        .await
+        {
+            Ok((bindings, _)) => return Ok(InterfaceBindings::Interface(bindings)),
+            Err(err) if is_timeout(&err) => {
+                return Err(
+                    err.context("failed to instantiate `wasi:http/incoming-handler` interface")
+                )
+            }
+            Err(_) => {}
+        }
+        let bindings = if let Some(bindings) = self.guest.take() {
+            bindings
+        } else {
+            self.as_guest_bindings()
+                .await
+                .context("failed to instantiate `wasi:http/incoming-handler` interface")?
+        };
+        Ok(InterfaceBindings::Guest(bindings))
+    }
+
     /// Instantiates and returns a [`InterfaceInstance<incoming_http_bindings::IncomingHttp>`] if exported by the [`Instance`].
     ///
     /// # Errors
@@ -569,38 +1357,87 @@ impl Instance {
     pub async fn into_incoming_http(
         mut self,
     ) -> anyhow::Result<InterfaceInstance<incoming_http_bindings::IncomingHttp>> {
-        let bindings = if let Ok((bindings, _)) =
-            incoming_http_bindings::IncomingHttp::instantiate_async(
-                &mut self.store,
-                &self.component,
-                &self.linker,
-            )
-            .await
-        {
-            InterfaceBindings::Interface(bindings)
-        } else {
-            self.as_guest_bindings()
-                .await
-                .map(InterfaceBindings::Guest)
-                .context("failed to instantiate `wasi:http/incoming-handler` interface")?
-        };
+        let bindings = self.incoming_http_bindings().await?;
         Ok(InterfaceInstance {
             store: Mutex::new(self.store),
             bindings,
         })
     }
-}
 
-#[async_trait]
-impl IncomingHttp for InterfaceInstance<incoming_http_bindings::IncomingHttp> {
-    #[allow(unused)] // TODO: Remove
+    /// Handle an incoming HTTP request using this [`Instance`].
+    /// Bindings are instantiated on first call and reused by subsequent calls.
+    ///
+    /// # Errors
+    ///
+    /// Fails if incoming HTTP bindings are not exported by the [`Instance`] or handling the request fails
+    #[instrument(skip_all)]
+    pub async fn handle_incoming_http(
+        &mut self,
+        request: http::Request<Box<dyn AsyncRead + Sync + Send + Unpin>>,
+    ) -> anyhow::Result<http::Response<Box<dyn AsyncRead + Sync + Send + Unpin>>> {
+        let bindings = self.incoming_http_bindings().await?;
+        let res = bindings.handle(&mut self.store, request).await;
+        self.incoming_http = Some(bindings);
+        res
+    }
+
+    /// Handle an incoming HTTP request using this [`Instance`], sending the response on
+    /// `response_tx` as soon as it is set and streaming its body until the invocation returns.
+    ///
+    /// # Errors
+    ///
+    /// Fails if incoming HTTP bindings are not exported by the [`Instance`] or handling the request fails
     #[instrument(skip_all)]
+    pub(crate) async fn handle_incoming_http_streaming(
+        &mut self,
+        request: http::Request<Box<dyn AsyncRead + Sync + Send + Unpin>>,
+        response_tx: oneshot::Sender<IncomingHttpResponse>,
+    ) -> anyhow::Result<()> {
+        let bindings = self.incoming_http_bindings().await?;
+        let res = bindings
+            .handle_streaming(&mut self.store, request, response_tx)
+            .await;
+        self.incoming_http = Some(bindings);
+        res
+    }
+}
+
+impl InterfaceBindings<incoming_http_bindings::IncomingHttp> {
+    /// Handles an incoming HTTP request, buffering the response body.
     async fn handle(
         &self,
+        store: &mut wasmtime::Store<Ctx>,
         request: http::Request<Box<dyn AsyncRead + Sync + Send + Unpin>>,
-    ) -> anyhow::Result<http::Response<Box<dyn AsyncRead + Sync + Send + Unpin>>> {
-        let mut store = self.store.lock().await;
-        match &self.bindings {
+    ) -> IncomingHttpResponse {
+        let (response_tx, response_rx) = oneshot::channel();
+        // The response body must be read concurrently with the invocation, since the guest may
+        // write more than fits into the body pipe buffer
+        let (res, response) = join!(self.handle_streaming(store, request, response_tx), async {
+            let Ok(response) = response_rx.await else {
+                return Ok(None);
+            };
+            let (parts, mut body) = response?.into_parts();
+            let mut buf = vec![];
+            body.read_to_end(&mut buf)
+                .await
+                .context("failed to read response body")?;
+            let body: Box<dyn AsyncRead + Sync + Send + Unpin> = Box::new(Cursor::new(buf));
+            anyhow::Ok(Some(http::Response::from_parts(parts, body)))
+        });
+        res?;
+        response?.context("response not set")
+    }
+
+    /// Handles an incoming HTTP request, sending the response on `response_tx` as soon as it is
+    /// set by the guest. The response body is streamed until the guest returns.
+    #[allow(clippy::too_many_lines)]
+    async fn handle_streaming(
+        &self,
+        store: &mut wasmtime::Store<Ctx>,
+        request: http::Request<Box<dyn AsyncRead + Sync + Send + Unpin>>,
+        response_tx: oneshot::Sender<IncomingHttpResponse>,
+    ) -> anyhow::Result<()> {
+        match self {
             InterfaceBindings::Guest(guest) => {
                 let request = wasmcloud_compat::HttpRequest::from_http(request)
                     .await
@@ -610,7 +1447,7 @@ impl IncomingHttp for InterfaceInstance<incoming_http_bindings::IncomingHttp> {
                 let mut response = AsyncVec::default();
                 match guest
                     .call(
-                        &mut store,
+                        store,
                         "HttpServer.HandleRequest",
                         Cursor::new(request),
                         response.clone(),
@@ -628,21 +1465,25 @@ impl IncomingHttp for InterfaceInstance<incoming_http_bindings::IncomingHttp> {
                                 .context("failed to parse response")?;
                         let response: http::Response<_> =
                             response.try_into().context("failed to convert response")?;
-                        Ok(
+                        let response =
                             response.map(|body| -> Box<dyn AsyncRead + Send + Sync + Unpin> {
                                 Box::new(Cursor::new(body))
-                            }),
-                        )
+                            });
+                        // The receiver is dropped if the host is no longer interested in the response
+                        let _ = response_tx.send(Ok(response));
+                        Ok(())
                     }
                     Err(err) => bail!(err),
                 }
             }
             InterfaceBindings::Interface(bindings) => {
+                apply_limits(store)?;
                 let (
                     http::request::Parts {
                         method,
                         uri,
                         headers,
+                        mut extensions,
                         ..
                     },
                     body,
@@ -666,86 +1507,111 @@ impl IncomingHttp for InterfaceInstance<incoming_http_bindings::IncomingHttp> {
                     other => types::Scheme::Other(other.to_string()),
                 });
                 let authority = uri.authority().map(http::uri::Authority::as_str);
-                let mut data = store.data_mut();
-                let headers = headers
-                    .into_iter()
-                    .map(|(name, value)| {
-                        let name = name.context("invalid header name")?;
-                        Ok((name.as_str().into(), vec![value.as_bytes().into()]))
-                    })
-                    .collect::<anyhow::Result<_>>()
-                    .context("failed to parse headers")?;
+                let data = store.data_mut();
                 let headers = data
                     .table
-                    .push_fields(headers)
+                    .push_fields(headers_to_fields(&headers))
                     .context("failed to push headers")?;
                 let request = data
                     .table
-                    .push(Box::new(IncomingRequest {
+                    .push_incoming_request(IncomingRequest {
                         method,
                         path_with_query: path_with_query.map(Into::into),
                         scheme,
                         authority: authority.map(Into::into),
                         body,
                         headers,
-                    }))
+                        trailers: extensions.remove(),
+                    })
                     .context("failed to push request to table")?;
                 let response = data
                     .table
-                    .new_response_outparam()
+                    .new_response_outparam(response_tx)
                     .context("failed to push response to table")?;
-                bindings
+                let res = bindings
                     .wasi_http_incoming_handler()
                     .call_handle(&mut *store, request, response)
-                    .await?;
+                    .await;
                 let data = store.data_mut();
-                match data
-                    .table
+                data.http_streams.close();
+                // Dropping the sender notifies the receiver if the response was never set
+                data.table
                     .delete_response_outparam(response)
-                    .context("failed to delete outgoing response parameter")?
-                {
-                    None => bail!("response not set"),
-                    Some(Ok(response)) => {
-                        let OutgoingResponse {
-                            status_code,
-                            headers,
-                            mut body,
-                        } = data
-                            .table
-                            .delete_outgoing_response(response)
-                            .context("failed to delete outgoing response")?;
-                        let headers = data
-                            .table
-                            .delete_fields(headers)
-                            .context("failed to delete headers")?;
-                        let res = http::Response::builder().status(status_code);
-                        let res = headers.into_iter().fold(res, |res, (name, mut values)| {
-                            if let Some(value) = values.pop() {
-                                res.header(name, value)
-                            } else {
-                                res
-                            }
-                        });
-                        body.rewind()
-                            .await
-                            .context("failed to rewind response body")?;
-                        let body: Box<dyn AsyncRead + Send + Sync + Unpin> = Box::new(body);
-                        res.body(body).context("failed to create response")
-                    }
-                    Some(Err(types::Error::InvalidUrl(err))) => {
-                        Err(anyhow!(err).context("invalid URL"))
-                    }
-                    Some(Err(types::Error::TimeoutError(err))) => {
-                        Err(anyhow!(err).context("timeout"))
-                    }
-                    Some(Err(types::Error::ProtocolError(err))) => {
-                        Err(anyhow!(err).context("protocol error"))
-                    }
-                    Some(Err(types::Error::UnexpectedError(err))) => {
-                        Err(anyhow!(err).context("unexpected error"))
-                    }
-                }
+                    .context("failed to delete outgoing response parameter")?;
+                res
             }
         }
     }
 }
+
+#[async_trait]
+impl IncomingHttp for InterfaceInstance<incoming_http_bindings::IncomingHttp> {
+    #[instrument(skip_all)]
+    async fn handle(
+        &self,
+        request: http::Request<Box<dyn AsyncRead + Sync + Send + Unpin>>,
+    ) -> anyhow::Result<http::Response<Box<dyn AsyncRead + Sync + Send + Unpin>>> {
+        let mut store = self.store.lock().await;
+        self.bindings.handle(&mut store, request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tokio::io::{duplex, AsyncWriteExt};
+
+    #[tokio::test]
+    async fn body_writer() -> anyhow::Result<()> {
+        let (w, mut r) = duplex(16);
+        let mut body = BodyWriter(Arc::new(std::sync::Mutex::new(Some(w))));
+        body.write_all(b"foo").await?;
+        body.clone().close();
+
+        // buffered contents remain readable once the writer is closed
+        let mut buf = vec![];
+        r.read_to_end(&mut buf).await?;
+        assert_eq!(buf, b"foo");
+
+        let err = body
+            .write_all(b"bar")
+            .await
+            .expect_err("write to a closed body should fail");
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+        Ok(())
+    }
+
+    #[test]
+    fn future_trailers() -> anyhow::Result<()> {
+        let (tx, trailers) = HttpTrailers::channel();
+        let mut trailers = FutureTrailers::Pending(trailers);
+        assert!(trailers.take()?.is_none());
+
+        let mut headers = http::HeaderMap::new();
+        headers.insert("foo", http::HeaderValue::from_static("bar"));
+        tx.send(headers.clone())
+            .map_err(|_| anyhow!("trailers dropped"))?;
+        assert_eq!(trailers.take()?, Some(headers));
+        assert!(trailers.take().is_err());
+
+        // dropping the sender completes the body without trailers
+        let (tx, trailers) = HttpTrailers::channel();
+        let mut trailers = FutureTrailers::Pending(trailers);
+        drop(tx);
+        assert_eq!(trailers.take()?, Some(http::HeaderMap::new()));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn future_trailers_ready() -> anyhow::Result<()> {
+        let (tx, trailers) = HttpTrailers::channel();
+        let mut trailers = FutureTrailers::Pending(trailers);
+        let headers = http::HeaderMap::new();
+        let sent = headers.clone();
+        tokio::spawn(async move { tx.send(sent) });
+        trailers.ready().await;
+        assert_eq!(trailers.take()?, Some(headers));
+        Ok(())
+    }
+}
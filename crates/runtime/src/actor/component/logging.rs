@@ -1,4 +1,4 @@
-use super::{Ctx, Instance, InterfaceBindings, InterfaceInstance};
+use super::{apply_limits, Ctx, Instance, InterfaceBindings, InterfaceInstance};
 
 use crate::capability::logging::logging;
 use crate::capability::Logging;
@@ -120,6 +120,7 @@ impl Logging for InterfaceInstance<logging_bindings::Logging> {
                     logging::Level::Error => Level::Error,
                     logging::Level::Critical => Level::Critical,
                 };
+                apply_limits(&mut store)?;
                 trace!("call `wasi:logging/logging.log`");
                 bindings
                     .wasi_logging_logging()
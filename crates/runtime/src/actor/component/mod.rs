@@ -1,6 +1,6 @@
-use crate::actor::claims;
+use crate::actor::{claims, is_timeout};
 use crate::capability::{builtin, Interfaces};
-use crate::Runtime;
+use crate::{ActorConfig, ActorLimits, Runtime};
 
 use core::fmt::{self, Debug};
 use core::mem::replace;
@@ -160,12 +160,23 @@ impl HostOutputStream for StdioStream<Box<dyn HostOutputStream>> {
 }
 
 struct Ctx {
+    actor_config: ActorConfig,
+    limits: wasmtime::StoreLimits,
     wasi: preview2::WasiCtx,
     table: preview2::Table,
     handler: builtin::Handler,
     stdin: StdioStream<Box<dyn HostInputStream>>,
     stdout: StdioStream<Box<dyn HostOutputStream>>,
     stderr: StdioStream<Box<dyn HostOutputStream>>,
+    http_streams: http::HttpStreams,
+}
+
+/// Applies execution limits configured for the actor to the [`wasmtime::Store`]
+fn apply_limits(store: &mut wasmtime::Store<Ctx>) -> anyhow::Result<()> {
+    let actor_config = store.data().actor_config.clone();
+    actor_config
+        .apply_limits(store)
+        .context("failed to apply execution limits")
 }
 
 impl preview2::WasiView for Ctx {
@@ -195,6 +206,7 @@ impl Debug for Ctx {
 /// Pre-compiled actor [Component], which is cheapily-[Cloneable](Clone)
 #[derive(Clone)]
 pub struct Component {
+    actor_config: ActorConfig,
     component: wasmtime::component::Component,
     engine: wasmtime::Engine,
     claims: Option<jwt::Claims<jwt::Actor>>,
@@ -204,6 +216,7 @@ pub struct Component {
 impl Debug for Component {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Component")
+            .field("actor_config", &self.actor_config)
             .field("claims", &self.claims)
             .field("handler", &self.handler)
             .field("runtime", &"wasmtime")
@@ -214,6 +227,7 @@ impl Debug for Component {
 fn instantiate(
     engine: &wasmtime::Engine,
     component: wasmtime::component::Component,
+    actor_config: ActorConfig,
     handler: impl Into<builtin::Handler>,
 ) -> anyhow::Result<Instance> {
     let mut linker = wasmtime::component::Linker::new(engine);
@@ -238,18 +252,25 @@ fn instantiate(
         .context("failed to build WASI")?;
     let handler = handler.into();
     let ctx = Ctx {
+        limits: actor_config.limits.store_limits(),
+        actor_config,
         wasi,
         table,
         handler,
         stdin,
         stdout,
         stderr,
+        http_streams: http::HttpStreams::default(),
     };
-    let store = wasmtime::Store::new(engine, ctx);
+    let mut store = wasmtime::Store::new(engine, ctx);
+    store.limiter(|ctx| &mut ctx.limits);
+    apply_limits(&mut store)?;
     Ok(Instance {
         component,
         linker,
         store,
+        guest: None,
+        incoming_http: None,
     })
 }
 
@@ -260,9 +281,14 @@ impl Component {
         let wasm = wasm.as_ref();
         let engine = rt.engine.clone();
         let claims = claims(wasm)?;
-        let component = wasmtime::component::Component::new(&engine, wasm)
-            .context("failed to compile component")?;
+        let component = if let Some(cache) = &rt.artifact_cache {
+            cache.component(&engine, wasm)
+        } else {
+            wasmtime::component::Component::new(&engine, wasm)
+        }
+        .context("failed to compile component")?;
         Ok(Self {
+            actor_config: rt.actor_config.clone(),
             component,
             engine,
             claims,
@@ -276,6 +302,14 @@ impl Component {
         self.claims.as_ref()
     }
 
+    /// Overrides the [`ActorLimits`] configured for the [Runtime] for all instances of this [Component]
+    /// created from now on.
+    #[must_use]
+    pub fn with_limits(mut self, limits: ActorLimits) -> Self {
+        self.actor_config.limits = limits;
+        self
+    }
+
     /// Like [Self::instantiate], but moves the [Component].
     #[instrument]
     pub fn into_instance(self) -> anyhow::Result<Instance> {
@@ -287,14 +321,24 @@ impl Component {
     pub fn into_instance_claims(
         self,
     ) -> anyhow::Result<(Instance, Option<jwt::Claims<jwt::Actor>>)> {
-        let instance = instantiate(&self.engine, self.component, self.handler)?;
+        let instance = instantiate(
+            &self.engine,
+            self.component,
+            self.actor_config,
+            self.handler,
+        )?;
         Ok((instance, self.claims))
     }
 
     /// Instantiates a [Component] and returns the resulting [Instance].
     #[instrument]
     pub fn instantiate(&self) -> anyhow::Result<Instance> {
-        instantiate(&self.engine, self.component.clone(), self.handler.clone())
+        instantiate(
+            &self.engine,
+            self.component.clone(),
+            self.actor_config.clone(),
+            self.handler.clone(),
+        )
     }
 
     /// Instantiates a [Component] producing an [Instance] and invokes an operation on it using [Instance::call]
@@ -323,6 +367,10 @@ pub struct Instance {
     component: wasmtime::component::Component,
     linker: wasmtime::component::Linker<Ctx>,
     store: wasmtime::Store<Ctx>,
+    /// Guest bindings instantiated by a previous call, if any
+    guest: Option<GuestBindings>,
+    /// Incoming HTTP bindings instantiated by a previous call, if any
+    incoming_http: Option<InterfaceBindings<incoming_http_bindings::IncomingHttp>>,
 }
 
 impl Debug for Instance {
@@ -368,6 +416,8 @@ impl Instance {
 
     /// Instantiates and returns [`GuestBindings`] if exported by the [`Instance`].
     async fn as_guest_bindings(&mut self) -> anyhow::Result<GuestBindings> {
+        // Instantiation runs guest code, e.g. start functions, so it is subject to the limits
+        apply_limits(&mut self.store)?;
         // Attempt to instantiate using guest bindings
         let guest_err = match guest_bindings::Guest::instantiate_async(
             &mut self.store,
@@ -377,6 +427,9 @@ impl Instance {
         .await
         {
             Ok((bindings, _)) => return Ok(GuestBindings::Interface(bindings)),
+            Err(e) if is_timeout(&e) => {
+                return Err(e.context("failed to instantiate `wasmcloud:bus/guest` interface"))
+            }
             Err(e) => e,
         };
 
@@ -396,6 +449,7 @@ impl Instance {
     }
 
     /// Invoke an operation on an [Instance] producing a result.
+    /// Guest bindings are instantiated on first call and reused by subsequent calls.
     #[instrument(skip_all)]
     pub async fn call(
         &mut self,
@@ -403,10 +457,16 @@ impl Instance {
         request: impl AsyncRead + Send + Sync + Unpin + 'static,
         response: impl AsyncWrite + Send + Sync + Unpin + 'static,
     ) -> anyhow::Result<Result<(), String>> {
-        self.as_guest_bindings()
-            .await?
+        let bindings = if let Some(bindings) = self.guest.take() {
+            bindings
+        } else {
+            self.as_guest_bindings().await?
+        };
+        let res = bindings
             .call(&mut self.store, operation, request, response)
-            .await
+            .await;
+        self.guest = Some(bindings);
+        res
     }
 
     /// Instantiates and returns a [`GuestInstance`] if exported by the [`Instance`].
@@ -415,7 +475,11 @@ impl Instance {
     ///
     /// Fails if guest bindings are not exported by the [`Instance`]
     pub async fn into_guest(mut self) -> anyhow::Result<GuestInstance> {
-        let bindings = self.as_guest_bindings().await?;
+        let bindings = if let Some(bindings) = self.guest.take() {
+            bindings
+        } else {
+            self.as_guest_bindings().await?
+        };
         Ok(GuestInstance {
             store: Arc::new(Mutex::new(self.store)),
             bindings: Arc::new(bindings),
@@ -438,6 +502,7 @@ impl GuestBindings {
         request: impl AsyncRead + Send + Sync + Unpin + 'static,
         response: impl AsyncWrite + Send + Sync + Unpin + 'static,
     ) -> anyhow::Result<Result<(), String>> {
+        apply_limits(store)?;
         let ctx = store.data_mut();
         ctx.stdin
             .replace(Box::new(AsyncReadStream::new(request)))
@@ -517,3 +582,100 @@ pub struct InterfaceInstance<T> {
     store: Mutex<wasmtime::Store<Ctx>>,
     bindings: InterfaceBindings<T>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::time::Duration;
+
+    use tokio::io::{empty, sink};
+    use tokio::time::{sleep, timeout};
+
+    /// Component, which loops forever on instantiation
+    const LOOP: &str = r"(component
+        (core module $m
+            (func $start (loop $loop (br $loop)))
+            (start $start)
+        )
+        (core instance (instantiate $m))
+    )";
+
+    /// Component, which does nothing on instantiation and exports no bindings
+    const NOOP: &str = r"(component
+        (core module $m
+            (func $start)
+            (start $start)
+        )
+        (core instance (instantiate $m))
+    )";
+
+    /// Returns the [Runtime] along with the [Component], since the execution time of actors is
+    /// only tracked as long as the [Runtime] is alive
+    fn component(actor_config: ActorConfig, wat: &str) -> anyhow::Result<(Runtime, Component)> {
+        let rt = Runtime::builder().actor_config(actor_config).build()?;
+        let component = Component::new(&rt, wat::parse_str(wat)?)?;
+        Ok((rt, component))
+    }
+
+    #[tokio::test]
+    async fn fuel() -> anyhow::Result<()> {
+        let (_rt, component) = component(
+            ActorConfig {
+                max_fuel: Some(10_000),
+                ..Default::default()
+            },
+            LOOP,
+        )?;
+        let mut instance = component.instantiate()?;
+        let err = timeout(
+            Duration::from_secs(5),
+            instance.call("foo", empty(), sink()),
+        )
+        .await?
+        .expect_err("instantiation should run out of fuel");
+        assert!(is_timeout(&err), "{err:?}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execution_time() -> anyhow::Result<()> {
+        let (_rt, component) = component(
+            ActorConfig {
+                max_execution_time: Some(Duration::from_millis(50)),
+                ..Default::default()
+            },
+            LOOP,
+        )?;
+        let mut instance = component.instantiate()?;
+        let err = timeout(
+            Duration::from_secs(5),
+            instance.call("foo", empty(), sink()),
+        )
+        .await?
+        .expect_err("instantiation should be interrupted");
+        assert!(is_timeout(&err), "{err:?}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execution_time_from_instantiation() -> anyhow::Result<()> {
+        let (_rt, component) = component(
+            ActorConfig {
+                max_execution_time: Some(Duration::from_millis(20)),
+                ..Default::default()
+            },
+            NOOP,
+        )?;
+        let mut instance = component.instantiate()?;
+        // bindings are instantiated lazily, the deadline must not be counted from the
+        // creation of the instance
+        sleep(Duration::from_millis(100)).await;
+        let err = instance
+            .call("foo", empty(), sink())
+            .await
+            .expect_err("component does not export bindings");
+        assert!(!is_timeout(&err), "{err:?}");
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 mod component;
 mod module;
+mod pool;
 
 pub use component::{
     Component, GuestInstance as ComponentGuestInstance, Instance as ComponentInstance,
@@ -9,20 +10,25 @@ pub use module::{
     Config as ModuleConfig, GuestInstance as ModuleGuestInstance, Instance as ModuleInstance,
     Module,
 };
+pub use pool::{InstancePool, PooledInstance};
 
 use crate::capability::logging::logging;
 use crate::capability::{
     Blobstore, Bus, IncomingHttp, KeyValueAtomic, KeyValueReadWrite, Logging, Messaging,
+    OutgoingHttp,
 };
+use crate::runtime::EPOCH_INTERVAL;
 use crate::Runtime;
 
 use core::fmt::Debug;
+use core::time::Duration;
 
 use std::sync::Arc;
 
 use anyhow::{ensure, Context, Result};
 use async_trait::async_trait;
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite};
+use tokio::sync::oneshot;
 use tracing::instrument;
 use wascap::jwt;
 use wascap::wasm::extract_claims;
@@ -32,6 +38,74 @@ use wascap::wasm::extract_claims;
 pub struct Config {
     /// Whether actors are required to be signed to be executed
     pub require_signature: bool,
+    /// Amount of fuel a single actor invocation is allowed to consume, unlimited if `None`
+    pub max_fuel: Option<u64>,
+    /// Wall-clock time a single actor invocation is allowed to run for, unlimited if `None`
+    pub max_execution_time: Option<Duration>,
+    /// Limits on resources a single actor instance is allowed to allocate
+    pub limits: Limits,
+}
+
+/// Limits on resources a single actor instance is allowed to allocate, applied uniformly to
+/// modules and components
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Limits {
+    /// Maximum size, in bytes, of each linear memory, unlimited if `None`
+    pub max_memory_bytes: Option<usize>,
+    /// Maximum amount of elements in each table, unlimited if `None`
+    pub max_table_elements: Option<u32>,
+    /// Maximum amount of WebAssembly instances, unlimited if `None`
+    pub max_instances: Option<usize>,
+}
+
+impl Limits {
+    /// Returns [`wasmtime::StoreLimits`] enforcing these [Limits]
+    pub(crate) fn store_limits(self) -> wasmtime::StoreLimits {
+        let mut limits = wasmtime::StoreLimitsBuilder::new();
+        if let Some(max_memory_bytes) = self.max_memory_bytes {
+            limits = limits.memory_size(max_memory_bytes);
+        }
+        if let Some(max_table_elements) = self.max_table_elements {
+            limits = limits.table_elements(max_table_elements);
+        }
+        if let Some(max_instances) = self.max_instances {
+            limits = limits.instances(max_instances);
+        }
+        limits.build()
+    }
+}
+
+impl Config {
+    /// Configures execution limits of a [`wasmtime::Store`] prior to executing guest code
+    pub(crate) fn apply_limits<T>(&self, store: &mut wasmtime::Store<T>) -> Result<()> {
+        if let Some(fuel) = self.max_fuel {
+            let remaining = store.fuel_remaining().unwrap_or_default();
+            if let Some(fuel) = fuel.checked_sub(remaining) {
+                store.add_fuel(fuel).context("failed to add fuel")?;
+            } else {
+                store
+                    .consume_fuel(remaining - fuel)
+                    .context("failed to consume fuel")?;
+            }
+        }
+        if let Some(max_execution_time) = self.max_execution_time {
+            let ticks = max_execution_time.as_millis() / EPOCH_INTERVAL.as_millis();
+            store.set_epoch_deadline(ticks.try_into().unwrap_or(u64::MAX).max(1));
+        }
+        Ok(())
+    }
+}
+
+/// Returns `true` if `err` was caused by an actor invocation exceeding the execution limits
+/// configured in [Config], i.e. consuming all of its fuel or running past its deadline.
+#[must_use]
+pub fn is_timeout(err: &anyhow::Error) -> bool {
+    err.chain().any(|err| {
+        matches!(
+            err.downcast_ref::<wasmtime::Trap>(),
+            Some(wasmtime::Trap::OutOfFuel | wasmtime::Trap::Interrupt)
+        )
+    })
 }
 
 /// Extracts and validates claims contained within `WebAssembly` binary, if such are found
@@ -116,6 +190,16 @@ impl Actor {
         }
     }
 
+    /// Overrides the [Limits] configured for the [Runtime] for all instances of this [Actor]
+    /// created from now on.
+    #[must_use]
+    pub fn with_limits(self, limits: Limits) -> Self {
+        match self {
+            Self::Module(module) => Self::Module(module.with_limits(limits)),
+            Self::Component(component) => Self::Component(component.with_limits(limits)),
+        }
+    }
+
     /// Like [Self::instantiate], but moves the [Actor].
     #[instrument]
     pub async fn into_instance(self) -> anyhow::Result<Instance> {
@@ -414,6 +498,22 @@ impl Instance {
         self
     }
 
+    /// Set [`OutgoingHttp`] handler for this [Instance].
+    pub fn outgoing_http(
+        &mut self,
+        outgoing_http: Arc<dyn OutgoingHttp + Send + Sync>,
+    ) -> &mut Self {
+        match self {
+            Self::Module(module) => {
+                module.outgoing_http(outgoing_http);
+            }
+            Self::Component(component) => {
+                component.outgoing_http(outgoing_http);
+            }
+        }
+        self
+    }
+
     /// Set actor stderr stream. If another stderr was set, it is replaced and the old one is flushed and shut down if supported by underlying actor implementation.
     ///
     /// # Errors
@@ -459,6 +559,61 @@ impl Instance {
         }
     }
 
+    /// Handle an incoming HTTP request using this [Instance]. Unlike [Self::into_incoming_http],
+    /// this does not consume the [Instance], which allows it to be reused.
+    ///
+    /// # Errors
+    ///
+    /// Fails if no incoming HTTP bindings are exported by the [`Instance`] or handling the request fails
+    #[instrument(skip_all)]
+    pub async fn handle_incoming_http(
+        &mut self,
+        request: http::Request<Box<dyn AsyncRead + Sync + Send + Unpin>>,
+    ) -> anyhow::Result<http::Response<Box<dyn AsyncRead + Sync + Send + Unpin>>> {
+        match self {
+            Self::Module(module) => module
+                .handle_incoming_http(request)
+                .await
+                .context("failed to handle request in module"),
+            Self::Component(component) => component
+                .handle_incoming_http(request)
+                .await
+                .context("failed to handle request in component"),
+        }
+    }
+
+    /// Handle an incoming HTTP request using this [`Instance`], sending the response on
+    /// `response_tx` as soon as it is available. Component actors stream the response body until
+    /// the invocation returns, module actors always produce a fully buffered response.
+    ///
+    /// # Errors
+    ///
+    /// Fails if no incoming HTTP bindings are exported by the [`Instance`] or handling the request fails
+    #[instrument(skip_all)]
+    pub(crate) async fn handle_incoming_http_streaming(
+        &mut self,
+        request: http::Request<Box<dyn AsyncRead + Sync + Send + Unpin>>,
+        response_tx: oneshot::Sender<
+            anyhow::Result<http::Response<Box<dyn AsyncRead + Sync + Send + Unpin>>>,
+        >,
+    ) -> anyhow::Result<()> {
+        match self {
+            Self::Module(module) => {
+                let res = module
+                    .handle_incoming_http(request)
+                    .await
+                    .context("failed to handle request in module")?;
+                // The receiver is dropped if the caller is no longer interested in the response
+                let _ = response_tx.send(Ok(res));
+                Ok(())
+            }
+            Self::Component(component) => component
+                .handle_incoming_http_streaming(request, response_tx)
+                .await
+                .context("failed to handle request in component"),
+        }
+    }
+
     /// Instantiates and returns a [`GuestInstance`] if exported by the [`Instance`].
     ///
     /// # Errors
@@ -6,9 +6,10 @@ use crate::actor::claims;
 use crate::capability::logging::logging;
 use crate::capability::{
     builtin, Blobstore, Bus, IncomingHttp, KeyValueAtomic, KeyValueReadWrite, Logging, Messaging,
+    OutgoingHttp,
 };
 use crate::io::AsyncVec;
-use crate::Runtime;
+use crate::{ActorConfig, ActorLimits, Runtime};
 
 use core::any::Any;
 use core::fmt::{self, Debug};
@@ -121,6 +122,7 @@ impl<T: AsyncWrite + Sync + Send + Unpin + 'static> WasiFile for AsyncWritePipe<
 struct Ctx {
     wasi: wasmtime_wasi::WasiCtx,
     wasmbus: wasmbus::Ctx,
+    limits: wasmtime::StoreLimits,
 }
 
 impl Debug for Ctx {
@@ -141,6 +143,7 @@ impl Ctx {
 /// Pre-compiled actor [Module], which is cheapily-[Cloneable](Clone)
 #[derive(Clone)]
 pub struct Module {
+    actor_config: ActorConfig,
     claims: Option<jwt::Claims<jwt::Actor>>,
     config: Config,
     handler: builtin::HandlerBuilder,
@@ -150,6 +153,7 @@ pub struct Module {
 impl Debug for Module {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Module")
+            .field("actor_config", &self.actor_config)
             .field("claims", &self.claims)
             .field("config", &self.config)
             .field("handler", &self.handler)
@@ -164,11 +168,20 @@ impl Module {
     pub fn claims(&self) -> Option<&jwt::Claims<jwt::Actor>> {
         self.claims.as_ref()
     }
+
+    /// Overrides the [`ActorLimits`] configured for the [Runtime] for all instances of this [Module]
+    /// created from now on.
+    #[must_use]
+    pub fn with_limits(mut self, limits: ActorLimits) -> Self {
+        self.actor_config.limits = limits;
+        self
+    }
 }
 
 async fn instantiate(
     module: &wasmtime::Module,
     config: &Config,
+    actor_config: ActorConfig,
     handler: impl Into<builtin::Handler>,
 ) -> anyhow::Result<Instance> {
     let mut wasi = WasiCtxBuilder::new();
@@ -179,11 +192,16 @@ async fn instantiate(
     let ctx = Ctx {
         wasi,
         wasmbus: wasmbus::Ctx::new(handler),
+        limits: actor_config.limits.store_limits(),
     };
 
     let engine = module.engine();
 
     let mut store = wasmtime::Store::new(engine, ctx);
+    store.limiter(|ctx| &mut ctx.limits);
+    actor_config
+        .apply_limits(&mut store)
+        .context("failed to apply execution limits")?;
     let mut linker = wasmtime::Linker::<Ctx>::new(engine);
 
     wasmtime_wasi::add_to_linker(&mut linker, |ctx| &mut ctx.wasi)
@@ -215,6 +233,7 @@ async fn instantiate(
         }
     };
     Ok(Instance {
+        actor_config,
         store,
         guest_call,
         start,
@@ -227,9 +246,15 @@ impl Module {
     pub fn new(rt: &Runtime, wasm: impl AsRef<[u8]>) -> anyhow::Result<Self> {
         let wasm = wasm.as_ref();
         let claims = claims(wasm)?;
-        let module = wasmtime::Module::new(&rt.engine, wasm).context("failed to compile module")?;
+        let module = if let Some(cache) = &rt.artifact_cache {
+            cache.module(&rt.engine, wasm)
+        } else {
+            wasmtime::Module::new(&rt.engine, wasm)
+        }
+        .context("failed to compile module")?;
         Ok(Self {
             module,
+            actor_config: rt.actor_config.clone(),
             claims,
             handler: rt.handler.clone(),
             config: rt.module_config,
@@ -239,7 +264,7 @@ impl Module {
     /// Like [Self::instantiate], but moves the [Module].
     #[instrument]
     pub async fn into_instance(self) -> anyhow::Result<Instance> {
-        instantiate(&self.module, &self.config, self.handler).await
+        instantiate(&self.module, &self.config, self.actor_config, self.handler).await
     }
 
     /// Like [Self::instantiate], but moves the [Module] and returns the associated [jwt::Claims].
@@ -247,14 +272,21 @@ impl Module {
     pub async fn into_instance_claims(
         self,
     ) -> anyhow::Result<(Instance, Option<jwt::Claims<jwt::Actor>>)> {
-        let instance = instantiate(&self.module, &self.config, self.handler).await?;
+        let instance =
+            instantiate(&self.module, &self.config, self.actor_config, self.handler).await?;
         Ok((instance, self.claims))
     }
 
     /// Instantiates a [Module] and returns the resulting [Instance].
     #[instrument]
     pub async fn instantiate(&self) -> anyhow::Result<Instance> {
-        instantiate(&self.module, &self.config, self.handler.clone()).await
+        instantiate(
+            &self.module,
+            &self.config,
+            self.actor_config.clone(),
+            self.handler.clone(),
+        )
+        .await
     }
 
     /// Instantiate a [Module] producing an [Instance] and invoke an operation on it using [Instance::call]
@@ -275,6 +307,7 @@ impl Module {
 
 /// An instance of a [Module]
 pub struct Instance {
+    actor_config: ActorConfig,
     store: wasmtime::Store<Ctx>,
     guest_call: Option<TypedFunc<guest_call::Params, guest_call::Result>>,
     start: Option<TypedFunc<(), ()>>,
@@ -357,6 +390,15 @@ impl Instance {
         self
     }
 
+    /// Set [`OutgoingHttp`] handler for this [Instance].
+    pub fn outgoing_http(
+        &mut self,
+        outgoing_http: Arc<dyn OutgoingHttp + Send + Sync>,
+    ) -> &mut Self {
+        self.handler_mut().replace_outgoing_http(outgoing_http);
+        self
+    }
+
     /// Set actor stderr stream. If another stderr was set, it is replaced.
     pub fn stderr(&mut self, stderr: impl AsyncWrite + Send + Sync + Unpin + 'static) -> &mut Self {
         let stderr = AsyncWritePipe(Arc::new(Mutex::new(stderr)));
@@ -373,6 +415,9 @@ impl Instance {
         mut response: impl AsyncWrite + Send + Sync + Unpin + 'static,
     ) -> anyhow::Result<Result<(), String>> {
         self.store.data_mut().reset();
+        self.actor_config
+            .apply_limits(&mut self.store)
+            .context("failed to apply execution limits")?;
 
         // TODO: Introduce wasmbus v2 with two-way streaming
 
@@ -453,6 +498,49 @@ impl Instance {
         }
         Ok(Ok(()))
     }
+
+    /// Handle an incoming HTTP request using this [`Instance`].
+    ///
+    /// # Errors
+    ///
+    /// Fails if either calling the actor fails or the actor returns an error
+    #[instrument(skip_all)]
+    pub async fn handle_incoming_http(
+        &mut self,
+        request: http::Request<Box<dyn AsyncRead + Sync + Send + Unpin>>,
+    ) -> anyhow::Result<http::Response<Box<dyn AsyncRead + Sync + Send + Unpin>>> {
+        let request = wasmcloud_compat::HttpRequest::from_http(request)
+            .await
+            .context("failed to parse request")?;
+        let request = rmp_serde::to_vec_named(&request).context("failed to encode request")?;
+        let mut response = AsyncVec::default();
+        match self
+            .call(
+                "HttpServer.HandleRequest",
+                Cursor::new(request),
+                response.clone(),
+            )
+            .await
+            .context("failed to call actor")?
+        {
+            Ok(()) => {
+                response
+                    .rewind()
+                    .await
+                    .context("failed to rewind response buffer")?;
+                let response: wasmcloud_compat::HttpResponse =
+                    rmp_serde::from_read(&mut response).context("failed to parse response")?;
+                let response: http::Response<_> =
+                    response.try_into().context("failed to convert response")?;
+                Ok(
+                    response.map(|body| -> Box<dyn AsyncRead + Send + Sync + Unpin> {
+                        Box::new(Cursor::new(body))
+                    }),
+                )
+            }
+            Err(err) => bail!(err),
+        }
+    }
 }
 
 /// Instantiated, clone-able guest instance
@@ -515,36 +603,6 @@ impl IncomingHttp for GuestInstance {
         &self,
         request: http::Request<Box<dyn AsyncRead + Sync + Send + Unpin>>,
     ) -> anyhow::Result<http::Response<Box<dyn AsyncRead + Sync + Send + Unpin>>> {
-        let request = wasmcloud_compat::HttpRequest::from_http(request)
-            .await
-            .context("failed to parse request")?;
-        let request = rmp_serde::to_vec_named(&request).context("failed to encode request")?;
-        let mut response = AsyncVec::default();
-        match self
-            .call(
-                "HttpServer.HandleRequest",
-                Cursor::new(request),
-                response.clone(),
-            )
-            .await
-            .context("failed to call actor")?
-        {
-            Ok(()) => {
-                response
-                    .rewind()
-                    .await
-                    .context("failed to rewind response buffer")?;
-                let response: wasmcloud_compat::HttpResponse =
-                    rmp_serde::from_read(&mut response).context("failed to parse response")?;
-                let response: http::Response<_> =
-                    response.try_into().context("failed to convert response")?;
-                Ok(
-                    response.map(|body| -> Box<dyn AsyncRead + Send + Sync + Unpin> {
-                        Box::new(Cursor::new(body))
-                    }),
-                )
-            }
-            Err(err) => bail!(err),
-        }
+        self.0.lock().await.handle_incoming_http(request).await
     }
 }
@@ -0,0 +1,546 @@
+use super::{Actor, Instance};
+use crate::Runtime;
+
+use core::fmt::{self, Debug};
+use core::future::Future;
+use core::mem;
+use core::ops::{Deref, DerefMut};
+use core::pin::Pin;
+use core::task::{ready, Context as TaskContext, Poll};
+
+use std::io;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use anyhow::{bail, Context};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::sync::{oneshot, OwnedSemaphorePermit, Semaphore};
+use tokio::task::JoinHandle;
+use tracing::{instrument, trace};
+
+#[derive(Default)]
+struct State {
+    /// Instances ready to be checked out
+    idle: Vec<Instance>,
+    /// Maximum amount of instances in the pool
+    size: usize,
+    /// Amount of instances currently alive, both idle and checked out
+    live: usize,
+    /// Amount of permits held by checked out instances, which must be forgotten on return
+    excess: usize,
+}
+
+struct Inner {
+    actor: Actor,
+    rt: Runtime,
+    permits: Arc<Semaphore>,
+    state: Mutex<State>,
+}
+
+/// A bounded pool of reusable [Actor] instances.
+///
+/// At most [`InstancePool::size`] instances are checked out at any given time and
+/// [`InstancePool::get`] waits for an instance to be returned if all of them are in use.
+/// Instances returned to the pool are [reset](Instance::reset) before being handed out again.
+#[derive(Clone)]
+pub struct InstancePool(Arc<Inner>);
+
+impl Debug for InstancePool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.state();
+        f.debug_struct("InstancePool")
+            .field("actor", &self.0.actor)
+            .field("size", &state.size)
+            .field("live", &state.live)
+            .field("idle", &state.idle.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl InstancePool {
+    /// Returns a new [`InstancePool`] of `actor` instances bounded by `size`.
+    /// Instances are created on demand, use [`Self::fill`] to pre-instantiate them.
+    /// A pool of size 0 does not hand out any instances until resized.
+    #[must_use]
+    pub fn new(rt: &Runtime, actor: Actor, size: usize) -> Self {
+        Self(Arc::new(Inner {
+            actor,
+            rt: rt.clone(),
+            permits: Arc::new(Semaphore::new(size)),
+            state: Mutex::new(State {
+                size,
+                ..State::default()
+            }),
+        }))
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.0.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// [Actor] instances of which are pooled.
+    #[must_use]
+    pub fn actor(&self) -> &Actor {
+        &self.0.actor
+    }
+
+    /// Maximum amount of instances, which can be checked out concurrently.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.state().size
+    }
+
+    /// Amount of instantiated instances ready to be checked out.
+    #[must_use]
+    pub fn idle(&self) -> usize {
+        self.state().idle.len()
+    }
+
+    /// Instantiates the actor until the pool holds [`Self::size`] instances.
+    ///
+    /// # Errors
+    ///
+    /// Fails if instantiation fails
+    #[instrument]
+    pub async fn fill(&self) -> anyhow::Result<()> {
+        loop {
+            {
+                let mut state = self.state();
+                if state.live >= state.size {
+                    return Ok(());
+                }
+                state.live += 1;
+            }
+            match self.0.actor.instantiate().await {
+                Ok(instance) => self.state().idle.push(instance),
+                Err(e) => {
+                    self.state().live -= 1;
+                    return Err(e.context("failed to instantiate actor"));
+                }
+            }
+        }
+    }
+
+    /// Sets the maximum amount of instances in the pool. Surplus idle instances are dropped
+    /// immediately, surplus checked out instances - once returned.
+    #[instrument]
+    pub fn resize(&self, size: usize) {
+        let mut state = self.state();
+        if size > state.size {
+            let grow = size - state.size;
+            let cancel = grow.min(state.excess);
+            state.excess -= cancel;
+            self.0.permits.add_permits(grow - cancel);
+        } else {
+            let mut shrink = state.size - size;
+            while shrink > 0 {
+                let Ok(permit) = self.0.permits.try_acquire() else {
+                    break;
+                };
+                permit.forget();
+                shrink -= 1;
+            }
+            state.excess += shrink;
+            let surplus = state.live.saturating_sub(size).min(state.idle.len());
+            let idle = state.idle.len() - surplus;
+            state.idle.truncate(idle);
+            state.live -= surplus;
+        }
+        state.size = size;
+    }
+
+    /// Checks out an [Instance] from the pool, waiting for one to be returned if all instances
+    /// are in use. An idle instance is reused if available, otherwise the actor is instantiated.
+    ///
+    /// # Errors
+    ///
+    /// Fails if instantiation fails
+    #[instrument]
+    pub async fn get(&self) -> anyhow::Result<PooledInstance> {
+        let permit = Arc::clone(&self.0.permits)
+            .acquire_owned()
+            .await
+            .context("instance pool closed")?;
+        let idle = self.state().idle.pop();
+        let instance = if let Some(mut instance) = idle {
+            trace!("reuse idle instance");
+            instance.reset(&self.0.rt).await;
+            instance
+        } else {
+            trace!("instantiate actor");
+            self.state().live += 1;
+            match self.0.actor.instantiate().await {
+                Ok(instance) => instance,
+                Err(e) => {
+                    self.state().live -= 1;
+                    return Err(e.context("failed to instantiate actor"));
+                }
+            }
+        };
+        Ok(PooledInstance {
+            instance: Some(instance),
+            permit: Some(permit),
+            pool: self.clone(),
+            poisoned: false,
+        })
+    }
+
+    fn put(&self, instance: Instance, permit: OwnedSemaphorePermit, poisoned: bool) {
+        let mut state = self.state();
+        if state.excess > 0 {
+            state.excess -= 1;
+            state.live -= 1;
+            permit.forget();
+        } else if poisoned || state.live > state.size {
+            state.live -= 1;
+        } else {
+            state.idle.push(instance);
+        }
+    }
+}
+
+/// An [Instance] checked out from an [`InstancePool`], which is returned to the pool on drop
+pub struct PooledInstance {
+    instance: Option<Instance>,
+    permit: Option<OwnedSemaphorePermit>,
+    pool: InstancePool,
+    poisoned: bool,
+}
+
+impl Debug for PooledInstance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PooledInstance")
+            .field("instance", &self.instance)
+            .field("poisoned", &self.poisoned)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Deref for PooledInstance {
+    type Target = Instance;
+
+    fn deref(&self) -> &Self::Target {
+        self.instance.as_ref().expect("instance already returned")
+    }
+}
+
+impl DerefMut for PooledInstance {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.instance.as_mut().expect("instance already returned")
+    }
+}
+
+impl Drop for PooledInstance {
+    fn drop(&mut self) {
+        if let (Some(instance), Some(permit)) = (self.instance.take(), self.permit.take()) {
+            self.pool.put(instance, permit, self.poisoned);
+        }
+    }
+}
+
+impl PooledInstance {
+    /// Invoke an operation using [`Instance::call`]. If the call fails or does not run to
+    /// completion, the instance is discarded instead of being returned to the pool.
+    ///
+    /// # Errors
+    ///
+    /// Outermost error represents a failure in calling the actor, innermost - the
+    /// application-layer error originating from within the actor itself
+    #[instrument(skip_all)]
+    pub async fn call(
+        &mut self,
+        operation: impl AsRef<str>,
+        request: impl AsyncRead + Send + Sync + Unpin + 'static,
+        response: impl AsyncWrite + Send + Sync + Unpin + 'static,
+    ) -> anyhow::Result<Result<(), String>> {
+        // NOTE: The instance remains poisoned if this future is dropped before the call returns
+        let poisoned = mem::replace(&mut self.poisoned, true);
+        let res = self.deref_mut().call(operation, request, response).await;
+        self.poisoned = poisoned || res.is_err();
+        res
+    }
+
+    /// Serve an incoming HTTP request using [`Instance::handle_incoming_http`] in a background
+    /// task, returning the response as soon as it is set by the actor. The response body is
+    /// streamed while the invocation is in progress and fails if the invocation does.
+    /// If the invocation fails or does not run to completion, the instance is discarded instead
+    /// of being returned to the pool.
+    ///
+    /// # Errors
+    ///
+    /// Fails if handling the request fails before the response is set
+    #[instrument(skip_all)]
+    pub async fn serve_incoming_http(
+        mut self,
+        request: http::Request<Box<dyn AsyncRead + Sync + Send + Unpin>>,
+    ) -> anyhow::Result<http::Response<Box<dyn AsyncRead + Sync + Send + Unpin>>> {
+        let (response_tx, response_rx) = oneshot::channel();
+        let task = tokio::spawn(async move {
+            self.handle_incoming_http_streaming(request, response_tx)
+                .await
+        });
+        let Ok(res) = response_rx.await else {
+            task.await.context("failed to join HTTP handler task")??;
+            bail!("response not set");
+        };
+        Ok(
+            res?.map(|body| -> Box<dyn AsyncRead + Sync + Send + Unpin> {
+                Box::new(StreamingBody {
+                    body,
+                    task: Some(task),
+                })
+            }),
+        )
+    }
+
+    /// Like [`Instance::handle_incoming_http_streaming`], but poisons the instance unless
+    /// the invocation completes successfully
+    async fn handle_incoming_http_streaming(
+        &mut self,
+        request: http::Request<Box<dyn AsyncRead + Sync + Send + Unpin>>,
+        response_tx: oneshot::Sender<
+            anyhow::Result<http::Response<Box<dyn AsyncRead + Sync + Send + Unpin>>>,
+        >,
+    ) -> anyhow::Result<()> {
+        let poisoned = mem::replace(&mut self.poisoned, true);
+        let res = self
+            .deref_mut()
+            .handle_incoming_http_streaming(request, response_tx)
+            .await;
+        self.poisoned = poisoned || res.is_err();
+        res
+    }
+
+    /// Discard the instance, so that it is not returned to the pool.
+    pub fn discard(mut self) {
+        self.poisoned = true;
+    }
+}
+
+/// Response body streamed from an in-progress invocation, which fails if the invocation does
+struct StreamingBody {
+    body: Box<dyn AsyncRead + Sync + Send + Unpin>,
+    task: Option<JoinHandle<anyhow::Result<()>>>,
+}
+
+impl AsyncRead for StreamingBody {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut TaskContext<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let filled = buf.filled().len();
+        ready!(Pin::new(&mut self.body).poll_read(cx, buf))?;
+        if buf.filled().len() > filled || buf.remaining() == 0 {
+            return Poll::Ready(Ok(()));
+        }
+        // The body is finished, wait for the invocation to complete
+        let Some(task) = self.task.as_mut() else {
+            return Poll::Ready(Ok(()));
+        };
+        let res = ready!(Pin::new(task).poll(cx));
+        self.task = None;
+        match res {
+            Ok(Ok(())) => Poll::Ready(Ok(())),
+            Ok(Err(err)) => Poll::Ready(Err(io::Error::new(io::ErrorKind::Other, err))),
+            Err(err) => Poll::Ready(Err(io::Error::new(io::ErrorKind::Other, err))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::future::pending;
+    use core::time::Duration;
+
+    use async_trait::async_trait;
+    use tokio::io::{duplex, empty, sink, AsyncReadExt, AsyncWriteExt};
+    use tokio::time::timeout;
+
+    use crate::capability::logging::logging;
+    use crate::capability::Logging;
+
+    /// An actor module, which succeeds on `ok`, traps on `trap` and on `block` writes a log entry,
+    /// which is never handled
+    const MODULE: &str = r#"
+(module
+  (import "wasmbus" "__host_call" (func $host_call (param i32 i32 i32 i32 i32 i32 i32 i32) (result i32)))
+  (memory (export "memory") 1)
+  (data (i32.const 0) "wasmcloud:builtin:logging")
+  (data (i32.const 32) "Logging.WriteLog")
+  (data (i32.const 64) "\82\a5level\a4info\a4text\a2hi")
+  (func (export "__guest_call") (param $operation_len i32) (param $payload_len i32) (result i32)
+    (if (i32.eq (local.get $operation_len) (i32.const 4))
+      (then unreachable))
+    (if (i32.eq (local.get $operation_len) (i32.const 5))
+      (then (return (call $host_call
+        (i32.const 0) (i32.const 0)
+        (i32.const 0) (i32.const 25)
+        (i32.const 32) (i32.const 16)
+        (i32.const 64) (i32.const 20)))))
+    (i32.const 1))
+)
+"#;
+
+    /// [Logging] handler, which never completes
+    struct PendingLogging;
+
+    #[async_trait]
+    impl Logging for PendingLogging {
+        async fn log(&self, _: logging::Level, _: String, _: String) -> anyhow::Result<()> {
+            pending().await
+        }
+    }
+
+    fn new_pool(size: usize) -> anyhow::Result<InstancePool> {
+        let rt = Runtime::builder()
+            .logging(Arc::new(PendingLogging))
+            .build()?;
+        let wasm = wat::parse_str(MODULE)?;
+        let actor = Actor::new(&rt, wasm)?;
+        Ok(InstancePool::new(&rt, actor, size))
+    }
+
+    async fn call(instance: &mut PooledInstance, operation: &str) -> anyhow::Result<()> {
+        instance
+            .call(operation, empty(), sink())
+            .await?
+            .map_err(anyhow::Error::msg)
+    }
+
+    #[tokio::test]
+    async fn get() -> anyhow::Result<()> {
+        let pool = new_pool(1)?;
+        let instance = pool.get().await?;
+        assert_eq!(pool.idle(), 0);
+
+        // all instances are checked out, so `get` waits for one to be returned
+        let waiting = tokio::spawn({
+            let pool = pool.clone();
+            async move { pool.get().await.map(drop) }
+        });
+        assert!(timeout(Duration::from_millis(100), pool.get())
+            .await
+            .is_err());
+        drop(instance);
+        timeout(Duration::from_secs(5), waiting).await???;
+        assert_eq!(pool.idle(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resize() -> anyhow::Result<()> {
+        let pool = new_pool(2)?;
+        pool.fill().await?;
+        assert_eq!(pool.idle(), 2);
+
+        pool.resize(1);
+        assert_eq!(pool.size(), 1);
+        assert_eq!(pool.idle(), 1);
+
+        // checked out instances exceeding the size are dropped once returned
+        let instance = pool.get().await?;
+        pool.resize(0);
+        assert!(timeout(Duration::from_millis(100), pool.get())
+            .await
+            .is_err());
+        drop(instance);
+        assert_eq!(pool.idle(), 0);
+
+        pool.resize(2);
+        let instances = (pool.get().await?, pool.get().await?);
+        assert!(timeout(Duration::from_millis(100), pool.get())
+            .await
+            .is_err());
+        drop(instances);
+        assert_eq!(pool.idle(), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn poison() -> anyhow::Result<()> {
+        let pool = new_pool(1)?;
+
+        let mut instance = pool.get().await?;
+        call(&mut instance, "ok").await?;
+        drop(instance);
+        assert_eq!(
+            pool.idle(),
+            1,
+            "instance should be returned after a successful call"
+        );
+
+        let mut instance = pool.get().await?;
+        assert!(call(&mut instance, "trap").await.is_err());
+        drop(instance);
+        assert_eq!(
+            pool.idle(),
+            0,
+            "instance should be discarded after a failed call"
+        );
+
+        let mut instance = pool.get().await?;
+        assert!(
+            timeout(Duration::from_millis(100), call(&mut instance, "block"))
+                .await
+                .is_err()
+        );
+        drop(instance);
+        assert_eq!(
+            pool.idle(),
+            0,
+            "instance should be discarded after an aborted call"
+        );
+
+        let instance = pool.get().await?;
+        instance.discard();
+        assert_eq!(pool.idle(), 0, "discarded instance should not be returned");
+
+        // the pool still hands out instances
+        let mut instance = pool.get().await?;
+        call(&mut instance, "ok").await?;
+        drop(instance);
+        assert_eq!(pool.idle(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn streaming_body() -> anyhow::Result<()> {
+        let (mut tx, rx) = duplex(16);
+        let (done_tx, done_rx) = oneshot::channel();
+        let mut body = StreamingBody {
+            body: Box::new(rx),
+            task: Some(tokio::spawn(async move { done_rx.await? })),
+        };
+
+        // the body is readable while the invocation is still in progress
+        tx.write_all(b"foo").await?;
+        let mut buf = [0; 3];
+        timeout(Duration::from_secs(5), body.read_exact(&mut buf)).await??;
+        assert_eq!(&buf, b"foo");
+
+        // the body is only complete once the invocation is
+        tx.write_all(b"bar").await?;
+        drop(tx);
+        timeout(Duration::from_secs(5), body.read_exact(&mut buf)).await??;
+        assert_eq!(&buf, b"bar");
+        assert!(timeout(Duration::from_millis(100), body.read(&mut [0; 1]))
+            .await
+            .is_err());
+        done_tx
+            .send(Ok(()))
+            .map_err(|_| anyhow::anyhow!("task stopped"))?;
+        assert_eq!(body.read(&mut [0; 1]).await?, 0);
+
+        // a failed invocation fails the body
+        let (tx, rx) = duplex(16);
+        drop(tx);
+        let mut body = StreamingBody {
+            body: Box::new(rx),
+            task: Some(tokio::spawn(async { bail!("invocation failed") })),
+        };
+        assert!(body.read_to_end(&mut vec![]).await.is_err());
+        Ok(())
+    }
+}
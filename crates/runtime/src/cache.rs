@@ -0,0 +1,328 @@
+use core::hash::{Hash, Hasher};
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use anyhow::{ensure, Context as _, Result};
+use sha2::{Digest, Sha256};
+use tracing::{debug, instrument, trace, warn};
+
+/// Length of the SHA-256 digest prefixing every cached artifact
+const DIGEST_LEN: usize = 32;
+
+/// Extension of cached precompiled modules
+const MODULE_EXTENSION: &str = "cwasm-module";
+
+/// Extension of cached precompiled components
+const COMPONENT_EXTENSION: &str = "cwasm-component";
+
+/// On-disk content-addressed cache of precompiled [`wasmtime`] artifacts.
+///
+/// Artifacts are keyed on the SHA-256 digest of the Wasm binary and the compatibility hash of the
+/// [`wasmtime::Engine`] they were compiled with, so a change in engine configuration or `wasmtime`
+/// version never results in an incompatible artifact being loaded.
+/// Every artifact is stored prefixed by the SHA-256 digest of its contents, which is verified
+/// before the artifact is deserialized. Artifacts failing validation are removed and recompiled.
+/// Once the total size of cached artifacts exceeds the configured maximum, the least recently
+/// used artifacts are evicted. Artifacts are marked as used when they are written or loaded.
+#[derive(Clone, Debug)]
+pub(crate) struct ArtifactCache {
+    dir: PathBuf,
+    max_size: u64,
+    engine_hash: String,
+}
+
+impl ArtifactCache {
+    /// Returns a new [`ArtifactCache`] of artifacts compiled using `engine` stored in `dir`,
+    /// which is created if it does not exist. Artifacts exceeding `max_size` are evicted.
+    pub(crate) fn new(engine: &wasmtime::Engine, dir: PathBuf, max_size: u64) -> Result<Self> {
+        fs::create_dir_all(&dir).with_context(|| {
+            format!(
+                "failed to create artifact cache directory `{}`",
+                dir.display()
+            )
+        })?;
+        let mut hasher = Sha256Hasher::default();
+        engine.precompile_compatibility_hash().hash(&mut hasher);
+        let cache = Self {
+            dir,
+            max_size,
+            engine_hash: hex::encode(hasher.0.finalize()),
+        };
+        cache.evict();
+        Ok(cache)
+    }
+
+    /// Returns a [`wasmtime::Module`] compiled from `wasm`, loading it from the cache if present.
+    #[instrument(skip_all)]
+    pub(crate) fn module(
+        &self,
+        engine: &wasmtime::Engine,
+        wasm: &[u8],
+    ) -> Result<wasmtime::Module> {
+        self.load_or_compile(
+            MODULE_EXTENSION,
+            wasm,
+            || engine.precompile_module(wasm),
+            // SAFETY: the artifact was either just produced by `precompile_module` or its
+            // contents were verified against the digest written alongside it by this cache
+            |buf| unsafe { wasmtime::Module::deserialize(engine, buf) },
+        )
+    }
+
+    /// Returns a [`wasmtime::component::Component`] compiled from `wasm`, loading it from the
+    /// cache if present.
+    #[instrument(skip_all)]
+    pub(crate) fn component(
+        &self,
+        engine: &wasmtime::Engine,
+        wasm: &[u8],
+    ) -> Result<wasmtime::component::Component> {
+        self.load_or_compile(
+            COMPONENT_EXTENSION,
+            wasm,
+            || engine.precompile_component(wasm),
+            // SAFETY: the artifact was either just produced by `precompile_component` or its
+            // contents were verified against the digest written alongside it by this cache
+            |buf| unsafe { wasmtime::component::Component::deserialize(engine, buf) },
+        )
+    }
+
+    fn path(&self, extension: &str, wasm: &[u8]) -> PathBuf {
+        let digest = hex::encode(Sha256::digest(wasm));
+        self.dir
+            .join(format!("{digest}-{}", self.engine_hash))
+            .with_extension(extension)
+    }
+
+    fn load_or_compile<T>(
+        &self,
+        extension: &str,
+        wasm: &[u8],
+        precompile: impl FnOnce() -> Result<Vec<u8>>,
+        deserialize: impl Fn(&[u8]) -> Result<T>,
+    ) -> Result<T> {
+        let path = self.path(extension, wasm);
+        match load(&path) {
+            Ok(Some(buf)) => match deserialize(&buf) {
+                Ok(artifact) => {
+                    trace!(path = ?path.display(), "loaded cached artifact");
+                    return Ok(artifact);
+                }
+                Err(e) => {
+                    warn!(path = ?path.display(), error = ?e, "failed to deserialize cached artifact, removing it");
+                    remove(&path);
+                }
+            },
+            Ok(None) => trace!(path = ?path.display(), "artifact not cached"),
+            Err(e) => {
+                warn!(path = ?path.display(), error = ?e, "invalid cached artifact, removing it");
+                remove(&path);
+            }
+        }
+        let buf = precompile().context("failed to precompile artifact")?;
+        let artifact = deserialize(&buf).context("failed to deserialize precompiled artifact")?;
+        if let Err(e) = store(&path, &buf) {
+            warn!(path = ?path.display(), error = ?e, "failed to cache precompiled artifact");
+        } else {
+            debug!(path = ?path.display(), "cached precompiled artifact");
+            self.evict();
+        }
+        Ok(artifact)
+    }
+
+    /// Removes least recently used artifacts until the total size of the cache does not
+    /// exceed the configured maximum
+    fn evict(&self) {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) => {
+                warn!(dir = ?self.dir.display(), error = ?e, "failed to read artifact cache directory");
+                return;
+            }
+        };
+        let mut artifacts: Vec<_> = entries
+            .filter_map(|entry| {
+                let entry = entry.ok()?;
+                let path = entry.path();
+                let extension = path.extension()?;
+                if extension != MODULE_EXTENSION && extension != COMPONENT_EXTENSION {
+                    return None;
+                }
+                let metadata = entry.metadata().ok()?;
+                let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                Some((modified, metadata.len(), path))
+            })
+            .collect();
+        let mut size: u64 = artifacts.iter().map(|(_, len, _)| len).sum();
+        if size <= self.max_size {
+            return;
+        }
+        artifacts.sort_unstable_by_key(|(modified, ..)| *modified);
+        for (_, len, path) in artifacts {
+            if size <= self.max_size {
+                break;
+            }
+            debug!(path = ?path.display(), "evict cached artifact");
+            remove(&path);
+            size = size.saturating_sub(len);
+        }
+    }
+}
+
+/// [`Hasher`] feeding all written data into a SHA-256 digest, which, unlike
+/// [`DefaultHasher`](std::collections::hash_map::DefaultHasher), is stable across Rust releases
+#[derive(Default)]
+struct Sha256Hasher(Sha256);
+
+impl Hasher for Sha256Hasher {
+    fn finish(&self) -> u64 {
+        let digest = self.0.clone().finalize();
+        let mut buf = [0; 8];
+        buf.copy_from_slice(&digest[..8]);
+        u64::from_be_bytes(buf)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+}
+
+/// Reads the artifact at `path` and verifies its digest, returning `None` if it does not exist.
+/// The modification time of the artifact is updated, so that it is evicted last.
+fn load(path: &Path) -> Result<Option<Vec<u8>>> {
+    let mut buf = match fs::read(path) {
+        Ok(buf) => buf,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e).context("failed to read artifact"),
+    };
+    ensure!(buf.len() >= DIGEST_LEN, "artifact is truncated");
+    let artifact = buf.split_off(DIGEST_LEN);
+    ensure!(
+        Sha256::digest(&artifact).as_slice() == buf,
+        "artifact digest mismatch"
+    );
+    if let Err(e) = touch(path) {
+        warn!(path = ?path.display(), error = ?e, "failed to update cached artifact modification time");
+    }
+    Ok(Some(artifact))
+}
+
+/// Sets the modification time of the file at `path` to now
+fn touch(path: &Path) -> io::Result<()> {
+    fs::File::options()
+        .write(true)
+        .open(path)?
+        .set_modified(SystemTime::now())
+}
+
+/// Atomically writes the artifact to `path` prefixed by its digest
+fn store(path: &Path, artifact: &[u8]) -> Result<()> {
+    let tmp = path.with_extension(format!("tmp-{}", uuid::Uuid::new_v4()));
+    let mut buf = Vec::with_capacity(DIGEST_LEN + artifact.len());
+    buf.extend_from_slice(&Sha256::digest(artifact));
+    buf.extend_from_slice(artifact);
+    fs::write(&tmp, buf).context("failed to write artifact")?;
+    if let Err(e) = fs::rename(&tmp, path) {
+        remove(&tmp);
+        return Err(e).context("failed to rename artifact");
+    }
+    Ok(())
+}
+
+fn remove(path: &Path) {
+    if let Err(e) = fs::remove_file(path) {
+        if e.kind() != io::ErrorKind::NotFound {
+            warn!(path = ?path.display(), error = ?e, "failed to remove cached artifact");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::time::Duration;
+
+    /// An empty WebAssembly module
+    const EMPTY_MODULE: &[u8] = b"\0asm\x01\0\0\0";
+
+    #[test]
+    fn artifact_cache() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let engine = wasmtime::Engine::default();
+        let cache = ArtifactCache::new(&engine, dir.path().into(), u64::MAX)?;
+        let path = cache.path(MODULE_EXTENSION, EMPTY_MODULE);
+
+        cache.module(&engine, EMPTY_MODULE)?;
+        let cached = fs::read(&path).context("artifact not cached")?;
+        assert!(load(&path)?.is_some());
+
+        // corrupted artifacts are detected and replaced
+        let mut corrupted = cached.clone();
+        *corrupted.last_mut().expect("artifact is empty") ^= 0xff;
+        fs::write(&path, corrupted)?;
+        assert!(load(&path).is_err());
+        cache.module(&engine, EMPTY_MODULE)?;
+        assert_eq!(fs::read(&path)?, cached);
+
+        // artifacts exceeding the maximum cache size are evicted
+        ArtifactCache::new(&engine, dir.path().into(), 0)?;
+        assert!(!path.exists());
+        Ok(())
+    }
+
+    #[test]
+    fn engine_hash() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let engine = wasmtime::Engine::default();
+        let a = ArtifactCache::new(&engine, dir.path().into(), u64::MAX)?;
+        let b = ArtifactCache::new(&engine, dir.path().into(), u64::MAX)?;
+        assert_eq!(a.engine_hash, b.engine_hash);
+        assert_eq!(a.engine_hash.len(), 64);
+        assert!(a.engine_hash.chars().all(|c| c.is_ascii_hexdigit()));
+
+        let mut config = wasmtime::Config::default();
+        config.consume_fuel(true);
+        let engine = wasmtime::Engine::new(&config)?;
+        let c = ArtifactCache::new(&engine, dir.path().into(), u64::MAX)?;
+        assert_ne!(a.engine_hash, c.engine_hash);
+        Ok(())
+    }
+
+    #[test]
+    fn evict_least_recently_used() -> Result<()> {
+        /// Another empty WebAssembly module, which differs from [`EMPTY_MODULE`] by a custom
+        /// section
+        const OTHER_MODULE: &[u8] = b"\0asm\x01\0\0\0\0\x02\x01a";
+
+        let dir = tempfile::tempdir()?;
+        let engine = wasmtime::Engine::default();
+        let cache = ArtifactCache::new(&engine, dir.path().into(), u64::MAX)?;
+        let empty = cache.path(MODULE_EXTENSION, EMPTY_MODULE);
+        let other = cache.path(MODULE_EXTENSION, OTHER_MODULE);
+
+        cache.module(&engine, EMPTY_MODULE)?;
+        cache.module(&engine, OTHER_MODULE)?;
+        let past = SystemTime::now() - Duration::from_secs(60);
+        for path in [&empty, &other] {
+            fs::File::options()
+                .write(true)
+                .open(path)?
+                .set_modified(past)?;
+        }
+
+        // loading the artifact marks it as used
+        cache.module(&engine, EMPTY_MODULE)?;
+        assert!(fs::metadata(&empty)?.modified()? > past);
+
+        // only enough space for a single artifact
+        let max_size = fs::metadata(&empty)?.len().max(fs::metadata(&other)?.len());
+        ArtifactCache::new(&engine, dir.path().into(), max_size)?;
+        assert!(empty.exists());
+        assert!(!other.exists());
+        Ok(())
+    }
+}
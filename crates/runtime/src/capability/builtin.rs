@@ -1,3 +1,4 @@
+use super::http::types::RequestOptions;
 use super::logging::logging;
 use super::{blobstore, bus, format_opt, messaging};
 
@@ -6,6 +7,7 @@ use core::fmt::Debug;
 use core::future::Future;
 use core::pin::Pin;
 use core::str::FromStr;
+use core::task::{Context as TaskContext, Poll};
 use core::time::Duration;
 
 use std::ops::RangeInclusive;
@@ -16,6 +18,7 @@ use async_trait::async_trait;
 use futures::{Stream, TryStreamExt};
 use nkeys::{KeyPair, KeyPairType};
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::sync::oneshot;
 use tracing::{instrument, trace};
 
 #[derive(Clone, Default)]
@@ -27,6 +30,7 @@ pub struct Handler {
     keyvalue_readwrite: Option<Arc<dyn KeyValueReadWrite + Sync + Send>>,
     logging: Option<Arc<dyn Logging + Sync + Send>>,
     messaging: Option<Arc<dyn Messaging + Sync + Send>>,
+    outgoing_http: Option<Arc<dyn OutgoingHttp + Sync + Send>>,
 }
 
 impl Debug for Handler {
@@ -39,6 +43,7 @@ impl Debug for Handler {
             .field("keyvalue_readwrite", &format_opt(&self.keyvalue_readwrite))
             .field("logging", &format_opt(&self.logging))
             .field("messaging", &format_opt(&self.messaging))
+            .field("outgoing_http", &format_opt(&self.outgoing_http))
             .finish()
     }
 }
@@ -136,6 +141,14 @@ impl Handler {
     ) -> Option<Arc<dyn Messaging + Send + Sync>> {
         self.messaging.replace(messaging)
     }
+
+    /// Replace [`OutgoingHttp`] handler returning the old one, if such was set
+    pub fn replace_outgoing_http(
+        &mut self,
+        outgoing_http: Arc<dyn OutgoingHttp + Send + Sync>,
+    ) -> Option<Arc<dyn OutgoingHttp + Send + Sync>> {
+        self.outgoing_http.replace(outgoing_http)
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -281,6 +294,31 @@ pub trait Bus {
     }
 }
 
+/// HTTP trailers, which become available once the body of a request or response is read to
+/// completion. Trailers are passed along with requests and responses of [`IncomingHttp`] and
+/// [`OutgoingHttp`] in their [`http::Extensions`].
+/// Resolves to `None` if the body was completed without trailers.
+#[derive(Debug)]
+pub struct HttpTrailers(oneshot::Receiver<http::HeaderMap>);
+
+impl HttpTrailers {
+    /// Returns a new [`HttpTrailers`] and a [`oneshot::Sender`] used to send the trailers.
+    /// Dropping the sender completes the [`HttpTrailers`] without trailers.
+    #[must_use]
+    pub fn channel() -> (oneshot::Sender<http::HeaderMap>, Self) {
+        let (tx, rx) = oneshot::channel();
+        (tx, Self(rx))
+    }
+}
+
+impl Future for HttpTrailers {
+    type Output = Option<http::HeaderMap>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx).map(Result::ok)
+    }
+}
+
 #[async_trait]
 /// `wasi:http/incoming-handler` implementation
 pub trait IncomingHttp {
@@ -291,6 +329,17 @@ pub trait IncomingHttp {
     ) -> anyhow::Result<http::Response<Box<dyn AsyncRead + Sync + Send + Unpin>>>;
 }
 
+#[async_trait]
+/// `wasi:http/outgoing-handler` implementation
+pub trait OutgoingHttp {
+    /// Handle `wasi:http/outgoing-handler`
+    async fn handle(
+        &self,
+        request: http::Request<Box<dyn AsyncRead + Sync + Send + Unpin>>,
+        options: Option<RequestOptions>,
+    ) -> anyhow::Result<http::Response<Box<dyn AsyncRead + Sync + Send + Unpin>>>;
+}
+
 #[async_trait]
 /// `wasi:blobstore/blobstore` implementation
 pub trait Blobstore {
@@ -344,6 +393,24 @@ pub trait Blobstore {
         name: String,
     ) -> anyhow::Result<blobstore::container::ObjectMetadata>;
 
+    /// Handle `wasi:blobstore/blobstore.copy-object`
+    async fn copy_object(
+        &self,
+        src_container: &str,
+        src_name: String,
+        dest_container: &str,
+        dest_name: String,
+    ) -> anyhow::Result<()>;
+
+    /// Handle `wasi:blobstore/blobstore.move-object`
+    async fn move_object(
+        &self,
+        src_container: &str,
+        src_name: String,
+        dest_container: &str,
+        dest_name: String,
+    ) -> anyhow::Result<()>;
+
     /// Handle `wasi:blobstore/container.clear`
     async fn clear_container(&self, container: &str) -> anyhow::Result<()> {
         let names = self
@@ -527,6 +594,32 @@ impl Blobstore for Handler {
             .await
     }
 
+    #[instrument]
+    async fn copy_object(
+        &self,
+        src_container: &str,
+        src_name: String,
+        dest_container: &str,
+        dest_name: String,
+    ) -> anyhow::Result<()> {
+        self.proxy_blobstore("wasi:blobstore/blobstore.copy-object")?
+            .copy_object(src_container, src_name, dest_container, dest_name)
+            .await
+    }
+
+    #[instrument]
+    async fn move_object(
+        &self,
+        src_container: &str,
+        src_name: String,
+        dest_container: &str,
+        dest_name: String,
+    ) -> anyhow::Result<()> {
+        self.proxy_blobstore("wasi:blobstore/blobstore.move-object")?
+            .move_object(src_container, src_name, dest_container, dest_name)
+            .await
+    }
+
     #[instrument]
     async fn clear_container(&self, container: &str) -> anyhow::Result<()> {
         self.proxy_blobstore("wasi:blobstore/container.clear")?
@@ -686,6 +779,24 @@ impl IncomingHttp for Handler {
     }
 }
 
+#[async_trait]
+impl OutgoingHttp for Handler {
+    #[instrument(skip(request))]
+    async fn handle(
+        &self,
+        request: http::Request<Box<dyn AsyncRead + Sync + Send + Unpin>>,
+        options: Option<RequestOptions>,
+    ) -> anyhow::Result<http::Response<Box<dyn AsyncRead + Sync + Send + Unpin>>> {
+        proxy(
+            &self.outgoing_http,
+            "OutgoingHttp",
+            "wasi:http/outgoing-handler.handle",
+        )?
+        .handle(request, options)
+        .await
+    }
+}
+
 #[async_trait]
 impl Messaging for Handler {
     #[instrument(skip(body))]
@@ -738,6 +849,8 @@ pub(crate) struct HandlerBuilder {
     pub logging: Option<Arc<dyn Logging + Sync + Send>>,
     /// [`Messaging`] handler
     pub messaging: Option<Arc<dyn Messaging + Sync + Send>>,
+    /// [`OutgoingHttp`] handler
+    pub outgoing_http: Option<Arc<dyn OutgoingHttp + Sync + Send>>,
 }
 
 impl HandlerBuilder {
@@ -805,6 +918,17 @@ impl HandlerBuilder {
             ..self
         }
     }
+
+    /// Set [`OutgoingHttp`] handler
+    pub fn outgoing_http(
+        self,
+        outgoing_http: Arc<impl OutgoingHttp + Sync + Send + 'static>,
+    ) -> Self {
+        Self {
+            outgoing_http: Some(outgoing_http),
+            ..self
+        }
+    }
 }
 
 impl Debug for HandlerBuilder {
@@ -817,6 +941,7 @@ impl Debug for HandlerBuilder {
             .field("keyvalue_readwrite", &format_opt(&self.keyvalue_readwrite))
             .field("logging", &format_opt(&self.logging))
             .field("messaging", &format_opt(&self.messaging))
+            .field("outgoing_http", &format_opt(&self.outgoing_http))
             .finish()
     }
 }
@@ -831,6 +956,7 @@ impl From<Handler> for HandlerBuilder {
             keyvalue_readwrite,
             logging,
             messaging,
+            outgoing_http,
         }: Handler,
     ) -> Self {
         Self {
@@ -841,6 +967,7 @@ impl From<Handler> for HandlerBuilder {
             keyvalue_readwrite,
             logging,
             messaging,
+            outgoing_http,
         }
     }
 }
@@ -855,6 +982,7 @@ impl From<HandlerBuilder> for Handler {
             keyvalue_readwrite,
             logging,
             messaging,
+            outgoing_http,
         }: HandlerBuilder,
     ) -> Self {
         Self {
@@ -865,6 +993,7 @@ impl From<HandlerBuilder> for Handler {
             keyvalue_readwrite,
             logging,
             messaging,
+            outgoing_http,
         }
     }
 }
@@ -17,7 +17,12 @@ pub mod runtime;
 /// wasmCloud I/O functionality
 pub mod io;
 
-pub use actor::{Actor, Config as ActorConfig, Instance as ActorInstance};
+mod cache;
+
+pub use actor::{
+    is_timeout, Actor, Config as ActorConfig, Instance as ActorInstance, InstancePool,
+    Limits as ActorLimits, PooledInstance,
+};
 pub use runtime::*;
 
 pub use async_trait::async_trait;
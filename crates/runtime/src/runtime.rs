@@ -1,16 +1,24 @@
 use crate::actor::ModuleConfig;
+use crate::cache::ArtifactCache;
 use crate::capability::{
     builtin, Blobstore, Bus, IncomingHttp, KeyValueAtomic, KeyValueReadWrite, Logging, Messaging,
+    OutgoingHttp,
 };
 use crate::ActorConfig;
 
 use core::fmt;
 use core::fmt::Debug;
+use core::time::Duration;
 
-use std::sync::Arc;
+use std::path::PathBuf;
+use std::sync::{mpsc, Arc};
+use std::thread;
 
 use anyhow::Context;
 
+/// Interval, at which the engine epoch is incremented if actor execution time is limited
+pub(crate) const EPOCH_INTERVAL: Duration = Duration::from_millis(10);
+
 /// [`RuntimeBuilder`] used to configure and build a [Runtime]
 #[derive(Clone, Default)]
 pub struct RuntimeBuilder {
@@ -18,6 +26,7 @@ pub struct RuntimeBuilder {
     handler: builtin::HandlerBuilder,
     actor_config: ActorConfig,
     module_config: ModuleConfig,
+    artifact_cache: Option<(PathBuf, u64)>,
 }
 
 impl RuntimeBuilder {
@@ -32,6 +41,7 @@ impl RuntimeBuilder {
             handler: builtin::HandlerBuilder::default(),
             actor_config: ActorConfig::default(),
             module_config: ModuleConfig::default(),
+            artifact_cache: None,
         }
     }
 
@@ -53,6 +63,16 @@ impl RuntimeBuilder {
         }
     }
 
+    /// Cache precompiled actors in `dir`, evicting least recently used artifacts once their
+    /// total size exceeds `max_size` bytes. Artifacts are marked as used when written or loaded
+    #[must_use]
+    pub fn artifact_cache(self, dir: impl Into<PathBuf>, max_size: u64) -> Self {
+        Self {
+            artifact_cache: Some((dir.into(), max_size)),
+            ..self
+        }
+    }
+
     /// Set a [`Blobstore`] handler to use for all actor instances unless overriden for the instance
     #[must_use]
     pub fn blobstore(self, blobstore: Arc<impl Blobstore + Sync + Send + 'static>) -> Self {
@@ -125,19 +145,61 @@ impl RuntimeBuilder {
         }
     }
 
+    /// Set a [`OutgoingHttp`] handler to use for all actor instances unless overriden for the instance
+    #[must_use]
+    pub fn outgoing_http(
+        self,
+        outgoing_http: Arc<impl OutgoingHttp + Sync + Send + 'static>,
+    ) -> Self {
+        Self {
+            handler: self.handler.outgoing_http(outgoing_http),
+            ..self
+        }
+    }
+
     /// Turns this builder into a [`Runtime`]
     ///
     /// # Errors
     ///
     /// Fails if the configuration is not valid
-    pub fn build(self) -> anyhow::Result<Runtime> {
+    pub fn build(mut self) -> anyhow::Result<Runtime> {
+        if self.actor_config.max_fuel.is_some() {
+            self.engine_config.consume_fuel(true);
+        }
+        if self.actor_config.max_execution_time.is_some() {
+            self.engine_config.epoch_interruption(true);
+        }
         let engine =
             wasmtime::Engine::new(&self.engine_config).context("failed to construct engine")?;
+        let artifact_cache = self
+            .artifact_cache
+            .map(|(dir, max_size)| ArtifactCache::new(&engine, dir, max_size))
+            .transpose()
+            .context("failed to initialize artifact cache")?;
+        let epoch_ticker = if self.actor_config.max_execution_time.is_some() {
+            let (tx, rx) = mpsc::sync_channel::<()>(0);
+            let engine = engine.clone();
+            thread::Builder::new()
+                .name("wasmcloud-epoch".into())
+                .spawn(move || {
+                    // The ticker stops once all senders, i.e. [Runtime] clones, are dropped
+                    while let Err(mpsc::RecvTimeoutError::Timeout) = rx.recv_timeout(EPOCH_INTERVAL)
+                    {
+                        engine.increment_epoch();
+                    }
+                })
+                .context("failed to spawn epoch ticker thread")?;
+            Some(tx)
+        } else {
+            None
+        };
         Ok(Runtime {
             engine,
             handler: self.handler,
             actor_config: self.actor_config,
             module_config: self.module_config,
+            artifact_cache,
+            _epoch_ticker: epoch_ticker,
         })
     }
 }
@@ -157,6 +219,8 @@ pub struct Runtime {
     pub(crate) handler: builtin::HandlerBuilder,
     pub(crate) actor_config: ActorConfig,
     pub(crate) module_config: ModuleConfig,
+    pub(crate) artifact_cache: Option<ArtifactCache>,
+    _epoch_ticker: Option<mpsc::SyncSender<()>>,
 }
 
 impl Debug for Runtime {
@@ -165,6 +229,7 @@ impl Debug for Runtime {
             .field("handler", &self.handler)
             .field("actor_config", &self.actor_config)
             .field("module_config", &self.module_config)
+            .field("artifact_cache", &self.artifact_cache)
             .field("runtime", &"wasmtime")
             .finish_non_exhaustive()
     }
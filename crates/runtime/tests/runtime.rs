@@ -145,14 +145,32 @@ impl capability::Bus for Handler {
 
     async fn call(
         &self,
-        _target: Option<capability::TargetEntity>,
-        _operation: String,
+        target: Option<capability::TargetEntity>,
+        operation: String,
     ) -> anyhow::Result<(
         Pin<Box<dyn futures::Future<Output = anyhow::Result<(), String>> + Send>>,
         Box<dyn tokio::io::AsyncWrite + Sync + Send + Unpin>,
         Box<dyn tokio::io::AsyncRead + Sync + Send + Unpin>,
     )> {
-        panic!("should not have been called")
+        match (target, operation.as_str()) {
+            (None, "test-actors:foobar/actor.echo") => {
+                let (mut req_r, req_w) = tokio::io::duplex(1 << 16);
+                let (res_r, mut res_w) = tokio::io::duplex(1 << 16);
+                Ok((
+                    Box::pin(async move {
+                        tokio::io::copy(&mut req_r, &mut res_w)
+                            .await
+                            .map_err(|e| e.to_string())?;
+                        Ok(())
+                    }),
+                    Box::new(req_w),
+                    Box::new(res_r),
+                ))
+            }
+            (target, operation) => {
+                panic!("`call` with target `{target:?}` and operation `{operation}` should not have been called")
+            }
+        }
     }
 
     async fn call_sync(
@@ -252,9 +270,9 @@ impl capability::Bus for Handler {
                     .get("", key)
                     .await
                     .expect("failed to call `get`");
-                let mut value = String::new();
+                let mut value = vec![];
                 reader
-                    .read_to_string(&mut value)
+                    .read_to_end(&mut value)
                     .await
                     .expect("failed to read value");
                 let buf = rmp_serde::to_vec_named(&wasmcloud_compat::keyvalue::GetResponse {
@@ -309,6 +327,21 @@ impl capability::Bus for Handler {
                 Ok(buf)
             }
 
+            (
+                Some(capability::TargetEntity::Link(Some(name))),
+                "wasmcloud:keyvalue/KeyValue.CompareAndSwap",
+            ) if name == "keyvalue" => {
+                let wasmcloud_compat::keyvalue::CompareAndSwapRequest { key, old, new } =
+                    rmp_serde::from_slice(&payload).expect("failed to decode payload");
+                let ok = self
+                    .keyvalue_atomic
+                    .compare_and_swap("", key, old, new)
+                    .await
+                    .expect("failed to call `compare_and_swap`");
+                let buf = rmp_serde::to_vec_named(&ok).expect("failed to encode reply");
+                Ok(buf)
+            }
+
             (
                 Some(capability::TargetEntity::Actor(capability::ActorIdentifier::Alias(name))),
                 "test-actors:foobar/actor.foobar" // component invocation
@@ -24,7 +24,7 @@ use tracing_subscriber::{
     filter::LevelFilter,
     layer::{Layered, SubscriberExt},
     registry::LookupSpan,
-    EnvFilter, Layer, Registry,
+    reload, EnvFilter, Layer, Registry,
 };
 
 use wasmcloud_core::{logging::Level, OtelConfig};
@@ -61,6 +61,11 @@ impl<'a> Drop for LockedWriter<'a> {
 
 static STDERR: OnceCell<std::io::Stderr> = OnceCell::new();
 
+/// Level filter of the global subscriber, which can be updated using [`set_log_level`]
+type ReloadableLevelFilter = reload::Layer<EnvFilter, Registry>;
+
+static LEVEL_FILTER: OnceCell<reload::Handle<EnvFilter, Registry>> = OnceCell::new();
+
 #[cfg(feature = "otel")]
 const TRACING_PATH: &str = "/v1/traces";
 
@@ -107,7 +112,7 @@ pub fn configure_tracing(
         .map_err(|_| anyhow::anyhow!("stderr already initialized"))?;
 
     let base_reg = tracing_subscriber::Registry::default();
-    let level_filter = get_level_filter(log_level_override);
+    let level_filter = get_reloadable_level_filter(log_level_override)?;
 
     let res = if structured_logging_enabled {
         let log_layer = get_json_log_layer()?;
@@ -134,7 +139,7 @@ pub fn configure_tracing(
         .map_err(|_| anyhow::anyhow!("stderr already initialized"))?;
 
     let base_reg = tracing_subscriber::Registry::default();
-    let level_filter = get_level_filter(log_level_override);
+    let level_filter = get_reloadable_level_filter(log_level_override)?;
 
     let exporter = otel_config
         .traces_exporter
@@ -240,7 +245,7 @@ fn get_tracer(mut tracing_endpoint: String, service_name: String) -> Result<Trac
         .install_batch(opentelemetry::runtime::Tokio)
 }
 
-fn get_default_log_layer() -> anyhow::Result<impl Layer<Layered<EnvFilter, Registry>>> {
+fn get_default_log_layer() -> anyhow::Result<impl Layer<Layered<ReloadableLevelFilter, Registry>>> {
     let stderr = STDERR.get().context("stderr not initialized")?;
     Ok(tracing_subscriber::fmt::layer()
         .with_writer(LockedWriter::new)
@@ -249,7 +254,7 @@ fn get_default_log_layer() -> anyhow::Result<impl Layer<Layered<EnvFilter, Regis
         .fmt_fields(DefaultFields::new()))
 }
 
-fn get_json_log_layer() -> anyhow::Result<impl Layer<Layered<EnvFilter, Registry>>> {
+fn get_json_log_layer() -> anyhow::Result<impl Layer<Layered<ReloadableLevelFilter, Registry>>> {
     let stderr = STDERR.get().context("stderr not initialized")?;
     Ok(tracing_subscriber::fmt::layer()
         .with_writer(LockedWriter::new)
@@ -258,6 +263,25 @@ fn get_json_log_layer() -> anyhow::Result<impl Layer<Layered<EnvFilter, Registry
         .fmt_fields(JsonFields::new()))
 }
 
+fn get_reloadable_level_filter(
+    log_level_override: Option<&Level>,
+) -> anyhow::Result<ReloadableLevelFilter> {
+    let (level_filter, handle) = reload::Layer::new(get_level_filter(log_level_override));
+    LEVEL_FILTER
+        .set(handle)
+        .map_err(|_| anyhow::anyhow!("level filter already initialized"))?;
+    Ok(level_filter)
+}
+
+/// Updates the log level of the global subscriber configured using [`configure_tracing`]
+pub fn set_log_level(log_level: &Level) -> anyhow::Result<()> {
+    LEVEL_FILTER
+        .get()
+        .context("tracing not configured")?
+        .reload(get_level_filter(Some(log_level)))
+        .context("failed to update log level")
+}
+
 fn get_level_filter(log_level_override: Option<&Level>) -> EnvFilter {
     if let Some(log_level) = log_level_override {
         let level = wasi_level_to_tracing_level(log_level);
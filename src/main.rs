@@ -1,25 +1,39 @@
 #![warn(clippy::pedantic)]
 
+use core::num::NonZeroUsize;
+
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
 use anyhow::{self, Context};
 use clap::Parser;
 use nkeys::KeyPair;
-use tokio::{select, signal};
-use tracing::Level as TracingLogLevel;
+use tokio::{fs, select, signal, time};
+use tracing::{error, info, Level as TracingLogLevel};
 use wasmcloud_core::logging::Level as WasmcloudLogLevel;
 use wasmcloud_core::OtelConfig;
 use wasmcloud_host::oci::Config as OciConfig;
 use wasmcloud_host::url::Url;
-use wasmcloud_host::wasmbus::config::PolicyService as PolicyServiceConfig;
+use wasmcloud_host::wasmbus::config::{
+    Capacity, File as HostConfigFile, PolicyService as PolicyServiceConfig, ProviderHealthAction,
+    ProviderHealthCheck, ProviderRestartPolicy, ProviderSupervision,
+};
 use wasmcloud_host::WasmbusHostConfig;
+use wasmcloud_runtime::ActorLimits;
 use wasmcloud_tracing::configure_tracing;
 
 #[derive(Debug, Parser)]
 #[allow(clippy::struct_excessive_bools)]
 #[command(version, about, long_about = None)]
 struct Args {
+    /// Path to a TOML host configuration file, settings present in the file take precedence over command line arguments and environment variables
+    #[clap(long = "config", env = "WASMCLOUD_CONFIG")]
+    config: Option<PathBuf>,
+    /// Interval, in milliseconds, between checks of the host configuration file for changes. Changes are also applied on SIGHUP
+    #[clap(long = "config-watch-interval-ms", default_value = "5000", env = "WASMCLOUD_CONFIG_WATCH_INTERVAL_MS", value_parser = parse_duration)]
+    config_watch_interval: Duration,
     /// Controls the verbosity of logs from the wasmCloud host
     #[clap(long = "log-level", alias = "structured-log-level", default_value_t = TracingLogLevel::INFO, env = "WASMCLOUD_LOG_LEVEL")]
     pub log_level: TracingLogLevel,
@@ -60,6 +74,81 @@ struct Args {
     /// Delay, in milliseconds, between requesting a provider shut down and forcibly terminating its process
     #[clap(long = "provider-shutdown-delay", default_value = "300", env = "WASMCLOUD_PROV_SHUTDOWN_DELAY_MS", value_parser = parse_duration)]
     provider_shutdown_delay: Duration,
+    /// Policy determining whether a capability provider is restarted once it exits, one of `never`, `on-failure` or `always`
+    #[clap(
+        long = "provider-restart-policy",
+        default_value = "on-failure",
+        env = "WASMCLOUD_PROV_RESTART_POLICY"
+    )]
+    provider_restart_policy: ProviderRestartPolicy,
+    /// Maximum amount of consecutive restarts of a capability provider before it is considered stopped
+    #[clap(
+        long = "provider-max-restarts",
+        default_value = "5",
+        env = "WASMCLOUD_PROV_MAX_RESTARTS"
+    )]
+    provider_max_restarts: u32,
+    /// Delay, in milliseconds, before the first restart of a capability provider, doubled on every consecutive restart
+    #[clap(long = "provider-restart-backoff", default_value = "1000", env = "WASMCLOUD_PROV_RESTART_BACKOFF_MS", value_parser = parse_duration)]
+    provider_restart_backoff: Duration,
+    /// Maximum delay, in milliseconds, between consecutive restarts of a capability provider
+    #[clap(long = "provider-max-restart-backoff", default_value = "60000", env = "WASMCLOUD_PROV_MAX_RESTART_BACKOFF_MS", value_parser = parse_duration)]
+    provider_max_restart_backoff: Duration,
+    /// Maximum time, in milliseconds, to wait for in-flight invocations of actor instances being stopped or replaced to complete
+    #[clap(long = "drain-timeout-ms", default_value = "10000", env = "WASMCLOUD_DRAIN_TIMEOUT_MS", value_parser = parse_duration)]
+    drain_timeout: Duration,
+    /// Amount of actor instances replaced at a time during rolling actor updates
+    #[clap(
+        long = "actor-update-batch-size",
+        default_value = "1",
+        env = "WASMCLOUD_ACTOR_UPDATE_BATCH_SIZE"
+    )]
+    actor_update_batch_size: NonZeroUsize,
+    /// Interval, in milliseconds, between consecutive capability provider health checks
+    #[clap(long = "provider-health-interval", default_value = "30000", env = "WASMCLOUD_PROV_HEALTH_INTERVAL_MS", value_parser = parse_duration)]
+    provider_health_interval: Duration,
+    /// Time, in milliseconds, to wait for a capability provider health check response
+    #[clap(long = "provider-health-timeout", default_value = "10000", env = "WASMCLOUD_PROV_HEALTH_TIMEOUT_MS", value_parser = parse_duration)]
+    provider_health_timeout: Duration,
+    /// Amount of consecutive failed health checks, after which the provider health action is taken, 0 to never take action
+    #[clap(
+        long = "provider-health-failure-threshold",
+        default_value = "3",
+        env = "WASMCLOUD_PROV_HEALTH_FAILURE_THRESHOLD"
+    )]
+    provider_health_failure_threshold: u32,
+    /// Action taken once a capability provider reaches the health check failure threshold, one of `event`, `restart` or `stop`
+    #[clap(
+        long = "provider-health-action",
+        default_value = "restart",
+        env = "WASMCLOUD_PROV_HEALTH_ACTION"
+    )]
+    provider_health_action: ProviderHealthAction,
+    /// Interval, in milliseconds, between host heartbeats
+    #[clap(long = "heartbeat-interval-ms", default_value = "30000", env = "WASMCLOUD_HEARTBEAT_INTERVAL_MS", value_parser = parse_duration)]
+    heartbeat_interval: Duration,
+    /// Maximum amount of distinct actors, after which the host declines actor auctions and rejects starting new actors
+    #[clap(long = "max-actors", env = "WASMCLOUD_MAX_ACTORS")]
+    max_actors: Option<usize>,
+    /// Maximum total amount of actor instances, after which the host declines actor auctions and rejects starting new instances
+    #[clap(long = "max-actor-instances", env = "WASMCLOUD_MAX_ACTOR_INSTANCES")]
+    max_actor_instances: Option<usize>,
+    /// Maximum amount of capability provider processes, after which the host declines provider auctions and rejects starting new providers
+    #[clap(long = "max-providers", env = "WASMCLOUD_MAX_PROVIDERS")]
+    max_providers: Option<usize>,
+    /// Minimum amount of memory, in bytes, which must be available on the system for the host to accept auctions
+    #[clap(
+        long = "min-memory-headroom-bytes",
+        env = "WASMCLOUD_MIN_MEMORY_HEADROOM_BYTES"
+    )]
+    min_memory_headroom_bytes: Option<u64>,
+    /// A comma-separated list of authorities (`host` or `host:port`), to which actor outgoing HTTP requests are sent directly by the host instead of a linked `wasmcloud:httpclient` provider
+    #[clap(
+        long = "allowed-http-authorities",
+        env = "WASMCLOUD_ALLOWED_HTTP_AUTHORITIES",
+        value_delimiter = ','
+    )]
+    allowed_http_authorities: Vec<String>,
     /// Determines whether OCI images tagged latest are allowed to be pulled from OCI registries and started
     #[clap(long = "allow-latest", env = "WASMCLOUD_OCI_ALLOW_LATEST")]
     allow_latest: bool,
@@ -87,12 +176,43 @@ struct Args {
         env = "WASMCLOUD_ALLOW_FILE_LOAD"
     )]
     allow_file_load: bool,
+    /// Maximum wall-clock time, in milliseconds, a single actor invocation is allowed to run for
+    #[clap(long = "max-execution-time-ms", env = "WASMCLOUD_MAX_EXECUTION_TIME_MS", value_parser = parse_duration)]
+    max_execution_time: Option<Duration>,
+    /// Maximum amount of fuel a single actor invocation is allowed to consume
+    #[clap(long = "max-fuel", env = "WASMCLOUD_MAX_FUEL")]
+    max_fuel: Option<u64>,
+    /// Directory to cache precompiled actors in, which speeds up subsequent starts of the same actors
+    #[clap(long = "artifact-cache-dir", env = "WASMCLOUD_ARTIFACT_CACHE_DIR")]
+    artifact_cache_dir: Option<PathBuf>,
+    /// Maximum total size, in bytes, of precompiled actors in the artifact cache
+    #[clap(
+        long = "artifact-cache-max-bytes",
+        default_value = "1073741824",
+        env = "WASMCLOUD_ARTIFACT_CACHE_MAX_BYTES"
+    )]
+    artifact_cache_max_bytes: u64,
+    /// Maximum size, in bytes, of each linear memory of an actor instance
+    #[clap(long = "max-memory-bytes", env = "WASMCLOUD_MAX_MEMORY_BYTES")]
+    max_memory_bytes: Option<usize>,
+    /// Maximum amount of elements in each table of an actor instance
+    #[clap(long = "max-table-elements", env = "WASMCLOUD_MAX_TABLE_ELEMENTS")]
+    max_table_elements: Option<u32>,
+    /// Maximum amount of WebAssembly instances within an actor instance
+    #[clap(long = "max-wasm-instances", env = "WASMCLOUD_MAX_WASM_INSTANCES")]
+    max_wasm_instances: Option<usize>,
     /// Enable JSON structured logging from the wasmCloud host
     #[clap(
         long = "enable-structured-logging",
         env = "WASMCLOUD_STRUCTURED_LOGGING_ENABLED"
     )]
     enable_structured_logging: bool,
+    /// Publish output of capability providers on `wasmbus.log.{lattice_prefix}.{provider_id}.{link_name}` NATS subjects
+    #[clap(
+        long = "publish-provider-output",
+        env = "WASMCLOUD_PUBLISH_PROVIDER_OUTPUT"
+    )]
+    publish_provider_output: bool,
 
     /// An IP address or DNS name to use to connect to NATS for Control Interface (CTL) messages, defaults to the value supplied to --nats-host if not supplied
     #[clap(long = "ctl-host", env = "WASMCLOUD_CTL_HOST", hide = true)]
@@ -248,14 +368,6 @@ async fn main() -> anyhow::Result<()> {
         exporter_otlp_endpoint: args.otel_exporter_otlp_endpoint,
     };
     let log_level = WasmcloudLogLevel::from(args.log_level);
-    if let Err(e) = configure_tracing(
-        "wasmCloud Host".to_string(),
-        &otel_config,
-        args.enable_structured_logging,
-        Some(&log_level),
-    ) {
-        eprintln!("Failed to configure tracing: {e}");
-    };
 
     let ctl_nats_url = Url::parse(&format!(
         "nats://{}:{}",
@@ -332,7 +444,7 @@ async fn main() -> anyhow::Result<()> {
         policy_changes_topic: args.policy_changes_topic,
         policy_timeout_ms: args.policy_timeout_ms,
     };
-    let (host, shutdown) = Box::pin(wasmcloud_host::wasmbus::Host::new(WasmbusHostConfig {
+    let mut config = WasmbusHostConfig {
         ctl_nats_url,
         lattice_prefix: args.lattice_prefix,
         host_key,
@@ -360,9 +472,69 @@ async fn main() -> anyhow::Result<()> {
         enable_structured_logging: args.enable_structured_logging,
         otel_config,
         policy_service_config,
-    }))
-    .await
-    .context("failed to initialize host")?;
+        max_execution_time: args.max_execution_time,
+        max_fuel: args.max_fuel,
+        actor_limits: ActorLimits {
+            max_memory_bytes: args.max_memory_bytes,
+            max_table_elements: args.max_table_elements,
+            max_instances: args.max_wasm_instances,
+        },
+        artifact_cache_dir: args.artifact_cache_dir,
+        artifact_cache_max_size: args.artifact_cache_max_bytes,
+        drain_timeout: args.drain_timeout,
+        actor_update_batch_size: args.actor_update_batch_size,
+        provider_health_check: ProviderHealthCheck {
+            interval: args.provider_health_interval,
+            timeout: args.provider_health_timeout,
+            failure_threshold: args.provider_health_failure_threshold,
+            action: args.provider_health_action,
+        },
+        publish_provider_output: args.publish_provider_output,
+        allowed_http_authorities: args.allowed_http_authorities,
+        provider_supervision: ProviderSupervision {
+            restart_policy: args.provider_restart_policy,
+            max_restarts: args.provider_max_restarts,
+            initial_backoff: args.provider_restart_backoff,
+            max_backoff: args.provider_max_restart_backoff,
+        },
+        labels: HashMap::default(),
+        heartbeat_interval: args.heartbeat_interval,
+        capacity: Capacity {
+            max_actors: args.max_actors,
+            max_actor_instances: args.max_actor_instances,
+            max_providers: args.max_providers,
+            min_memory_headroom: args.min_memory_headroom_bytes,
+        },
+    };
+    if let Some(path) = &args.config {
+        HostConfigFile::load(path)
+            .with_context(|| {
+                format!(
+                    "failed to load host configuration from `{}`",
+                    path.display()
+                )
+            })?
+            .apply(&mut config);
+    }
+    if let Err(e) = configure_tracing(
+        "wasmCloud Host".to_string(),
+        &config.otel_config,
+        config.enable_structured_logging,
+        Some(&config.log_level),
+    ) {
+        eprintln!("Failed to configure tracing: {e}");
+    };
+
+    let (host, shutdown) = Box::pin(wasmcloud_host::wasmbus::Host::new(config))
+        .await
+        .context("failed to initialize host")?;
+    let config_watch = args.config.map(|path| {
+        tokio::spawn(watch_config(
+            Arc::clone(&host),
+            path,
+            args.config_watch_interval,
+        ))
+    });
     #[cfg(unix)]
     let mut terminate = signal::unix::signal(signal::unix::SignalKind::terminate())?;
     #[cfg(unix)]
@@ -376,10 +548,66 @@ async fn main() -> anyhow::Result<()> {
         sig = signal::ctrl_c() => sig.context("failed to wait for Ctrl-C")?,
         _ = host.stopped() => {},
     };
+    if let Some(config_watch) = config_watch {
+        config_watch.abort();
+    }
     shutdown.await.context("failed to shutdown host")?;
     Ok(())
 }
 
+/// Reloads the host configuration file at `path` whenever its modification time changes, which
+/// is checked every `interval`, and, on unix, on SIGHUP
+async fn watch_config(host: Arc<wasmcloud_host::wasmbus::Host>, path: PathBuf, interval: Duration) {
+    async fn modified(path: &PathBuf) -> Option<SystemTime> {
+        fs::metadata(path).await.and_then(|md| md.modified()).ok()
+    }
+
+    #[cfg(unix)]
+    let mut hangup = match signal::unix::signal(signal::unix::SignalKind::hangup()) {
+        Ok(hangup) => Some(hangup),
+        Err(e) => {
+            error!(error = %e, "failed to listen for SIGHUP");
+            None
+        }
+    };
+    let mut interval = time::interval(interval);
+    interval.set_missed_tick_behavior(time::MissedTickBehavior::Delay);
+    // the first tick completes immediately
+    interval.tick().await;
+    let mut last_modified = modified(&path).await;
+    loop {
+        #[cfg(unix)]
+        let forced = select! {
+            _ = interval.tick() => false,
+            Some(()) = async {
+                match hangup.as_mut() {
+                    Some(hangup) => hangup.recv().await,
+                    None => std::future::pending().await,
+                }
+            } => true,
+        };
+        #[cfg(not(unix))]
+        let forced = {
+            interval.tick().await;
+            false
+        };
+        let current = modified(&path).await;
+        if !forced && current == last_modified {
+            continue;
+        }
+        last_modified = current;
+        info!(path = %path.display(), "reloading host configuration");
+        match HostConfigFile::load(&path) {
+            Ok(file) => {
+                if let Err(e) = host.reload_config(&file).await {
+                    error!(error = ?e, "failed to apply host configuration");
+                }
+            }
+            Err(e) => error!(error = ?e, "failed to load host configuration"),
+        }
+    }
+}
+
 fn parse_duration(arg: &str) -> anyhow::Result<Duration> {
     arg.parse()
         .map(Duration::from_millis)
@@ -188,7 +188,7 @@ impl exports::wasmcloud::bus::guest::Guest for Actor {
         let keyvalue::GetResponse { value, exists } =
             rmp_serde::from_slice(&buf).expect("failed to decode `Get` response");
         assert!(exists);
-        assert_eq!(value, "bar");
+        assert_eq!(value, b"bar");
 
         let buf = rmp_serde::to_vec_named(&foo_key).expect("failed to encode string");
         let buf = bus::host::call_sync(
@@ -204,7 +204,7 @@ impl exports::wasmcloud::bus::guest::Guest for Actor {
 
         let buf = rmp_serde::to_vec_named(&keyvalue::SetRequest {
             key: "result".into(),
-            value: body.clone(),
+            value: body.clone().into_bytes(),
             expires: 0,
         })
         .expect("failed to encode `SetRequest`");
@@ -252,6 +252,24 @@ impl exports::wasmcloud::bus::guest::Guest for Actor {
             rmp_serde::from_slice(&buf).expect("failed to decode `Increment` response");
         assert_eq!(value, 42);
 
+        for (old, new, expected) in [(42, 4242, true), (42, 0, false), (4242, 42, true)] {
+            let buf = rmp_serde::to_vec_named(&keyvalue::CompareAndSwapRequest {
+                key: counter_key.clone(),
+                old,
+                new,
+            })
+            .expect("failed to encode `CompareAndSwapRequest`");
+            let buf = bus::host::call_sync(
+                Some(&keyvalue_target),
+                "wasmcloud:keyvalue/KeyValue.CompareAndSwap",
+                &buf,
+            )
+            .expect("failed to compare and swap `counter`");
+            let swapped: bool =
+                rmp_serde::from_slice(&buf).expect("failed to decode `CompareAndSwap` response");
+            assert_eq!(swapped, expected);
+        }
+
         // TODO: Use blobstore
 
         bus::host::call_sync(
@@ -11,7 +11,9 @@ use std::io::{Read, Write};
 use serde::Deserialize;
 use serde_json::json;
 use wasi::http::types;
+use wasmcloud_actor::wasi::io::streams;
 use wasmcloud_actor::wasi::logging::logging;
+use wasmcloud_actor::wasi::poll::poll;
 use wasmcloud_actor::wasi::random::random;
 use wasmcloud_actor::wasi::{blobstore, keyvalue};
 use wasmcloud_actor::wasmcloud::bus::lattice::TargetEntity;
@@ -280,9 +282,18 @@ impl exports::wasi::http::incoming_handler::Guest for Actor {
             .expect("failed to increment `counter`");
         assert_eq!(value, 42);
 
-        // TODO: Verify return value when implemented for all hosts
-        let _ = keyvalue::atomic::compare_and_swap(bucket, &counter_key, 42, 4242);
-        let _ = keyvalue::atomic::compare_and_swap(bucket, &counter_key, 4242, 42);
+        let swapped = keyvalue::atomic::compare_and_swap(bucket, &counter_key, 42, 4242)
+            .map_err(keyvalue::wasi_cloud_error::trace)
+            .expect("failed to compare and swap `counter`");
+        assert!(swapped);
+        let swapped = keyvalue::atomic::compare_and_swap(bucket, &counter_key, 42, 0)
+            .map_err(keyvalue::wasi_cloud_error::trace)
+            .expect("failed to compare and swap `counter`");
+        assert!(!swapped);
+        let swapped = keyvalue::atomic::compare_and_swap(bucket, &counter_key, 4242, 42)
+            .map_err(keyvalue::wasi_cloud_error::trace)
+            .expect("failed to compare and swap `counter`");
+        assert!(swapped);
 
         bus::lattice::set_target(
             Some(&TargetEntity::Link(Some("blobstore".into()))),
@@ -338,6 +349,48 @@ impl exports::wasi::http::incoming_handler::Guest for Actor {
         blobstore::container::write_data(created_container, &result_key, result_value)
             .expect("failed to write `result`");
 
+        let copy_key = String::from("result-copy");
+        blobstore::blobstore::copy_object(
+            &blobstore::types::ObjectId {
+                container: container_name.clone(),
+                object: result_key.clone(),
+            },
+            &blobstore::types::ObjectId {
+                container: container_name.clone(),
+                object: copy_key.clone(),
+            },
+        )
+        .expect("failed to copy `result`");
+        assert!(
+            blobstore::container::has_object(created_container, &result_key)
+                .expect("failed to check whether `result` object exists")
+        );
+        assert!(
+            blobstore::container::has_object(created_container, &copy_key)
+                .expect("failed to check whether `result-copy` object exists")
+        );
+
+        let moved_key = String::from("result-moved");
+        blobstore::blobstore::move_object(
+            &blobstore::types::ObjectId {
+                container: container_name.clone(),
+                object: copy_key.clone(),
+            },
+            &blobstore::types::ObjectId {
+                container: container_name.clone(),
+                object: moved_key.clone(),
+            },
+        )
+        .expect("failed to move `result-copy`");
+        assert!(
+            !blobstore::container::has_object(created_container, &copy_key)
+                .expect("failed to check whether `result-copy` object exists")
+        );
+        assert!(
+            blobstore::container::has_object(created_container, &moved_key)
+                .expect("failed to check whether `result-moved` object exists")
+        );
+
         // TODO: Expand blobstore testing procedure
 
         bus::host::call_sync(
@@ -360,5 +413,27 @@ impl exports::wasi::http::incoming_handler::Guest for Actor {
         .expect("failed to invoke `test-actors:foobar/actor.foobar` on an actor");
         let res: String = serde_json::from_slice(&res).expect("failed to decode response");
         assert_eq!(res, "foobar");
+
+        let (res, stdin, stdout) = bus::host::call(None, "test-actors:foobar/actor.echo")
+            .expect("failed to call `test-actors:foobar/actor.echo`");
+        let mut stdin_writer = OutputStreamWriter::from(stdin);
+        stdin_writer
+            .write_all(b"echo")
+            .expect("failed to write call request");
+        stdin_writer.flush().expect("failed to flush call request");
+        streams::drop_output_stream(stdin);
+
+        let pollable = bus::host::listen_to_future_result(res);
+        assert_eq!(poll::poll_oneoff(&[pollable]), [true]);
+        poll::drop_pollable(pollable);
+        assert_eq!(bus::host::future_result_get(res), Some(Ok(())));
+        bus::host::drop_future_result(res);
+
+        let mut buf = vec![];
+        InputStreamReader::from(stdout)
+            .read_to_end(&mut buf)
+            .expect("failed to read call response");
+        streams::drop_input_stream(stdout);
+        assert_eq!(buf, b"echo");
     }
 }
@@ -31,6 +31,7 @@ use wasmcloud_control_interface::{
     ActorAuctionAck, ActorDescription, ActorInstance, ClientBuilder, CtlOperationAck,
     GetClaimsResponse, Host as HostInfo, HostInventory, ProviderAuctionAck,
 };
+use wasmcloud_host::wasmbus::config::Capacity;
 use wasmcloud_host::wasmbus::{Host, HostConfig};
 
 fn tempdir() -> anyhow::Result<TempDir> {
@@ -1476,3 +1477,190 @@ expected: {expected_labels:?}"#
 
     Ok(())
 }
+
+async fn assert_capacity_exceeded(
+    events: &mut async_nats::Subscriber,
+    host_key: &KeyPair,
+    limit: &str,
+    max: usize,
+    requested: usize,
+) -> anyhow::Result<()> {
+    #[derive(Deserialize)]
+    struct Event {
+        #[serde(rename = "type")]
+        ty: String,
+        data: serde_json::Value,
+    }
+
+    loop {
+        let msg = tokio::time::timeout(Duration::from_secs(5), events.next())
+            .await
+            .context("timed out waiting for `host_capacity_exceeded` event")?
+            .context("event subscription closed")?;
+        let Event { ty, data } =
+            serde_json::from_slice(&msg.payload).context("failed to decode event")?;
+        if ty != "com.wasmcloud.lattice.host_capacity_exceeded" {
+            continue;
+        }
+        ensure!(
+            data == serde_json::json!({
+                "host_id": host_key.public_key(),
+                "limit": limit,
+                "max": max,
+                "requested": requested,
+            }),
+            "invalid event data: {data}"
+        );
+        return Ok(());
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn host_capacity() -> anyhow::Result<()> {
+    let (nats_server, stop_nats_tx, nats_url) = start_nats().await?;
+    let nats_client = async_nats::connect_with_options(
+        nats_url.as_str(),
+        async_nats::ConnectOptions::new().retry_on_initial_connect(),
+    )
+    .await
+    .context("failed to connect to NATS")?;
+
+    const TEST_PREFIX: &str = "test-capacity";
+    let ctl_client = ClientBuilder::new(nats_client.clone())
+        .lattice_prefix(TEST_PREFIX.to_string())
+        .build()
+        .await
+        .map_err(|e| anyhow!(e).context("failed to build control interface client"))?;
+    let mut events = nats_client
+        .subscribe(format!("wasmbus.evt.{TEST_PREFIX}"))
+        .await
+        .context("failed to subscribe to events")?;
+
+    let host_key = Arc::new(KeyPair::new_server());
+    let (host, shutdown) = Host::new(HostConfig {
+        ctl_nats_url: nats_url.clone(),
+        rpc_nats_url: nats_url.clone(),
+        prov_rpc_nats_url: nats_url.clone(),
+        lattice_prefix: TEST_PREFIX.to_string(),
+        host_key: Some(Arc::clone(&host_key)),
+        allow_file_load: true,
+        capacity: Capacity {
+            max_actors: Some(1),
+            max_actor_instances: Some(2),
+            max_providers: Some(0),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .await
+    .context("failed to initialize host")?;
+
+    let module_actor_url = Url::from_file_path(test_actors::RUST_BUILTINS_MODULE_REACTOR_SIGNED)
+        .expect("failed to construct module actor ref");
+    let foobar_actor_url =
+        Url::from_file_path(test_actors::RUST_FOOBAR_COMPONENT_COMMAND_PREVIEW2_SIGNED)
+            .expect("failed to construct foobar actor ref");
+    let kvredis_provider_url = Url::from_file_path(test_providers::RUST_KVREDIS)
+        .expect("failed to construct provider ref");
+
+    assert_scale_actor(
+        &ctl_client,
+        &nats_client,
+        TEST_PREFIX,
+        &host_key,
+        &module_actor_url,
+        None,
+        1,
+    )
+    .await?;
+
+    // Scaling beyond the maximum amount of actor instances is rejected
+    let CtlOperationAck { accepted, error } = ctl_client
+        .scale_actor(
+            &host_key.public_key(),
+            module_actor_url.as_str(),
+            "",
+            3,
+            None,
+        )
+        .await
+        .map_err(|e| anyhow!(e).context("failed to scale actor"))?;
+    ensure!(!accepted);
+    ensure!(
+        error == "host capacity exceeded: 3 actor_instances requested, at most 2 allowed",
+        "invalid error: {error}"
+    );
+    assert_capacity_exceeded(&mut events, &host_key, "actor_instances", 2, 3).await?;
+
+    // Starting and scaling another actor is rejected once the maximum amount of actors is reached
+    let CtlOperationAck { accepted, error } = ctl_client
+        .start_actor(&host_key.public_key(), foobar_actor_url.as_str(), 1, None)
+        .await
+        .map_err(|e| anyhow!(e).context("failed to start actor"))?;
+    ensure!(!accepted);
+    ensure!(
+        error == "host capacity exceeded: 2 actors requested, at most 1 allowed",
+        "invalid error: {error}"
+    );
+    assert_capacity_exceeded(&mut events, &host_key, "actors", 1, 2).await?;
+
+    let CtlOperationAck { accepted, error } = ctl_client
+        .scale_actor(
+            &host_key.public_key(),
+            foobar_actor_url.as_str(),
+            "",
+            1,
+            None,
+        )
+        .await
+        .map_err(|e| anyhow!(e).context("failed to scale actor"))?;
+    ensure!(!accepted);
+    ensure!(
+        error == "host capacity exceeded: 2 actors requested, at most 1 allowed",
+        "invalid error: {error}"
+    );
+    assert_capacity_exceeded(&mut events, &host_key, "actors", 1, 2).await?;
+
+    // Commands within the limits are still accepted
+    assert_start_actor(
+        &ctl_client,
+        &nats_client,
+        TEST_PREFIX,
+        &host_key,
+        &module_actor_url,
+        1,
+    )
+    .await?;
+
+    let CtlOperationAck { accepted, error } = ctl_client
+        .start_provider(
+            &host_key.public_key(),
+            kvredis_provider_url.as_str(),
+            None,
+            None,
+            None,
+        )
+        .await
+        .map_err(|e| anyhow!(e).context("failed to start provider"))?;
+    ensure!(!accepted);
+    ensure!(
+        error == "host capacity exceeded: 1 providers requested, at most 0 allowed",
+        "invalid error: {error}"
+    );
+    assert_capacity_exceeded(&mut events, &host_key, "providers", 0, 1).await?;
+
+    let CtlOperationAck { accepted, error } = ctl_client
+        .stop_host(&host_key.public_key(), None)
+        .await
+        .map_err(|e| anyhow!(e).context("failed to stop host"))?;
+    ensure!(error == "");
+    ensure!(accepted);
+
+    let _ = host.stopped().await;
+    shutdown.await.context("failed to shutdown host")?;
+
+    stop_server(nats_server, stop_nats_tx)
+        .await
+        .context("failed to stop NATS")?;
+    Ok(())
+}